@@ -17,4 +17,4 @@ pub const POOL_SEED: &[u8] = b"pool";
 pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
 
 #[constant]
-pub const AMM_VERSION: u8 = 1;
\ No newline at end of file
+pub const AMM_VERSION: u8 = 2;
\ No newline at end of file
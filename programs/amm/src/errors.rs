@@ -37,4 +37,7 @@ pub enum AmmError {
 
     #[msg("Fee exceeds maximum")]
     InvalidFee,
+
+    #[msg("Pool account version mismatch, migrate_pool required")]
+    InvalidVersion,
 }
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::PoolAccount;
+
+#[event]
+pub struct AdminAccepted {
+    pub pool: Pubkey,
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    // Only the nominated admin
+    #[account(
+        constraint = pool.pending_admin == Some(new_admin.key()) @ AmmError::InvalidAdmin,
+    )]
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.seed_admin.as_ref(),
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.pool,
+    )]
+    pub pool: Box<Account<'info, PoolAccount>>,
+}
+
+pub fn accept_admin_handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let previous_admin = pool.admin;
+
+    // PDA stays derived from seed_admin
+    pool.admin = ctx.accounts.new_admin.key();
+    pool.pending_admin = None;
+
+    emit!(AdminAccepted {
+        pool: pool.key(),
+        previous_admin,
+        admin: pool.admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::PoolAccount;
+
+#[event]
+pub struct LiquidityProviderAccepted {
+    pub pool: Pubkey,
+    pub previous_liquidity_provider: Pubkey,
+    pub liquidity_provider: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct AcceptLiquidityProvider<'info> {
+    // Only the nominated liquidity provider
+    #[account(
+        constraint = pool.pending_liquidity_provider == Some(new_liquidity_provider.key())
+            @ AmmError::InvalidDepositor,
+    )]
+    pub new_liquidity_provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.seed_admin.as_ref(),
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.pool,
+    )]
+    pub pool: Box<Account<'info, PoolAccount>>,
+}
+
+pub fn accept_liquidity_provider_handler(ctx: Context<AcceptLiquidityProvider>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let previous_liquidity_provider = pool.liquidity_provider;
+
+    pool.liquidity_provider = ctx.accounts.new_liquidity_provider.key();
+    pool.pending_liquidity_provider = None;
+
+    emit!(LiquidityProviderAccepted {
+        pool: pool.key(),
+        previous_liquidity_provider,
+        liquidity_provider: pool.liquidity_provider,
+    });
+
+    Ok(())
+}
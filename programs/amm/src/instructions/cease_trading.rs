@@ -17,31 +17,74 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::constants::*;
-use crate::state::{PoolAccount, PoolState};
+use crate::state::{FinalSnapshot, PoolAccount};
 use crate::errors::*;
 
+#[event]
+pub struct TradingCeased {
+    pub pool: Pubkey,
+    pub snapshot: FinalSnapshot,
+}
+
 #[derive(Accounts)]
 pub struct CeaseTrading<'info> {
     #[account(address = pool.admin @ AmmError::InvalidAdmin)]
     pub admin: Signer<'info>,
 
+    // Must still be trading, checked by PoolAccount::cease_trading
     #[account(
         mut,
         seeds = [
             POOL_SEED,
-            pool.admin.as_ref(),
+            pool.seed_admin.as_ref(),
             pool.mint_a.as_ref(),
             pool.mint_b.as_ref(),
         ],
         bump = pool.bumps.pool,
-        constraint = pool.state == PoolState::Trading @ AmmError::InvalidState
     )]
     pub pool: Box<Account<'info, PoolAccount>>,
+
+    // Pool reserves, read for the final crank and snapshot
+    #[account(
+        seeds = [
+            RESERVE_SEED,
+            pool.key().as_ref(),
+            pool.mint_a.as_ref(),
+        ],
+        bump = pool.bumps.reserve_a,
+        token::mint = pool.mint_a,
+        token::authority = pool,
+    )]
+    pub reserve_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            RESERVE_SEED,
+            pool.key().as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.reserve_b,
+        token::mint = pool.mint_b,
+        token::authority = pool,
+    )]
+    pub reserve_b: InterfaceAccount<'info, TokenAccount>,
 }
 
 pub fn cease_trading_handler(ctx: Context<CeaseTrading>) -> Result<()> {
-    ctx.accounts.pool.state = PoolState::Finalized;
+    let now = Clock::get()?.unix_timestamp;
+    let reserve_a = ctx.accounts.reserve_a.amount;
+    let reserve_b = ctx.accounts.reserve_b.amount;
+    let pool = &mut ctx.accounts.pool;
+
+    let snapshot = pool.cease_trading(now, reserve_a, reserve_b)?;
+
+    emit!(TradingCeased {
+        pool: pool.key(),
+        snapshot,
+    });
+
     Ok(())
 }
@@ -17,7 +17,7 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
-use anchor_spl::token::{TokenAccount};
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::constants::*;
 use crate::state::{PoolAccount, PoolState};
@@ -29,7 +29,7 @@ pub struct CrankTwap<'info> {
         mut,
         seeds = [
             POOL_SEED,
-            pool.admin.as_ref(),
+            pool.seed_admin.as_ref(),
             pool.mint_a.as_ref(),
             pool.mint_b.as_ref(),
         ],
@@ -48,7 +48,7 @@ pub struct CrankTwap<'info> {
         token::mint = pool.mint_a,
         token::authority = pool,
     )]
-    pub reserve_a: Account<'info, TokenAccount>,
+    pub reserve_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         seeds = [
@@ -60,7 +60,7 @@ pub struct CrankTwap<'info> {
         token::mint = pool.mint_b,
         token::authority = pool,
     )]
-    pub reserve_b: Account<'info, TokenAccount>,
+    pub reserve_b: InterfaceAccount<'info, TokenAccount>,
 }
 
 pub fn crank_twap_handler(ctx: Context<CrankTwap>) -> Result<u128> {
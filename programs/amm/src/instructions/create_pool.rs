@@ -17,7 +17,7 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::*;
 use crate::errors::*;
@@ -42,8 +42,10 @@ pub struct CreatePool<'info> {
     pub admin: Signer<'info>,
 
     // Mints; Fees are collected in mint A
-    pub mint_a: Account<'info, Mint>,
-    pub mint_b: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init,
@@ -71,8 +73,9 @@ pub struct CreatePool<'info> {
         bump,
         token::mint = mint_a,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_a: Account<'info, TokenAccount>,
+    pub reserve_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init,
@@ -85,8 +88,9 @@ pub struct CreatePool<'info> {
         bump,
         token::mint = mint_b,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_b: Account<'info, TokenAccount>,
+    pub reserve_b: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Hardcoded fee authority wallet
     #[account(address = FEE_AUTHORITY)]
@@ -101,11 +105,12 @@ pub struct CreatePool<'info> {
         ],
         bump,
         token::mint = mint_a,
-        token::authority = fee_authority
+        token::authority = fee_authority,
+        token::token_program = token_program,
     )]
-    pub fee_vault: Account<'info, TokenAccount>,
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -126,6 +131,10 @@ pub fn create_pool_handler(
         version: AMM_VERSION,
         admin: ctx.accounts.admin.key(),
         liquidity_provider: liquidity_provider.unwrap_or(ctx.accounts.admin.key()),
+        seed_admin: ctx.accounts.admin.key(),
+        pending_admin: None,
+        pending_liquidity_provider: None,
+        final_snapshot: None,
         mint_a: ctx.accounts.mint_a.key(),
         mint_b: ctx.accounts.mint_b.key(),
         fee,
@@ -150,7 +159,7 @@ pub fn create_pool_handler(
         admin: ctx.accounts.admin.key(),
         mint_a: ctx.accounts.mint_a.key(),
         mint_b: ctx.accounts.mint_b.key(),
-        fee: fee,
+        fee,
     });
 
     Ok(())
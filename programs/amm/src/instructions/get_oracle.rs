@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::PoolAccount;
+use crate::twap::OracleState;
+
+#[derive(Accounts)]
+pub struct GetOracle<'info> {
+    // Read-only, any pool state
+    #[account(
+        seeds = [
+            POOL_SEED,
+            pool.seed_admin.as_ref(),
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.pool,
+    )]
+    pub pool: Box<Account<'info, PoolAccount>>,
+}
+
+// Returned via return data; doesn't crank, so reflects the last recording
+pub fn get_oracle_handler(ctx: Context<GetOracle>) -> Result<OracleState> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ctx.accounts.pool.oracle.state(now))
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::constants::*;
+use crate::state::PoolAccount;
+
+#[event]
+pub struct PoolMigrated {
+    pub pool: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    // Permissionless; covers any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Older layouts can't be deserialized as PoolAccount.
+    /// Owner checked here, discriminator and version checked in handler
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_pool_handler(ctx: Context<MigratePool>) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+
+    // Decode under the stored version's layout
+    let (from_version, migrated) = {
+        let data = pool_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data.starts_with(PoolAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let version = data[8];
+        (version, PoolAccount::upgrade(version, &data[8..])?)
+    };
+
+    // Grow the account to the current layout, topping up rent from the payer
+    let new_len = 8 + PoolAccount::INIT_SPACE;
+    if pool_info.data_len() < new_len {
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(pool_info.lamports());
+
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: pool_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        pool_info.resize(new_len)?;
+    }
+
+    // Write back under the current layout
+    let mut data = pool_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    emit!(PoolMigrated {
+        pool: pool_info.key(),
+        from_version,
+        to_version: AMM_VERSION,
+    });
+
+    Ok(())
+}
@@ -4,10 +4,24 @@ pub mod remove_liquidity;
 pub mod swap;
 pub mod crank_twap;
 pub mod cease_trading;
+pub mod get_final_snapshot;
+pub mod get_oracle;
+pub mod migrate_pool;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod propose_liquidity_provider;
+pub mod accept_liquidity_provider;
 
 pub use create_pool::*;
 pub use add_liquidity::*;
 pub use remove_liquidity::*;
 pub use swap::*;
 pub use crank_twap::*;
-pub use cease_trading::*;
\ No newline at end of file
+pub use cease_trading::*;
+pub use get_final_snapshot::*;
+pub use get_oracle::*;
+pub use migrate_pool::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use propose_liquidity_provider::*;
+pub use accept_liquidity_provider::*;
\ No newline at end of file
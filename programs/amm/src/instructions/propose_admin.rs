@@ -0,0 +1,62 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::PoolAccount;
+
+#[event]
+pub struct AdminProposed {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(address = pool.admin @ AmmError::InvalidAdmin)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.seed_admin.as_ref(),
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.pool,
+    )]
+    pub pool: Box<Account<'info, PoolAccount>>,
+}
+
+// Nominates a new admin; None cancels a pending handover
+pub fn propose_admin_handler(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        pool: pool.key(),
+        admin: pool.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::PoolAccount;
+
+#[event]
+pub struct LiquidityProviderProposed {
+    pub pool: Pubkey,
+    pub liquidity_provider: Pubkey,
+    pub pending_liquidity_provider: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeLiquidityProvider<'info> {
+    // Only the current liquidity provider can hand over its position
+    #[account(address = pool.liquidity_provider @ AmmError::InvalidDepositor)]
+    pub liquidity_provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_SEED,
+            pool.seed_admin.as_ref(),
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+        ],
+        bump = pool.bumps.pool,
+    )]
+    pub pool: Box<Account<'info, PoolAccount>>,
+}
+
+// Nominates a new liquidity provider; None cancels a pending handover
+pub fn propose_liquidity_provider_handler(
+    ctx: Context<ProposeLiquidityProvider>,
+    new_liquidity_provider: Option<Pubkey>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.pending_liquidity_provider = new_liquidity_provider;
+
+    emit!(LiquidityProviderProposed {
+        pool: pool.key(),
+        liquidity_provider: pool.liquidity_provider,
+        pending_liquidity_provider: new_liquidity_provider,
+    });
+
+    Ok(())
+}
@@ -17,7 +17,7 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{constants::*, errors::*, state::*, utils::transfer_signed};
 
@@ -39,7 +39,7 @@ pub struct RemoveLiquidity<'info> {
     #[account(
         seeds = [
             POOL_SEED,
-            pool.admin.as_ref(),
+            pool.seed_admin.as_ref(),
             pool.mint_a.as_ref(),
             pool.mint_b.as_ref(),
         ],
@@ -47,6 +47,13 @@ pub struct RemoveLiquidity<'info> {
     )]
     pub pool: Box<Account<'info, PoolAccount>>,
 
+    // Pool mints, needed for checked transfers
+    #[account(address = pool.mint_a)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool.mint_b)]
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
+
     // Pool reserves
     #[account(
         mut,
@@ -58,8 +65,9 @@ pub struct RemoveLiquidity<'info> {
         bump = pool.bumps.reserve_a,
         token::mint = pool.mint_a,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_a: Account<'info, TokenAccount>,
+    pub reserve_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
@@ -71,25 +79,28 @@ pub struct RemoveLiquidity<'info> {
         bump = pool.bumps.reserve_b,
         token::mint = pool.mint_b,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_b: Account<'info, TokenAccount>,
+    pub reserve_b: InterfaceAccount<'info, TokenAccount>,
 
     // Depositor token accounts for both mints
     #[account(
         mut,
         token::mint = pool.mint_a,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_acc_a: Account<'info, TokenAccount>,
+    pub depositor_token_acc_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = pool.mint_b,
         token::authority = depositor,
+        token::token_program = token_program,
     )]
-    pub depositor_token_acc_b: Account<'info, TokenAccount>,
+    pub depositor_token_acc_b: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn remove_liquidity_handler(ctx: Context<RemoveLiquidity>, amount_a: u64, amount_b: u64) -> Result<()> {
@@ -104,7 +115,7 @@ pub fn remove_liquidity_handler(ctx: Context<RemoveLiquidity>, amount_a: u64, am
     let pool = &ctx.accounts.pool;
     let seeds = &[
         POOL_SEED,
-        pool.admin.as_ref(),
+        pool.seed_admin.as_ref(),
         pool.mint_a.as_ref(),
         pool.mint_b.as_ref(),
         &[pool.bumps.pool],
@@ -114,19 +125,23 @@ pub fn remove_liquidity_handler(ctx: Context<RemoveLiquidity>, amount_a: u64, am
     // Transfer tokens from reserves -> depositor
     transfer_signed(
         ctx.accounts.reserve_a.to_account_info(),
+        ctx.accounts.mint_a.to_account_info(),
         ctx.accounts.depositor_token_acc_a.to_account_info(),
         ctx.accounts.pool.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         amount_a,
+        ctx.accounts.mint_a.decimals,
         signer_seeds,
     )?;
 
     transfer_signed(
         ctx.accounts.reserve_b.to_account_info(),
+        ctx.accounts.mint_b.to_account_info(),
         ctx.accounts.depositor_token_acc_b.to_account_info(),
         ctx.accounts.pool.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         amount_b,
+        ctx.accounts.mint_b.decimals,
         signer_seeds,
     )?;
 
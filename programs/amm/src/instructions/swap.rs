@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     constants::*,
@@ -27,7 +27,7 @@ pub struct Swap<'info> {
         mut,
         seeds = [
             POOL_SEED,
-            pool.admin.as_ref(),
+            pool.seed_admin.as_ref(),
             pool.mint_a.as_ref(),
             pool.mint_b.as_ref(),
         ],
@@ -36,6 +36,13 @@ pub struct Swap<'info> {
     )]
     pub pool: Box<Account<'info, PoolAccount>>,
 
+    // Pool mints, needed for checked transfers
+    #[account(address = pool.mint_a)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = pool.mint_b)]
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
+
     // Pool reserves
     #[account(
         mut,
@@ -47,8 +54,9 @@ pub struct Swap<'info> {
         bump = pool.bumps.reserve_a,
         token::mint = pool.mint_a,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_a: Account<'info, TokenAccount>,
+    pub reserve_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
@@ -60,8 +68,9 @@ pub struct Swap<'info> {
         bump = pool.bumps.reserve_b,
         token::mint = pool.mint_b,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub reserve_b: Account<'info, TokenAccount>,
+    pub reserve_b: InterfaceAccount<'info, TokenAccount>,
 
     /// Fee vault with hardcoded fee authority wallet
     #[account(
@@ -72,24 +81,37 @@ pub struct Swap<'info> {
         ],
         bump = pool.bumps.fee_vault,
     )]
-    pub fee_vault: Account<'info, TokenAccount>,
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
 
     // Trader accounts
     #[account(
         mut,
         token::mint = pool.mint_a,
         token::authority = trader,
+        token::token_program = token_program,
     )]
-    pub trader_account_a: Account<'info, TokenAccount>,
+    pub trader_account_a: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = pool.mint_b,
         token::authority = trader,
+        token::token_program = token_program,
     )]
-    pub trader_account_b: Account<'info, TokenAccount>,
+    pub trader_account_b: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
 
-    pub token_program: Program<'info, Token>,
+/// Token movements for a swap, as computed by `Swap::compute_swap`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapAmounts {
+    /// Amount added to the input-side reserve
+    pub input_to_reserve: u64,
+    /// Amount paid out to the trader
+    pub output_to_user: u64,
+    /// Fee sent to the fee vault, always in token A
+    pub fee_amount: u64,
 }
 
 impl<'info> Swap<'info> {
@@ -115,6 +137,64 @@ impl<'info> Swap<'info> {
             .map_err(|_| AmmError::MathOverflow)?;
         Ok(output)
     }
+
+    /// Fee in basis points, rounded up to 1 when nonzero bps would truncate to 0
+    pub fn compute_fee(amount: u64, fee_bps: u64) -> Result<u64> {
+        let mut fee = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(AmmError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(AmmError::MathOverflow)? as u64;
+
+        // Prevent dust swaps from avoiding fees via integer truncation
+        if fee_bps > 0 && fee == 0 {
+            fee = 1;
+        }
+
+        Ok(fee)
+    }
+
+    /// Fee and output for a swap against the given reserves.
+    /// Fee is always collected in token A.
+    pub fn compute_swap(
+        swap_a_to_b: bool,
+        input_amount: u64,
+        reserve_a: u64,
+        reserve_b: u64,
+        fee_bps: u64,
+    ) -> Result<SwapAmounts> {
+        if swap_a_to_b {
+            // A -> B: fee on input (A), then swap
+            let fee = Swap::compute_fee(input_amount, fee_bps)?;
+            let taxed_input = input_amount
+                .checked_sub(fee)
+                .ok_or(AmmError::MathUnderflow)?;
+
+            let out = Swap::compute_output(taxed_input, reserve_a, reserve_b)?;
+            require!(reserve_b >= out, AmmError::InsufficientReserve);
+
+            Ok(SwapAmounts {
+                input_to_reserve: taxed_input,
+                output_to_user: out,
+                fee_amount: fee,
+            })
+        } else {
+            // B -> A: swap first, then fee on output (A)
+            let gross_output = Swap::compute_output(input_amount, reserve_b, reserve_a)?;
+            require!(reserve_a >= gross_output, AmmError::InsufficientReserve);
+
+            let fee = Swap::compute_fee(gross_output, fee_bps)?;
+            let net_output = gross_output
+                .checked_sub(fee)
+                .ok_or(AmmError::MathUnderflow)?;
+
+            Ok(SwapAmounts {
+                input_to_reserve: input_amount,
+                output_to_user: net_output,
+                fee_amount: fee,
+            })
+        }
+    }
 }
 
 pub fn swap_handler(
@@ -139,48 +219,11 @@ pub fn swap_handler(
     let invariant_before = Swap::invariant(reserve_a as u128, reserve_b as u128)?;
 
     // Calculate fee and output based on swap direction
-    // Fee is always collected in token A
-    let (input_to_reserve, output_to_user, fee_amount) = if swap_a_to_b {
-        // A -> B: fee on input (A), then swap
-        let mut fee = (input_amount as u128)
-            .checked_mul(fee_bps as u128)
-            .ok_or(AmmError::MathOverflow)?
-            .checked_div(10000)
-            .ok_or(AmmError::MathOverflow)? as u64;
-
-        // Prevent dust swaps from avoiding fees via integer truncation
-        if fee_bps > 0 && fee == 0 {
-            fee = 1;
-        }
-
-        let taxed_input = input_amount
-            .checked_sub(fee)
-            .ok_or(AmmError::MathUnderflow)?;
-
-        let out = Swap::compute_output(taxed_input, reserve_a, reserve_b)?;
-        require!(reserve_b >= out, AmmError::InsufficientReserve);
-
-        (taxed_input, out, fee)
-    } else {
-        // B -> A: swap first, then fee on output (A)
-        let gross_output = Swap::compute_output(input_amount, reserve_b, reserve_a)?;
-        require!(reserve_a >= gross_output, AmmError::InsufficientReserve);
-
-        let mut fee = (gross_output as u128)
-            .checked_mul(fee_bps as u128)
-            .ok_or(AmmError::MathOverflow)?
-            .checked_div(10000)
-            .ok_or(AmmError::MathOverflow)? as u64;
-        // Prevent dust swaps from avoiding fees via integer truncation
-        if fee_bps > 0 && fee == 0 {
-            fee = 1;
-        }
-        let net_output = gross_output
-            .checked_sub(fee)
-            .ok_or(AmmError::MathUnderflow)?;
-
-        (input_amount, net_output, fee)
-    };
+    let SwapAmounts {
+        input_to_reserve,
+        output_to_user,
+        fee_amount,
+    } = Swap::compute_swap(swap_a_to_b, input_amount, reserve_a, reserve_b, fee_bps)?;
 
     // Slippage check
     require!(output_to_user >= min_output_amount, AmmError::SlippageExceeded);
@@ -194,7 +237,7 @@ pub fn swap_handler(
     let pool = &ctx.accounts.pool;
     let seeds = &[
         POOL_SEED,
-        pool.admin.as_ref(),
+        pool.seed_admin.as_ref(),
         pool.mint_a.as_ref(),
         pool.mint_b.as_ref(),
         &[pool.bumps.pool],
@@ -206,28 +249,34 @@ pub fn swap_handler(
         // 1. Transfer input A (minus fee) to reserve
         transfer_tokens(
             ctx.accounts.trader_account_a.to_account_info(),
+            ctx.accounts.mint_a.to_account_info(),
             ctx.accounts.reserve_a.to_account_info(),
             ctx.accounts.trader.to_account_info(),
             ctx.accounts.token_program.to_account_info(),
             input_to_reserve,
+            ctx.accounts.mint_a.decimals,
         )?;
         // 2. Transfer fee to fee vault (skip if zero)
         if fee_amount > 0 {
             transfer_tokens(
                 ctx.accounts.trader_account_a.to_account_info(),
+                ctx.accounts.mint_a.to_account_info(),
                 ctx.accounts.fee_vault.to_account_info(),
                 ctx.accounts.trader.to_account_info(),
                 ctx.accounts.token_program.to_account_info(),
                 fee_amount,
+                ctx.accounts.mint_a.decimals,
             )?;
         }
         // 3. Transfer output B to trader
         transfer_signed(
             ctx.accounts.reserve_b.to_account_info(),
+            ctx.accounts.mint_b.to_account_info(),
             ctx.accounts.trader_account_b.to_account_info(),
             ctx.accounts.pool.to_account_info(),
             ctx.accounts.token_program.to_account_info(),
             output_to_user,
+            ctx.accounts.mint_b.decimals,
             signer_seeds,
         )?;
     } else {
@@ -235,28 +284,34 @@ pub fn swap_handler(
         // 1. Transfer input B to reserve
         transfer_tokens(
             ctx.accounts.trader_account_b.to_account_info(),
+            ctx.accounts.mint_b.to_account_info(),
             ctx.accounts.reserve_b.to_account_info(),
             ctx.accounts.trader.to_account_info(),
             ctx.accounts.token_program.to_account_info(),
             input_to_reserve,
+            ctx.accounts.mint_b.decimals,
         )?;
         // 2. Transfer output A to trader
         transfer_signed(
             ctx.accounts.reserve_a.to_account_info(),
+            ctx.accounts.mint_a.to_account_info(),
             ctx.accounts.trader_account_a.to_account_info(),
             ctx.accounts.pool.to_account_info(),
             ctx.accounts.token_program.to_account_info(),
             output_to_user,
+            ctx.accounts.mint_a.decimals,
             signer_seeds,
         )?;
         // 3. Transfer fee from reserve A to fee vault (skip if zero)
         if fee_amount > 0 {
             transfer_signed(
                 ctx.accounts.reserve_a.to_account_info(),
+                ctx.accounts.mint_a.to_account_info(),
                 ctx.accounts.fee_vault.to_account_info(),
                 ctx.accounts.pool.to_account_info(),
                 ctx.accounts.token_program.to_account_info(),
                 fee_amount,
+                ctx.accounts.mint_a.decimals,
                 signer_seeds,
             )?;
         }
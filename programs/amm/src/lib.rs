@@ -21,6 +21,7 @@ use anchor_lang::prelude::*;
 pub mod constants;
 pub mod errors;
 pub mod instructions;
+pub mod reader;
 pub mod state;
 pub mod twap;
 pub mod utils;
@@ -28,6 +29,7 @@ pub mod utils;
 pub use constants::*;
 pub use instructions::*;
 pub use state::*;
+pub use twap::OracleState;
 
 declare_id!("AMMSgtnttAKx5Ad2Y1socKJ3CcQYCB2ctg8U2SAHcVEx");
 
@@ -81,4 +83,38 @@ pub mod amm {
     pub fn cease_trading(ctx: Context<CeaseTrading>) -> Result<()> {
         instructions::cease_trading::cease_trading_handler(ctx)
     }
+
+    pub fn get_oracle(ctx: Context<GetOracle>) -> Result<OracleState> {
+        instructions::get_oracle::get_oracle_handler(ctx)
+    }
+
+    pub fn get_final_snapshot(ctx: Context<GetFinalSnapshot>) -> Result<FinalSnapshot> {
+        instructions::get_final_snapshot::get_final_snapshot_handler(ctx)
+    }
+
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        instructions::migrate_pool::migrate_pool_handler(ctx)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::propose_admin::propose_admin_handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin_handler(ctx)
+    }
+
+    pub fn propose_liquidity_provider(
+        ctx: Context<ProposeLiquidityProvider>,
+        new_liquidity_provider: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_liquidity_provider::propose_liquidity_provider_handler(
+            ctx,
+            new_liquidity_provider,
+        )
+    }
+
+    pub fn accept_liquidity_provider(ctx: Context<AcceptLiquidityProvider>) -> Result<()> {
+        instructions::accept_liquidity_provider::accept_liquidity_provider_handler(ctx)
+    }
 }
@@ -0,0 +1,54 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Helpers for programs that read pools directly instead of via CPI.
+//!
+//! Depend on this crate with the `cpi` (or `no-entrypoint`) feature. Callers
+//! are responsible for checking the pool key is the one they expect.
+use anchor_lang::prelude::*;
+
+use crate::state::PoolAccount;
+use crate::twap::OracleState;
+
+/// Deserializes a pool, checking program owner, discriminator and version.
+pub fn load_pool(pool_info: &AccountInfo) -> Result<PoolAccount> {
+    require_keys_eq!(
+        *pool_info.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let data = pool_info.try_borrow_data()?;
+    PoolAccount::try_deserialize(&mut &data[..])
+}
+
+/// Oracle state as of the current clock; same as `get_oracle`.
+pub fn read_oracle(pool_info: &AccountInfo) -> Result<OracleState> {
+    let now = Clock::get()?.unix_timestamp;
+    read_oracle_at(pool_info, now)
+}
+
+/// `read_oracle` with an explicit timestamp, so it can be driven without a Clock sysvar.
+pub fn read_oracle_at(pool_info: &AccountInfo, now: i64) -> Result<OracleState> {
+    Ok(load_pool(pool_info)?.oracle.state(now))
+}
+
+/// Current TWAP, failing if it isn't available yet.
+pub fn read_twap(pool_info: &AccountInfo) -> Result<u128> {
+    load_pool(pool_info)?.oracle.fetch_twap()
+}
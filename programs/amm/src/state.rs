@@ -16,8 +16,11 @@
  * You should have received a copy of the GNU Affero General Public License
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
+use crate::constants::AMM_VERSION;
+use crate::errors::AmmError;
 use crate::twap::TwapOracle;
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 #[derive(Copy, Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub enum PoolState {
@@ -33,8 +36,21 @@ pub struct PoolBumps {
     pub fee_vault: u8,
 }
 
-#[account]
-#[derive(InitSpace)]
+/// Oracle and reserve state captured when trading ceases
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct FinalSnapshot {
+    /// TWAP at cessation, None if it never became available (e.g. still in warmup)
+    pub twap: Option<u128>,
+    pub last_price: u128,
+    pub last_observation: u128,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub unix_time: i64,
+}
+
+// Account traits are implemented below instead of by #[account], so that
+// un-migrated pools fail with InvalidVersion rather than failing to decode
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct PoolAccount {
     pub version: u8,
     pub bumps: PoolBumps,
@@ -57,4 +73,142 @@ pub struct PoolAccount {
     pub liquidity_provider: Pubkey,
 
     pub oracle: TwapOracle,
+
+    // Admin at creation, used for PDA derivation
+    // Fixed across admin handovers
+    pub seed_admin: Pubkey,
+
+    // Two-step handovers, set by the current holder and
+    // completed when the nominee accepts
+    pub pending_admin: Option<Pubkey>,
+    pub pending_liquidity_provider: Option<Pubkey>,
+
+    // Set once by cease_trading
+    pub final_snapshot: Option<FinalSnapshot>,
+}
+
+impl Discriminator for PoolAccount {
+    // sha256("account:PoolAccount")[..8], as #[account] would derive it
+    const DISCRIMINATOR: &'static [u8] = &[116, 210, 187, 119, 196, 196, 52, 137];
+}
+
+impl Owner for PoolAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for PoolAccount {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        writer
+            .write_all(Self::DISCRIMINATOR)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        AnchorSerialize::serialize(self, writer).map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for PoolAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        let disc_len = Self::DISCRIMINATOR.len();
+        require!(buf.len() > disc_len, ErrorCode::AccountDiscriminatorNotFound);
+        require!(
+            buf.starts_with(Self::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Older layouts don't decode as the current one, so check the version first
+        require!(buf[disc_len] == AMM_VERSION, AmmError::InvalidVersion);
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data = &buf[Self::DISCRIMINATOR.len()..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+impl PoolAccount {
+    /// Stops trading at `now`: cranks the oracle one last time against the final
+    /// reserves, then records the snapshot and finalizes the pool.
+    pub fn cease_trading(
+        &mut self,
+        now: i64,
+        reserve_a: u64,
+        reserve_b: u64,
+    ) -> Result<FinalSnapshot> {
+        require!(self.state == PoolState::Trading, AmmError::InvalidState);
+
+        // The pool may cease before a TWAP is available
+        let twap = self.oracle.try_crank_twap_at(now, reserve_a, reserve_b)?;
+
+        let snapshot = FinalSnapshot {
+            twap,
+            last_price: self.oracle.last_price,
+            last_observation: self.oracle.last_observation,
+            reserve_a,
+            reserve_b,
+            unix_time: now,
+        };
+
+        self.final_snapshot = Some(snapshot);
+        self.state = PoolState::Finalized;
+
+        Ok(snapshot)
+    }
+
+    /// Snapshot recorded by `cease_trading`, failing while the pool still trades
+    pub fn snapshot(&self) -> Result<FinalSnapshot> {
+        self.final_snapshot.ok_or(AmmError::InvalidState.into())
+    }
+
+    /// Decodes a pool written under an older `version` and upgrades it to the
+    /// current layout, filling any new fields with defaults.
+    /// `data` is the account data without the 8-byte discriminator.
+    ///
+    /// When `PoolAccount` changes shape, freeze the previous layout as
+    /// `PoolAccountV{n}` and add a decode arm for it here.
+    pub fn upgrade(version: u8, data: &[u8]) -> Result<Self> {
+        let mut pool: Self = match version {
+            1 => PoolAccountV1::deserialize(&mut &data[..])?.into(),
+            // Current or unknown versions have nothing to upgrade from
+            _ => return err!(AmmError::InvalidVersion),
+        };
+        pool.version = AMM_VERSION;
+        Ok(pool)
+    }
+}
+
+/// Frozen v1 layout, prior to pool handovers and the final snapshot
+#[derive(AnchorDeserialize)]
+pub struct PoolAccountV1 {
+    pub version: u8,
+    pub bumps: PoolBumps,
+    pub state: PoolState,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub fee: u16,
+    pub admin: Pubkey,
+    pub liquidity_provider: Pubkey,
+    pub oracle: TwapOracle,
+}
+
+impl From<PoolAccountV1> for PoolAccount {
+    fn from(v1: PoolAccountV1) -> Self {
+        Self {
+            version: v1.version,
+            bumps: v1.bumps,
+            state: v1.state,
+            mint_a: v1.mint_a,
+            mint_b: v1.mint_b,
+            fee: v1.fee,
+            admin: v1.admin,
+            liquidity_provider: v1.liquidity_provider,
+            oracle: v1.oracle,
+            seed_admin: v1.admin,
+            pending_admin: None,
+            pending_liquidity_provider: None,
+            final_snapshot: None,
+        }
+    }
 }
@@ -32,6 +32,21 @@ pub struct TWAPUpdate {
     pub twap: u128,
 }
 
+/// Read-only view of a TWAP oracle, returned by `get_oracle`.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleState {
+    /// None until accumulation has started (during warmup or before the first recording after it)
+    pub twap: Option<u128>,
+    pub last_price: u128,
+    pub last_observation: u128,
+    pub cumulative_observations: u128,
+    /// TWAP window: from end of warmup to the last recording
+    pub window_start: i64,
+    pub window_end: i64,
+    /// Seconds since the last recording
+    pub staleness: i64,
+}
+
 /// TWAP oracle that tracks time-weighted average prices with manipulation resistance.
 ///
 /// Observations are rate-limited to prevent flash loan and single-block attacks.
@@ -81,17 +96,58 @@ impl TwapOracle {
     }
 
     /// Records a new price sample and updates the TWAP accumulator.
-    /// Returns the current TWAP if available (None during warmup).
+    /// Returns the current TWAP, failing if it isn't available yet (e.g. during warmup).
     pub fn crank_twap(&mut self, reserves_a: u64, reserves_b: u64) -> Result<u128> {
-        let clock = Clock::get()?;
-        let now = clock.unix_timestamp;
+        let now = Clock::get()?.unix_timestamp;
+        self.crank_twap_at(now, reserves_a, reserves_b)
+    }
+
+    /// `crank_twap` with an explicit timestamp, so it can be driven without a Clock sysvar.
+    pub fn crank_twap_at(&mut self, now: i64, reserves_a: u64, reserves_b: u64) -> Result<u128> {
+        let recorded = self.record(now, reserves_a, reserves_b)?;
+
+        // Get final twap
+        let twap = self.fetch_twap()?;
+        if recorded {
+            self.emit_update(now, twap);
+        }
+
+        Ok(twap)
+    }
 
+    /// Same as `crank_twap`, but returns None instead of failing when no TWAP is available yet.
+    /// The observation is still recorded.
+    pub fn try_crank_twap(&mut self, reserves_a: u64, reserves_b: u64) -> Result<Option<u128>> {
+        let now = Clock::get()?.unix_timestamp;
+        self.try_crank_twap_at(now, reserves_a, reserves_b)
+    }
+
+    /// `try_crank_twap` with an explicit timestamp.
+    pub fn try_crank_twap_at(
+        &mut self,
+        now: i64,
+        reserves_a: u64,
+        reserves_b: u64,
+    ) -> Result<Option<u128>> {
+        let recorded = self.record(now, reserves_a, reserves_b)?;
+
+        let twap = self.fetch_twap().ok();
+        if let (true, Some(twap)) = (recorded, twap) {
+            self.emit_update(now, twap);
+        }
+
+        Ok(twap)
+    }
+
+    /// Moves the observation toward the current price and accumulates it.
+    /// Returns false if skipped (rate limited or no liquidity).
+    fn record(&mut self, now: i64, reserves_a: u64, reserves_b: u64) -> Result<bool> {
         // Early exit: rate limit or no liquidity
         if now < self.last_update_unix_time + self.min_recording_interval
             || reserves_a == 0
             || reserves_b == 0
         {
-            return self.fetch_twap();
+            return Ok(false);
         }
 
         let curr_price = (reserves_a as u128)
@@ -142,18 +198,32 @@ impl TwapOracle {
             Ordering::Equal => require_eq!(new_obs, curr_price),
         }
 
-        // Get final twap
-        let twap = self.fetch_twap()?;
+        Ok(true)
+    }
 
+    fn emit_update(&self, now: i64, twap: u128) {
         emit!(TWAPUpdate {
             unix_time: now,
-            price: curr_price,
-            observation: new_obs,
+            price: self.last_price,
+            observation: self.last_observation,
             cumulative_observations: self.cumulative_observations,
-            twap: twap
+            twap
         });
+    }
 
-        Ok(twap)
+    /// Snapshot of the oracle as of `now`, without recording a new observation.
+    pub fn state(&self, now: i64) -> OracleState {
+        OracleState {
+            twap: self.fetch_twap().ok(),
+            last_price: self.last_price,
+            last_observation: self.last_observation,
+            cumulative_observations: self.cumulative_observations,
+            window_start: self
+                .created_at_unix_time
+                .saturating_add(self.warmup_duration as i64),
+            window_end: self.last_update_unix_time,
+            staleness: now.saturating_sub(self.last_update_unix_time),
+        }
     }
 
     /// Computes the time-weighted average price since warmup completed.
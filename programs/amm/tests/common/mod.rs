@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Hand-built pool account data shared by the account tests.
+#![allow(dead_code)]
+
+use amm::twap::TwapOracle;
+use amm::{PoolAccount, PoolBumps, PoolState};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+pub const START: i64 = 1_700_000_000;
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+
+pub fn oracle(warmup_duration: u32) -> TwapOracle {
+    TwapOracle::new(START, PRICE_SCALE, PRICE_SCALE / 10, warmup_duration)
+}
+
+/// A freshly created pool at the current version
+pub fn pool() -> PoolAccount {
+    let admin = Pubkey::new_unique();
+    PoolAccount {
+        version: amm::AMM_VERSION,
+        bumps: PoolBumps {
+            pool: 255,
+            reserve_a: 254,
+            reserve_b: 253,
+            fee_vault: 252,
+        },
+        state: PoolState::Trading,
+        mint_a: Pubkey::new_unique(),
+        mint_b: Pubkey::new_unique(),
+        fee: 30,
+        admin,
+        liquidity_provider: admin,
+        oracle: oracle(0),
+        seed_admin: admin,
+        pending_admin: None,
+        pending_liquidity_provider: None,
+        final_snapshot: None,
+    }
+}
+
+/// Account data (with discriminator) as the program writes it
+pub fn pool_data(pool: &PoolAccount) -> Vec<u8> {
+    let mut data = vec![];
+    pool.try_serialize(&mut data).unwrap();
+    data
+}
+
+/// Account data under the frozen v1 layout, before handovers and the final snapshot
+pub fn v1_pool_data(admin: Pubkey, liquidity_provider: Pubkey) -> Vec<u8> {
+    let mut data = PoolAccount::DISCRIMINATOR.to_vec();
+    data.push(1); // version
+    data.extend([255, 254, 253, 252]); // bumps
+    data.push(0); // PoolState::Trading
+    data.extend(Pubkey::new_unique().to_bytes()); // mint_a
+    data.extend(Pubkey::new_unique().to_bytes()); // mint_b
+    data.extend(30u16.to_le_bytes()); // fee
+    data.extend(admin.to_bytes());
+    data.extend(liquidity_provider.to_bytes());
+    oracle(0).serialize(&mut data).unwrap();
+    data
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Tests for the final snapshot recorded when a pool ceases trading.
+mod common;
+
+use amm::errors::AmmError;
+use amm::PoolState;
+use common::*;
+
+const RESERVE_A: u64 = 2_000_000;
+const RESERVE_B: u64 = 1_000_000;
+
+#[test]
+fn cease_cranks_the_oracle_before_taking_the_snapshot() {
+    let mut pool = pool();
+    let now = START + 120;
+
+    let snapshot = pool.cease_trading(now, RESERVE_A, RESERVE_B).unwrap();
+
+    // The final crank recorded the closing reserves at `now`...
+    assert_eq!(pool.oracle.last_update_unix_time, now);
+    assert_eq!(pool.oracle.last_price, 2 * PRICE_SCALE);
+    // ...and the snapshot was taken from the cranked oracle
+    assert_eq!(snapshot.last_price, pool.oracle.last_price);
+    assert_eq!(snapshot.last_observation, pool.oracle.last_observation);
+    assert_eq!(snapshot.twap, Some(pool.oracle.fetch_twap().unwrap()));
+    assert_eq!(snapshot.reserve_a, RESERVE_A);
+    assert_eq!(snapshot.reserve_b, RESERVE_B);
+    assert_eq!(snapshot.unix_time, now);
+
+    assert!(pool.state == PoolState::Finalized);
+    assert_eq!(pool.snapshot().unwrap().unix_time, now);
+}
+
+#[test]
+fn snapshot_has_no_twap_when_ceasing_during_warmup() {
+    let mut pool = pool();
+    pool.oracle = oracle(3_600);
+
+    let snapshot = pool.cease_trading(START + 120, RESERVE_A, RESERVE_B).unwrap();
+
+    assert_eq!(snapshot.twap, None);
+    assert_eq!(snapshot.last_price, 2 * PRICE_SCALE);
+    assert!(pool.state == PoolState::Finalized);
+}
+
+#[test]
+fn snapshot_is_unavailable_before_cease() {
+    let pool = pool();
+
+    assert!(pool.final_snapshot.is_none());
+    let err = pool.snapshot().err().unwrap();
+    assert_eq!(err, AmmError::InvalidState.into());
+}
+
+#[test]
+fn ceased_pool_rejects_ceasing_again() {
+    let mut pool = pool();
+    let first = pool.cease_trading(START + 120, RESERVE_A, RESERVE_B).unwrap();
+
+    let err = pool
+        .cease_trading(START + 600, RESERVE_B, RESERVE_A)
+        .err()
+        .unwrap();
+    assert_eq!(err, AmmError::InvalidState.into());
+
+    // Neither the oracle nor the snapshot moved
+    assert_eq!(pool.oracle.last_update_unix_time, START + 120);
+    assert_eq!(pool.snapshot().unwrap().unix_time, first.unix_time);
+    assert_eq!(pool.snapshot().unwrap().reserve_a, RESERVE_A);
+}
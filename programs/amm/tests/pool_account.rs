@@ -0,0 +1,83 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Tests for the pool account's version guard and v1 upgrade.
+mod common;
+
+use amm::errors::AmmError;
+use amm::{PoolAccount, AMM_VERSION};
+use anchor_lang::prelude::*;
+use common::*;
+
+#[test]
+fn current_pool_round_trips() {
+    let pool = pool();
+    let decoded = PoolAccount::try_deserialize(&mut &pool_data(&pool)[..]).unwrap();
+
+    assert_eq!(decoded.version, AMM_VERSION);
+    assert_eq!(decoded.admin, pool.admin);
+    assert_eq!(decoded.mint_b, pool.mint_b);
+}
+
+#[test]
+fn unmigrated_pool_is_rejected_with_invalid_version() {
+    let data = v1_pool_data(Pubkey::new_unique(), Pubkey::new_unique());
+
+    let err = PoolAccount::try_deserialize(&mut &data[..]).err().unwrap();
+    assert_eq!(err, AmmError::InvalidVersion.into());
+}
+
+#[test]
+fn unknown_version_is_rejected_with_invalid_version() {
+    let mut data = pool_data(&pool());
+    data[8] = AMM_VERSION + 1;
+
+    let err = PoolAccount::try_deserialize(&mut &data[..]).err().unwrap();
+    assert_eq!(err, AmmError::InvalidVersion.into());
+}
+
+#[test]
+fn v1_pool_upgrades_to_current_layout() {
+    let admin = Pubkey::new_unique();
+    let liquidity_provider = Pubkey::new_unique();
+    let data = v1_pool_data(admin, liquidity_provider);
+
+    let upgraded = PoolAccount::upgrade(data[8], &data[8..]).unwrap();
+    assert_eq!(upgraded.version, AMM_VERSION);
+    assert_eq!(upgraded.admin, admin);
+    assert_eq!(upgraded.liquidity_provider, liquidity_provider);
+    // PDA seeds keep the admin the pool was created with
+    assert_eq!(upgraded.seed_admin, admin);
+    assert!(upgraded.pending_admin.is_none());
+    assert!(upgraded.pending_liquidity_provider.is_none());
+    assert!(upgraded.final_snapshot.is_none());
+
+    // Written back, it fits the migrated size and decodes under the current layout
+    let migrated = pool_data(&upgraded);
+    assert!(migrated.len() > data.len());
+    assert!(migrated.len() <= 8 + PoolAccount::INIT_SPACE);
+    PoolAccount::try_deserialize(&mut &migrated[..]).unwrap();
+}
+
+#[test]
+fn current_version_has_nothing_to_upgrade() {
+    let data = pool_data(&pool());
+
+    let err = PoolAccount::upgrade(data[8], &data[8..]).err().unwrap();
+    assert_eq!(err, AmmError::InvalidVersion.into());
+}
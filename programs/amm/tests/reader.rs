@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Tests for the reader helpers on hand-built account data.
+mod common;
+
+use amm::errors::AmmError;
+use amm::reader::{load_pool, read_oracle_at, read_twap};
+use anchor_lang::prelude::*;
+use common::*;
+
+/// Runs `f` against an AccountInfo over `data`, owned by `owner`
+fn with_account<T>(owner: Pubkey, mut data: Vec<u8>, f: impl FnOnce(&AccountInfo) -> T) -> T {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+    f(&info)
+}
+
+/// A pool that has recorded one observation, 60s after creation
+fn cranked_pool() -> amm::PoolAccount {
+    let mut pool = pool();
+    pool.oracle.crank_twap_at(START + 60, 1_000_000, 1_000_000).unwrap();
+    pool
+}
+
+#[test]
+fn loads_a_current_pool() {
+    let pool = cranked_pool();
+
+    let loaded = with_account(amm::ID, pool_data(&pool), |info| load_pool(info).unwrap());
+    assert_eq!(loaded.admin, pool.admin);
+    assert_eq!(loaded.oracle.cumulative_observations, pool.oracle.cumulative_observations);
+}
+
+#[test]
+fn rejects_a_pool_owned_by_another_program() {
+    let err = with_account(Pubkey::new_unique(), pool_data(&pool()), |info| {
+        load_pool(info).err().unwrap()
+    });
+    assert_eq!(err, ErrorCode::AccountOwnedByWrongProgram.into());
+}
+
+#[test]
+fn rejects_another_account_type() {
+    let mut data = pool_data(&pool());
+    data[..8].copy_from_slice(&[0; 8]);
+
+    let err = with_account(amm::ID, data, |info| load_pool(info).err().unwrap());
+    assert_eq!(err, ErrorCode::AccountDiscriminatorMismatch.into());
+}
+
+#[test]
+fn rejects_a_v1_pool_with_invalid_version() {
+    let data = v1_pool_data(Pubkey::new_unique(), Pubkey::new_unique());
+
+    let err = with_account(amm::ID, data.clone(), |info| load_pool(info).err().unwrap());
+    assert_eq!(err, AmmError::InvalidVersion.into());
+    let err = with_account(amm::ID, data.clone(), |info| read_twap(info).err().unwrap());
+    assert_eq!(err, AmmError::InvalidVersion.into());
+    let err = with_account(amm::ID, data, |info| read_oracle_at(info, START).err().unwrap());
+    assert_eq!(err, AmmError::InvalidVersion.into());
+}
+
+#[test]
+fn pool_without_observations_has_no_twap() {
+    let data = pool_data(&pool());
+
+    assert!(with_account(amm::ID, data.clone(), read_twap).is_err());
+
+    let oracle = with_account(amm::ID, data, |info| read_oracle_at(info, START + 30).unwrap());
+    assert_eq!(oracle.twap, None);
+    assert_eq!(oracle.cumulative_observations, 0);
+    assert_eq!(oracle.window_end, START);
+    assert_eq!(oracle.staleness, 30);
+}
+
+#[test]
+fn reads_the_twap_once_observed() {
+    let pool = cranked_pool();
+    let data = pool_data(&pool);
+
+    let twap = with_account(amm::ID, data.clone(), |info| read_twap(info).unwrap());
+    assert_eq!(twap, pool.oracle.fetch_twap().unwrap());
+
+    let oracle = with_account(amm::ID, data, |info| read_oracle_at(info, START + 90).unwrap());
+    assert_eq!(oracle.twap, Some(twap));
+    assert_eq!(oracle.window_start, START);
+    assert_eq!(oracle.window_end, START + 60);
+    assert_eq!(oracle.staleness, 30);
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Property tests for the constant-product swap math and fee rounding.
+use amm::{Swap, MAX_FEE};
+use proptest::prelude::*;
+
+fn reserve() -> impl Strategy<Value = u64> {
+    1..=u64::MAX / 2
+}
+
+proptest! {
+    #[test]
+    fn output_never_exceeds_reserve(
+        input in 0..=u64::MAX / 2,
+        reserve_in in reserve(),
+        reserve_out in reserve(),
+    ) {
+        let out = Swap::compute_output(input, reserve_in, reserve_out).unwrap();
+        prop_assert!(out < reserve_out);
+    }
+
+    #[test]
+    fn fee_rounds_up_from_zero(amount in any::<u64>(), fee_bps in 0..=MAX_FEE as u64) {
+        let fee = Swap::compute_fee(amount, fee_bps).unwrap();
+        let exact = (amount as u128 * fee_bps as u128 / 10000) as u64;
+
+        if fee_bps == 0 {
+            prop_assert_eq!(fee, 0);
+        } else {
+            prop_assert_eq!(fee, exact.max(1));
+        }
+    }
+
+    #[test]
+    fn swap_never_decreases_k(
+        swap_a_to_b in any::<bool>(),
+        input in 1..=u64::MAX / 4,
+        reserve_a in reserve(),
+        reserve_b in reserve(),
+        fee_bps in 0..=MAX_FEE as u64,
+    ) {
+        // Errors (e.g. fee larger than a dust output) abort the swap on-chain
+        let Ok(amounts) = Swap::compute_swap(swap_a_to_b, input, reserve_a, reserve_b, fee_bps) else {
+            return Ok(());
+        };
+
+        // Trader never pays more than the input amount
+        if swap_a_to_b {
+            prop_assert_eq!(amounts.input_to_reserve + amounts.fee_amount, input);
+        } else {
+            prop_assert_eq!(amounts.input_to_reserve, input);
+        }
+
+        // Mirror the transfers made by swap_handler
+        let (new_a, new_b) = if swap_a_to_b {
+            prop_assert!(amounts.output_to_user <= reserve_b);
+            (
+                reserve_a as u128 + amounts.input_to_reserve as u128,
+                reserve_b as u128 - amounts.output_to_user as u128,
+            )
+        } else {
+            let paid_from_a = amounts.output_to_user as u128 + amounts.fee_amount as u128;
+            prop_assert!(paid_from_a <= reserve_a as u128);
+            (
+                reserve_a as u128 - paid_from_a,
+                reserve_b as u128 + amounts.input_to_reserve as u128,
+            )
+        };
+
+        let k_before = reserve_a as u128 * reserve_b as u128;
+        prop_assert!(new_a * new_b >= k_before);
+    }
+}
@@ -16,3 +16,7 @@ pub const MAX_OPTIONS: u8 = 6;
 // Minimum number of conditional options required
 #[constant]
 pub const MIN_OPTIONS: u8 = 2;
+
+// Time after a proposal ends before anyone can void its vault
+#[constant]
+pub const VAULT_RESOLUTION_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
@@ -58,4 +58,7 @@ pub enum FutarchyError {
 
     #[msg("Invalid proposal parameters")]
     InvalidProposalParams,
+
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
 }
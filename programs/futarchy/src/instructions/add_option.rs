@@ -7,8 +7,9 @@ use crate::errors::FutarchyError;
 use crate::state::proposal::*;
 use amm::program::Amm;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::Token;
+use anchor_spl::token_interface::TokenInterface;
 use vault::program::Vault;
+use vault::VaultAccount;
 
 #[event]
 pub struct OptionAdded {
@@ -34,32 +35,37 @@ pub struct AddOption<'info> {
         ],
         bump = proposal.bump,
         constraint = proposal.state == ProposalState::Setup @ FutarchyError::InvalidState,
+        has_one = vault @ FutarchyError::InvalidVault,
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
 
+    #[account(mut)]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
     // Programs
     pub system_program: Program<'info, System>,
     pub vault_program: Program<'info, Vault>,
     pub amm_program: Program<'info, Amm>,
-    pub token_program: Program<'info, Token>,
+    #[account(address = vault.conditional_token_program @ FutarchyError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     // Remaining accounts (in order), all validated in CPI calls:
-    // 0: vault
-    // 1: cond_base_mint
-    // 2: cond_quote_mint
-    // 3: pool
-    // 4: reserve_a
-    // 5: reserve_b
-    // 6: fee_authority
-    // 7: fee_vault
+    // 0: cond_base_mint
+    // 1: cond_quote_mint
+    // 2: pool
+    // 3: reserve_a
+    // 4: reserve_b
+    // 5: fee_authority
+    // 6: fee_vault
 }
 
 pub fn add_option_handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, AddOption<'info>>,
+    uri: Option<String>, // Conditional mint metadata URI
 ) -> Result<()> {
     require!(
-        ctx.remaining_accounts.len() == 8,
+        ctx.remaining_accounts.len() == 7,
         FutarchyError::InvalidRemainingAccounts
     );
 
@@ -70,7 +76,7 @@ pub fn add_option_handler<'info>(
     require!(curr_options < MAX_OPTIONS, FutarchyError::TooManyOptions);
 
     // Update state
-    proposal.pools[curr_options as usize] = ctx.remaining_accounts[3].key(); // pool
+    proposal.pools[curr_options as usize] = ctx.remaining_accounts[2].key(); // pool
     proposal.num_options += 1;
 
     // Build proposal PDA signer seeds
@@ -88,17 +94,17 @@ pub fn add_option_handler<'info>(
         AddVaultOption {
             payer: ctx.accounts.creator.to_account_info(),
             owner: proposal.to_account_info(),
-            vault: ctx.remaining_accounts[0].to_account_info(),
-            cond_base_mint: ctx.remaining_accounts[1].to_account_info(),
-            cond_quote_mint: ctx.remaining_accounts[2].to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+            cond_base_mint: ctx.remaining_accounts[0].to_account_info(),
+            cond_quote_mint: ctx.remaining_accounts[1].to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            conditional_token_program: ctx.accounts.conditional_token_program.to_account_info(),
             associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
         },
         signer_seeds,
     );
 
-    vault::cpi::add_option(add_option_ctx)?;
+    vault::cpi::add_option(add_option_ctx, uri, String::new())?;
 
     // Create pool
     let create_pool_ctx = CpiContext::new_with_signer(
@@ -106,15 +112,15 @@ pub fn add_option_handler<'info>(
         CreatePool {
             payer: ctx.accounts.creator.to_account_info(),
             admin: proposal.to_account_info(),
-            mint_a: ctx.remaining_accounts[2].to_account_info(), // cond_quote_mint
-            mint_b: ctx.remaining_accounts[1].to_account_info(), // cond_base_mint
-            pool: ctx.remaining_accounts[3].to_account_info(),   // pool
-            reserve_a: ctx.remaining_accounts[4].to_account_info(), // reserve_a
-            reserve_b: ctx.remaining_accounts[5].to_account_info(), // reserve_b
-            fee_authority: ctx.remaining_accounts[6].to_account_info(), // fee_authority
-            fee_vault: ctx.remaining_accounts[7].to_account_info(), // fee_vault
+            mint_a: ctx.remaining_accounts[1].to_account_info(), // cond_quote_mint
+            mint_b: ctx.remaining_accounts[0].to_account_info(), // cond_base_mint
+            pool: ctx.remaining_accounts[2].to_account_info(),   // pool
+            reserve_a: ctx.remaining_accounts[3].to_account_info(), // reserve_a
+            reserve_b: ctx.remaining_accounts[4].to_account_info(), // reserve_b
+            fee_authority: ctx.remaining_accounts[5].to_account_info(), // fee_authority
+            fee_vault: ctx.remaining_accounts[6].to_account_info(), // fee_vault
             system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            token_program: ctx.accounts.conditional_token_program.to_account_info(),
         },
         signer_seeds
     );
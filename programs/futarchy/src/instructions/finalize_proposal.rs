@@ -2,8 +2,9 @@ use amm::cpi::accounts::{CeaseTrading, CrankTwap};
 use amm::program::Amm;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::get_return_data;
-use vault::cpi::accounts::FinalizeVault;
+use vault::cpi::accounts::{ExpireVault, FinalizeVault};
 use vault::program::Vault;
+use vault::{VaultAccount, VaultState};
 
 use crate::state::proposal::*;
 use crate::errors::FutarchyError;
@@ -16,6 +17,12 @@ pub struct ProposalFinalized {
     pub winning_idx: u8,
 }
 
+#[event]
+pub struct ProposalVoided {
+    pub proposal_id: u16,
+    pub proposal: Pubkey,
+}
+
 #[derive(Accounts)]
 pub struct FinalizeProposal<'info> {
     // Permissionless
@@ -68,55 +75,29 @@ pub fn finalize_proposal_handler<'info>(
         FutarchyError::ProposalNotExpired
     );
 
-    // Crank TWAP and collect values from each pool
-    let mut twaps: Vec<u128> = Vec::with_capacity(num_options);
-
+    // Validate pools match proposal
     for i in 0..num_options {
-        let pool_idx = i * 3;
-
-        // Validate pool matches proposal
-        let pool_key = ctx.remaining_accounts[pool_idx].key();
         require!(
-            pool_key == proposal.pools[i],
+            ctx.remaining_accounts[i * 3].key() == proposal.pools[i],
             FutarchyError::InvalidPools
         );
-
-        // Crank TWAP to ensure fresh data
-        let crank_twap_ctx = CpiContext::new(
-            ctx.accounts.amm_program.to_account_info(),
-            CrankTwap {
-                pool: ctx.remaining_accounts[pool_idx].to_account_info(),
-                reserve_a: ctx.remaining_accounts[pool_idx + 1].to_account_info(),
-                reserve_b: ctx.remaining_accounts[pool_idx + 2].to_account_info(),
-            },
-        );
-        amm::cpi::crank_twap(crank_twap_ctx)?;
-
-        // Get TWAP from CPI return data
-        let (_, data) = get_return_data().ok_or(FutarchyError::TwapNotReady)?;
-        let twap: u128 = AnchorDeserialize::deserialize(&mut &data[..])
-            .map_err(|_| FutarchyError::TwapNotReady)?;
-        twaps.push(twap);
     }
 
-    // Find index with highest TWAP
-    let max_twap_idx = twaps
-        .iter()
-        .enumerate()
-        .max_by_key(|(_, &twap)| twap)
-        .map(|(idx, _)| idx as u8)
-        .unwrap_or(0);
-
-    // Decide winning index based on market bias (in bips)
-    // To win, max_twap * 10000 must be > twaps[0] * (10000 + market_bias)
-    let basis_points: u128 = 10000;
-    let threshold = twaps[0]
-        .checked_mul(basis_points + proposal.config.market_bias as u128)
-        .ok_or(FutarchyError::MathOverflow)?;
-    let max_twap_scaled = twaps[max_twap_idx as usize]
-        .checked_mul(basis_points)
-        .ok_or(FutarchyError::MathOverflow)?;
-    let winning_idx = if max_twap_scaled > threshold { max_twap_idx } else { 0 };
+    // Past the vault's resolution deadline the vault can only be voided,
+    // so the proposal resolves as void instead
+    let (vault_state, voided) = {
+        let vault_data = ctx.accounts.vault.try_borrow_data()?;
+        let vault = VaultAccount::try_deserialize(&mut &vault_data[..])?;
+        let voided = vault.state == VaultState::Voided
+            || (vault.state == VaultState::Active && vault.is_past_deadline(clock.unix_timestamp));
+        (vault.state, voided)
+    };
+
+    let winning_idx = if voided {
+        None
+    } else {
+        Some(compute_winning_idx(&ctx)?)
+    };
 
     // Build proposal PDA signer seeds
     let moderator_key = proposal.moderator;
@@ -138,12 +119,37 @@ pub fn finalize_proposal_handler<'info>(
             CeaseTrading {
                 admin: ctx.accounts.proposal.to_account_info(),
                 pool: ctx.remaining_accounts[pool_idx].to_account_info(),
+                reserve_a: ctx.remaining_accounts[pool_idx + 1].to_account_info(),
+                reserve_b: ctx.remaining_accounts[pool_idx + 2].to_account_info(),
             },
             signer_seeds,
         );
         amm::cpi::cease_trading(cease_trading_ctx)?;
     }
 
+    let Some(winning_idx) = winning_idx else {
+        // Void the vault if nobody has expired it yet
+        if vault_state == VaultState::Active {
+            let expire_ctx = CpiContext::new(
+                ctx.accounts.vault_program.to_account_info(),
+                ExpireVault {
+                    vault: ctx.accounts.vault.to_account_info(),
+                },
+            );
+            vault::cpi::expire(expire_ctx)?;
+        }
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.state = ProposalState::Voided;
+
+        emit!(ProposalVoided {
+            proposal_id: proposal.id,
+            proposal: proposal.key(),
+        });
+
+        return Ok(());
+    };
+
     // Finalize vault with winning index (proposal PDA as owner)
     let finalize_vault_ctx = CpiContext::new_with_signer(
         ctx.accounts.vault_program.to_account_info(),
@@ -168,3 +174,56 @@ pub fn finalize_proposal_handler<'info>(
 
     Ok(())
 }
+
+// Cranks each pool's TWAP and picks the winner, subject to the market bias
+fn compute_winning_idx<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, FinalizeProposal<'info>>,
+) -> Result<u8> {
+    let proposal = &ctx.accounts.proposal;
+    let num_options = proposal.num_options as usize;
+
+    // Crank TWAP and collect values from each pool
+    let mut twaps: Vec<u128> = Vec::with_capacity(num_options);
+
+    for i in 0..num_options {
+        let pool_idx = i * 3;
+
+        // Crank TWAP to ensure fresh data
+        let crank_twap_ctx = CpiContext::new(
+            ctx.accounts.amm_program.to_account_info(),
+            CrankTwap {
+                pool: ctx.remaining_accounts[pool_idx].to_account_info(),
+                reserve_a: ctx.remaining_accounts[pool_idx + 1].to_account_info(),
+                reserve_b: ctx.remaining_accounts[pool_idx + 2].to_account_info(),
+            },
+        );
+        amm::cpi::crank_twap(crank_twap_ctx)?;
+
+        // Get TWAP from CPI return data
+        let (_, data) = get_return_data().ok_or(FutarchyError::TwapNotReady)?;
+        let twap: u128 = AnchorDeserialize::deserialize(&mut &data[..])
+            .map_err(|_| FutarchyError::TwapNotReady)?;
+        twaps.push(twap);
+    }
+
+    // Find index with highest TWAP
+    let max_twap_idx = twaps
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, &twap)| twap)
+        .map(|(idx, _)| idx as u8)
+        .unwrap_or(0);
+
+    // Decide winning index based on market bias (in bips)
+    // To win, max_twap * 10000 must be > twaps[0] * (10000 + market_bias)
+    let basis_points: u128 = 10000;
+    let threshold = twaps[0]
+        .checked_mul(basis_points + proposal.config.market_bias as u128)
+        .ok_or(FutarchyError::MathOverflow)?;
+    let max_twap_scaled = twaps[max_twap_idx as usize]
+        .checked_mul(basis_points)
+        .ok_or(FutarchyError::MathOverflow)?;
+    let winning_idx = if max_twap_scaled > threshold { max_twap_idx } else { 0 };
+
+    Ok(winning_idx)
+}
@@ -142,11 +142,11 @@ pub fn initialize_child_dao_handler(
 
     emit!(DAOInitialized {
         version: DAO_VERSION,
-        name: name,
+        name,
         admin: ctx.accounts.admin.key(),
         treasury_multisig: ctx.accounts.treasury_multisig.key(),
         mint_multisig: ctx.accounts.mint_multisig.key(),
-        dao_type: dao_type,
+        dao_type,
     });
 
     Ok(())
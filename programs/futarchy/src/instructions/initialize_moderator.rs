@@ -58,7 +58,7 @@ pub fn initialize_moderator_handler(
 
     emit!(ModeratorInitialized {
         version: MODERATOR_VERSION,
-        name: name,
+        name,
         moderator: moderator.key(),
         admin: moderator.admin,
         base_mint: moderator.base_mint,
@@ -136,7 +136,7 @@ pub fn initialize_parent_dao_handler(
     let moderator = &mut ctx.accounts.moderator;
     moderator.set_inner(ModeratorAccount {
         version: MODERATOR_VERSION,
-        bump: ctx.bumps.moderator.clone(),
+        bump: ctx.bumps.moderator,
         name: name.clone(),
         quote_mint: ctx.accounts.quote_mint.key(),
         base_mint: ctx.accounts.base_mint.key(),
@@ -171,11 +171,11 @@ pub fn initialize_parent_dao_handler(
 
     emit!(DAOInitialized {
         version: DAO_VERSION,
-        name: name,
+        name,
         admin: ctx.accounts.admin.key(),
         treasury_multisig: ctx.accounts.treasury_multisig.key(),
         mint_multisig: ctx.accounts.mint_multisig.key(),
-        dao_type: dao_type,
+        dao_type,
     });
 
     Ok(())
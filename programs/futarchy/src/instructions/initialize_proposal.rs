@@ -1,6 +1,6 @@
 use amm::cpi::accounts::CreatePool;
 use anchor_lang::prelude::*;
-use vault::VAULT_VERSION;
+use vault::{PROTOCOL_CONFIG_SEED, VAULT_VERSION};
 use vault::cpi::accounts::InitializeVault;
 
 use crate::state::moderator::{ModeratorAccount, MODERATOR_SEED};
@@ -9,6 +9,7 @@ use crate::errors::FutarchyError;
 use amm::program::Amm;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::Token;
+use anchor_spl::token_2022::Token2022;
 use vault::program::Vault;
 
 #[event]
@@ -52,11 +53,16 @@ pub struct InitializeProposal<'info> {
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
 
+    /// CHECK: Vault program's protocol config PDA, validated by the vault CPI
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump, seeds::program = vault_program.key())]
+    pub protocol_config: UncheckedAccount<'info>,
+
     // Programs
     pub system_program: Program<'info, System>,
     pub vault_program: Program<'info, Vault>,
     pub amm_program: Program<'info, Amm>,
     pub token_program: Program<'info, Token>,
+    pub conditional_token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     // Remaining accounts (in order):
@@ -84,6 +90,7 @@ pub fn initialize_proposal_handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, InitializeProposal<'info>>,
     proposal_params: ProposalParams,
     metadata: Option<String>,
+    permanent_delegate: bool, // Lets anyone sweep holders' winnings once resolved (see redeem_for)
 ) -> Result<u16> {
     require!(
         ctx.remaining_accounts.len() == 18,
@@ -154,14 +161,25 @@ pub fn initialize_proposal_handler<'info>(
             cond_quote_mint_0: ctx.remaining_accounts[7].to_account_info(),
             cond_base_mint_1: ctx.remaining_accounts[6].to_account_info(),
             cond_quote_mint_1: ctx.remaining_accounts[8].to_account_info(),
+            protocol_config: ctx.accounts.protocol_config.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            // Moderator mints are SPL Token
+            base_token_program: ctx.accounts.token_program.to_account_info(),
+            quote_token_program: ctx.accounts.token_program.to_account_info(),
+            conditional_token_program: ctx.accounts.conditional_token_program.to_account_info(),
             associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
         },
         signer_seeds,
     );
 
-    vault::cpi::initialize(init_vault_ctx, proposal_id)?;
+    vault::cpi::initialize(
+        init_vault_ctx,
+        proposal_id,
+        None,
+        None,
+        [String::new(), String::new()],
+        permanent_delegate,
+    )?;
 
     // Create pool 0
     let create_pool_0_ctx = CpiContext::new_with_signer(
@@ -177,7 +195,7 @@ pub fn initialize_proposal_handler<'info>(
             fee_authority: ctx.remaining_accounts[12].to_account_info(), // fee_authority
             fee_vault: ctx.remaining_accounts[13].to_account_info(), // fee_vault_0
             system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            token_program: ctx.accounts.conditional_token_program.to_account_info(),
         },
         signer_seeds
     );
@@ -205,7 +223,7 @@ pub fn initialize_proposal_handler<'info>(
             fee_authority: ctx.remaining_accounts[12].to_account_info(), // fee_authority
             fee_vault: ctx.remaining_accounts[17].to_account_info(), // fee_vault_1
             system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
+            token_program: ctx.accounts.conditional_token_program.to_account_info(),
         },
         signer_seeds
     );
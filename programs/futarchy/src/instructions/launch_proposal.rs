@@ -1,14 +1,16 @@
 use amm::cpi::accounts::AddLiquidity;
 use anchor_lang::prelude::*;
-use vault::cpi::accounts::{ActivateVault, UserVaultAction};
+use vault::cpi::accounts::{ActivateVault, DualVaultAction, SetResolutionDeadline};
 
+use crate::constants::VAULT_RESOLUTION_GRACE_PERIOD;
 use crate::errors::FutarchyError;
 use crate::state::proposal::*;
 use amm::program::Amm;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::Token;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use vault::program::Vault;
-use vault::VaultType;
+use vault::VaultAccount;
 
 #[event]
 pub struct ProposalLaunched {
@@ -41,15 +43,16 @@ pub struct LaunchProposal<'info> {
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
 
-    /// CHECK: Validated via CPI to vault program
     #[account(mut)]
-    pub vault: UncheckedAccount<'info>,
+    pub vault: Box<Account<'info, VaultAccount>>,
 
     // Programs
     pub system_program: Program<'info, System>,
     pub vault_program: Program<'info, Vault>,
     pub amm_program: Program<'info, Amm>,
     pub token_program: Program<'info, Token>,
+    #[account(address = vault.conditional_token_program @ FutarchyError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     // Remaining accounts (for N options):
@@ -104,7 +107,25 @@ pub fn launch_proposal_handler<'info>(
     ];
     let signer_seeds = &[&proposal_seeds[..]];
 
-    // 1. Activate vault via CPI (proposal PDA as signer)
+    // 1. Set resolution deadline & activate vault via CPI (proposal PDA as signer)
+    // Past the deadline anyone can void the vault, so funds aren't locked if the proposal never finalizes
+    let now = Clock::get()?.unix_timestamp;
+    let resolution_deadline = now
+        .checked_add(ctx.accounts.proposal.config.length as i64)
+        .and_then(|end| end.checked_add(VAULT_RESOLUTION_GRACE_PERIOD))
+        .ok_or(FutarchyError::MathOverflow)?;
+
+    let deadline_ctx = CpiContext::new_with_signer(
+        ctx.accounts.vault_program.to_account_info(),
+        SetResolutionDeadline {
+            owner: ctx.accounts.proposal.to_account_info(),
+            vault: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+    vault::cpi::set_resolution_deadline(deadline_ctx, Some(resolution_deadline))?;
+
+    // Activate vault
     let activate_ctx = CpiContext::new_with_signer(
         ctx.accounts.vault_program.to_account_info(),
         ActivateVault {
@@ -116,57 +137,50 @@ pub fn launch_proposal_handler<'info>(
     );
     vault::cpi::activate(activate_ctx)?;
 
-    // 2. Deposit base tokens (splits into N conditional base tokens)
-    // Build remaining_accounts for deposit: cond_base_mints + user_cond_base_atas (interleaved)
-    let mut base_deposit_remaining: Vec<AccountInfo<'info>> = Vec::with_capacity(num_options * 2);
-    for i in 0..num_options {
-        base_deposit_remaining.push(ctx.remaining_accounts[6 + i].to_account_info()); // cond_base_mint[i]
-        base_deposit_remaining.push(ctx.remaining_accounts[6 + 2 * num_options + i].to_account_info()); // user_cond_base_ata[i]
-    }
+    // 2. Deposit base & quote tokens (splits each into N conditional tokens)
+    // Remaining accounts 6..6+4N already match deposit_both's layout
+    let deposit_remaining = ctx.remaining_accounts[6..6 + 4 * num_options].to_vec();
 
-    let deposit_base_ctx = CpiContext::new(
+    // Creator's conditional ATAs (base, then quote), which deposit_both creates if missing
+    let user_cond_atas = &ctx.remaining_accounts[6 + 2 * num_options..6 + 4 * num_options];
+    let balances_before = user_cond_atas
+        .iter()
+        .map(token_balance)
+        .collect::<Result<Vec<_>>>()?;
+
+    let deposit_ctx = CpiContext::new(
         ctx.accounts.vault_program.to_account_info(),
-        UserVaultAction {
+        DualVaultAction {
             signer: ctx.accounts.creator.to_account_info(),
             vault: ctx.accounts.vault.to_account_info(),
-            mint: ctx.remaining_accounts[0].to_account_info(), // base_mint
-            vault_ata: ctx.remaining_accounts[2].to_account_info(), // vault_base_ata
-            user_ata: ctx.remaining_accounts[4].to_account_info(), // user_base_ata
-            token_program: ctx.accounts.token_program.to_account_info(),
+            base_mint: ctx.remaining_accounts[0].to_account_info(),
+            quote_mint: ctx.remaining_accounts[1].to_account_info(),
+            vault_base_ata: ctx.remaining_accounts[2].to_account_info(),
+            vault_quote_ata: ctx.remaining_accounts[3].to_account_info(),
+            user_base_ata: ctx.remaining_accounts[4].to_account_info(),
+            user_quote_ata: ctx.remaining_accounts[5].to_account_info(),
+            base_token_program: ctx.accounts.token_program.to_account_info(),
+            quote_token_program: ctx.accounts.token_program.to_account_info(),
+            conditional_token_program: ctx.accounts.conditional_token_program.to_account_info(),
             associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
         },
     )
-    .with_remaining_accounts(base_deposit_remaining);
+    .with_remaining_accounts(deposit_remaining);
 
-    vault::cpi::deposit(deposit_base_ctx, VaultType::Base, base_amount)?;
+    vault::cpi::deposit_both(deposit_ctx, base_amount, quote_amount)?;
 
-    // 3. Deposit quote tokens (splits into N conditional quote tokens)
-    // Build remaining_accounts for deposit: cond_quote_mints + user_cond_quote_atas (interleaved)
-    let mut quote_deposit_remaining: Vec<AccountInfo<'info>> = Vec::with_capacity(num_options * 2);
-    for i in 0..num_options {
-        quote_deposit_remaining.push(ctx.remaining_accounts[6 + num_options + i].to_account_info()); // cond_quote_mint[i]
-        quote_deposit_remaining.push(ctx.remaining_accounts[6 + 3 * num_options + i].to_account_info()); // user_cond_quote_ata[i]
+    // Conditional tokens minted (net of the vault's protocol deposit fee), read back from the ATAs
+    let mut minted = Vec::with_capacity(2 * num_options);
+    for (ata, before) in user_cond_atas.iter().zip(balances_before) {
+        minted.push(
+            token_balance(ata)?
+                .checked_sub(before)
+                .ok_or(FutarchyError::MathOverflow)?,
+        );
     }
 
-    let deposit_quote_ctx = CpiContext::new(
-        ctx.accounts.vault_program.to_account_info(),
-        UserVaultAction {
-            signer: ctx.accounts.creator.to_account_info(),
-            vault: ctx.accounts.vault.to_account_info(),
-            mint: ctx.remaining_accounts[1].to_account_info(), // quote_mint
-            vault_ata: ctx.remaining_accounts[3].to_account_info(), // vault_quote_ata
-            user_ata: ctx.remaining_accounts[5].to_account_info(), // user_quote_ata
-            token_program: ctx.accounts.token_program.to_account_info(),
-            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-        },
-    )
-    .with_remaining_accounts(quote_deposit_remaining);
-
-    vault::cpi::deposit(deposit_quote_ctx, VaultType::Quote, quote_amount)?;
-
-    // 4. For each pool: add_liquidity with conditional tokens
+    // 3. For each pool: add_liquidity with conditional tokens
     // Pool mint_a = cond_quote, mint_b = cond_base (see initialize_proposal.rs)
     for i in 0..num_options {
         // Validate pool matches proposal
@@ -181,21 +195,23 @@ pub fn launch_proposal_handler<'info>(
             AddLiquidity {
                 depositor: ctx.accounts.creator.to_account_info(),
                 pool: ctx.remaining_accounts[6 + 4 * num_options + i].to_account_info(), // pool[i]
+                mint_a: ctx.remaining_accounts[6 + num_options + i].to_account_info(), // cond_quote_mint[i]
+                mint_b: ctx.remaining_accounts[6 + i].to_account_info(), // cond_base_mint[i]
                 reserve_a: ctx.remaining_accounts[6 + 5 * num_options + i].to_account_info(), // reserve_a[i]
                 reserve_b: ctx.remaining_accounts[6 + 6 * num_options + i].to_account_info(), // reserve_b[i]
                 depositor_token_acc_a: ctx.remaining_accounts[6 + 3 * num_options + i].to_account_info(), // user_cond_quote_ata[i] (mint_a = cond_quote)
                 depositor_token_acc_b: ctx.remaining_accounts[6 + 2 * num_options + i].to_account_info(), // user_cond_base_ata[i] (mint_b = cond_base)
-                token_program: ctx.accounts.token_program.to_account_info(),
+                token_program: ctx.accounts.conditional_token_program.to_account_info(),
             },
         );
 
-        amm::cpi::add_liquidity(add_liq_ctx, quote_amount, base_amount)?;
+        amm::cpi::add_liquidity(add_liq_ctx, minted[num_options + i], minted[i])?;
     }
 
-    // 5. Set proposal state to Pending
+    // 4. Set proposal state to Pending
     let proposal = &mut ctx.accounts.proposal;
     proposal.state = ProposalState::Pending;
-    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.created_at = now;
 
     emit!(ProposalLaunched {
         proposal_id: proposal.id,
@@ -208,3 +224,12 @@ pub fn launch_proposal_handler<'info>(
 
     Ok(())
 }
+
+/// Amount held by a token account, zero if it doesn't exist yet
+fn token_balance(info: &AccountInfo) -> Result<u64> {
+    if info.data_is_empty() {
+        return Ok(0);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
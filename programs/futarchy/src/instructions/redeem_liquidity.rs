@@ -2,10 +2,11 @@ use amm::cpi::accounts::RemoveLiquidity;
 use amm::program::Amm;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use vault::cpi::accounts::UserVaultAction;
 use vault::program::Vault;
-use vault::VaultType;
+use vault::{VaultAccount, VaultType};
 
 use crate::errors::FutarchyError;
 use crate::state::proposal::*;
@@ -15,7 +16,8 @@ pub struct LiquidityRedeemed {
     pub proposal_id: u16,
     pub proposal: Pubkey,
     pub redeemer: Pubkey,
-    pub winning_idx: u8,
+    pub winning_idx: u8, // 0 when voided
+    pub voided: bool,
 }
 
 #[derive(Accounts)]
@@ -38,17 +40,18 @@ pub struct RedeemLiquidity<'info> {
     )]
     pub proposal: Box<Account<'info, ProposalAccount>>,
 
-    /// CHECK: Validated via constraint and CPI
     #[account(mut)]
-    pub vault: UncheckedAccount<'info>,
+    pub vault: Box<Account<'info, VaultAccount>>,
 
-    /// CHECK: Winning pool - validated in handler against proposal.pools[winning_idx]
+    /// CHECK: Winning pool (pool 0 if voided) - validated in handler against proposal.pools
     #[account(mut)]
     pub pool: UncheckedAccount<'info>,
 
     pub vault_program: Program<'info, Vault>,
     pub amm_program: Program<'info, Amm>,
     pub token_program: Program<'info, Token>,
+    #[account(address = vault.conditional_token_program @ FutarchyError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     // Remaining accounts layout (for N options):
@@ -69,6 +72,9 @@ pub struct RedeemLiquidity<'info> {
     //   7+2N+1: vault_quote_ata
     //   7+2N+2: user_quote_ata
     //   7+2N+3..7+4N+3: [cond_quote_mint_i, user_cond_quote_ata_i] for i in 0..N
+    //
+    // Voided proposals only, every other pool (3 * (N - 1) accounts):
+    //   10+4N+3(i-1)..: [pool_i, reserve_a_i, reserve_b_i] for i in 1..N
 }
 
 pub fn redeem_liquidity_handler<'info>(
@@ -77,9 +83,11 @@ pub fn redeem_liquidity_handler<'info>(
     let proposal = &ctx.accounts.proposal;
     let num_options = proposal.num_options as usize;
 
-    // Extract winning_idx from proposal state
-    let ProposalState::Resolved(winning_idx) = proposal.state else {
-        return err!(FutarchyError::InvalidState);
+    // Extract winning_idx from proposal state; a voided vault pays on every option
+    let (winning_idx, voided) = match proposal.state {
+        ProposalState::Resolved(winning_idx) => (winning_idx, false),
+        ProposalState::Voided => (0, true),
+        _ => return err!(FutarchyError::InvalidState),
     };
 
     // Validate pool matches winning pool
@@ -88,38 +96,59 @@ pub fn redeem_liquidity_handler<'info>(
         FutarchyError::InvalidPools
     );
 
-    // Validate remaining accounts length: 4 + 3 + 2N + 3 + 2N = 10 + 4N
-    let expected_remaining = 10 + 4 * num_options;
+    // Validate remaining accounts length: 4 + 3 + 2N + 3 + 2N = 10 + 4N, plus 3(N - 1) if voided
+    let fixed_remaining = 10 + 4 * num_options;
+    let expected_remaining = if voided {
+        fixed_remaining + 3 * (num_options - 1)
+    } else {
+        fixed_remaining
+    };
     require!(
         ctx.remaining_accounts.len() >= expected_remaining,
         FutarchyError::InvalidRemainingAccounts
     );
 
-    // Read reserve amounts to determine how much to withdraw
-    let reserve_a_data = ctx.remaining_accounts[0].try_borrow_data()?;
-    let reserve_a = TokenAccount::try_deserialize(&mut &reserve_a_data[..])?;
-    let amount_a = reserve_a.amount;
-    drop(reserve_a_data);
+    // Pool i mints: mint_a = cond_quote_mint[i], mint_b = cond_base_mint[i]
+    let quote_cond_start = 7 + 2 * num_options + 3;
+    let w = winning_idx as usize;
 
-    let reserve_b_data = ctx.remaining_accounts[1].try_borrow_data()?;
-    let reserve_b = TokenAccount::try_deserialize(&mut &reserve_b_data[..])?;
-    let amount_b = reserve_b.amount;
-    drop(reserve_b_data);
+    // 1. CPI to amm::remove_liquidity on the winning pool (pool 0 if voided)
+    remove_all_liquidity(
+        &ctx,
+        &ctx.accounts.pool,
+        [&ctx.remaining_accounts[0], &ctx.remaining_accounts[1]],
+        [
+            &ctx.remaining_accounts[quote_cond_start + 2 * w],
+            &ctx.remaining_accounts[7 + 2 * w],
+        ],
+        [&ctx.remaining_accounts[2], &ctx.remaining_accounts[3]],
+    )?;
 
-    // 1. CPI to amm::remove_liquidity
-    let remove_liq_ctx = CpiContext::new(
-        ctx.accounts.amm_program.to_account_info(),
-        RemoveLiquidity {
-            depositor: ctx.accounts.creator.to_account_info(),
-            pool: ctx.accounts.pool.to_account_info(),
-            reserve_a: ctx.remaining_accounts[0].to_account_info(),
-            reserve_b: ctx.remaining_accounts[1].to_account_info(),
-            depositor_token_acc_a: ctx.remaining_accounts[2].to_account_info(),
-            depositor_token_acc_b: ctx.remaining_accounts[3].to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
-    amm::cpi::remove_liquidity(remove_liq_ctx, amount_a, amount_b)?;
+    // Voided: every other pool's conditional tokens also redeem, so unwind them too
+    if voided {
+        for i in 1..num_options {
+            let pool_idx = fixed_remaining + 3 * (i - 1);
+            let pool = &ctx.remaining_accounts[pool_idx];
+            require!(pool.key() == proposal.pools[i], FutarchyError::InvalidPools);
+
+            remove_all_liquidity(
+                &ctx,
+                pool,
+                [
+                    &ctx.remaining_accounts[pool_idx + 1],
+                    &ctx.remaining_accounts[pool_idx + 2],
+                ],
+                [
+                    &ctx.remaining_accounts[quote_cond_start + 2 * i],
+                    &ctx.remaining_accounts[7 + 2 * i],
+                ],
+                [
+                    &ctx.remaining_accounts[quote_cond_start + 2 * i + 1], // user_cond_quote_ata_i
+                    &ctx.remaining_accounts[7 + 2 * i + 1],                // user_cond_base_ata_i
+                ],
+            )?;
+        }
+    }
 
     // Build remaining accounts for redeem_winnings base
     // Indices: 7..7+2N
@@ -141,6 +170,7 @@ pub fn redeem_liquidity_handler<'info>(
             vault_ata: ctx.remaining_accounts[5].to_account_info(), // vault_base_ata
             user_ata: ctx.remaining_accounts[6].to_account_info(), // user_base_ata
             token_program: ctx.accounts.token_program.to_account_info(),
+            conditional_token_program: ctx.accounts.conditional_token_program.to_account_info(),
             associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
         },
@@ -170,6 +200,7 @@ pub fn redeem_liquidity_handler<'info>(
             vault_ata: ctx.remaining_accounts[quote_fixed_start + 1].to_account_info(), // vault_quote_ata
             user_ata: ctx.remaining_accounts[quote_fixed_start + 2].to_account_info(), // user_quote_ata
             token_program: ctx.accounts.token_program.to_account_info(),
+            conditional_token_program: ctx.accounts.conditional_token_program.to_account_info(),
             associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
         },
@@ -183,7 +214,45 @@ pub fn redeem_liquidity_handler<'info>(
         proposal: proposal.key(),
         redeemer: ctx.accounts.creator.key(),
         winning_idx,
+        voided,
     });
 
     Ok(())
 }
+
+// Withdraws a pool's full reserves to the creator, its sole liquidity provider.
+// Pairs are in [mint_a, mint_b] order
+fn remove_all_liquidity<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, RedeemLiquidity<'info>>,
+    pool: &AccountInfo<'info>,
+    reserves: [&AccountInfo<'info>; 2],
+    mints: [&AccountInfo<'info>; 2],
+    depositor_accs: [&AccountInfo<'info>; 2],
+) -> Result<()> {
+    // Read reserve amounts to determine how much to withdraw
+    let reserve_a_data = reserves[0].try_borrow_data()?;
+    let reserve_a = TokenAccount::try_deserialize(&mut &reserve_a_data[..])?;
+    let amount_a = reserve_a.amount;
+    drop(reserve_a_data);
+
+    let reserve_b_data = reserves[1].try_borrow_data()?;
+    let reserve_b = TokenAccount::try_deserialize(&mut &reserve_b_data[..])?;
+    let amount_b = reserve_b.amount;
+    drop(reserve_b_data);
+
+    let remove_liq_ctx = CpiContext::new(
+        ctx.accounts.amm_program.to_account_info(),
+        RemoveLiquidity {
+            depositor: ctx.accounts.creator.to_account_info(),
+            pool: pool.to_account_info(),
+            mint_a: mints[0].to_account_info(),
+            mint_b: mints[1].to_account_info(),
+            reserve_a: reserves[0].to_account_info(),
+            reserve_b: reserves[1].to_account_info(),
+            depositor_token_acc_a: depositor_accs[0].to_account_info(),
+            depositor_token_acc_b: depositor_accs[1].to_account_info(),
+            token_program: ctx.accounts.conditional_token_program.to_account_info(),
+        },
+    );
+    amm::cpi::remove_liquidity(remove_liq_ctx, amount_a, amount_b)
+}
@@ -83,7 +83,7 @@ pub fn upgrade_dao_handler(
     let moderator = &mut ctx.accounts.moderator;
     moderator.set_inner(ModeratorAccount {
         version: MODERATOR_VERSION,
-        bump: ctx.bumps.moderator.clone(),
+        bump: ctx.bumps.moderator,
         name: dao.name.clone(),
         quote_mint: ctx.accounts.quote_mint.key(),
         base_mint: ctx.accounts.base_mint.key(),
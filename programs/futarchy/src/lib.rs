@@ -25,16 +25,21 @@ pub mod futarchy {
         ctx: Context<'_, '_, 'info, 'info, InitializeProposal<'info>>,
         proposal_params: ProposalParams,
         metadata: Option<String>,
+        permanent_delegate: bool,
     ) -> Result<u16> {
         instructions::initialize_proposal::initialize_proposal_handler(
             ctx,
             proposal_params,
-            metadata
+            metadata,
+            permanent_delegate,
         )
     }
 
-    pub fn add_option<'info>(ctx: Context<'_, '_, 'info, 'info, AddOption<'info>>) -> Result<()> {
-        instructions::add_option::add_option_handler(ctx)
+    pub fn add_option<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddOption<'info>>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::add_option::add_option_handler(ctx, uri)
     }
 
     pub fn launch_proposal<'info>(
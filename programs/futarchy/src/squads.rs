@@ -33,6 +33,7 @@ pub struct Permissions {
 }
 
 impl SquadsMultisig {
+    #[allow(clippy::too_many_arguments)]
     pub fn create_squads_multisig<'info>(
         program_config: AccountInfo<'info>,
         treasury: AccountInfo<'info>,
@@ -103,7 +104,7 @@ impl SquadsMultisig {
                 },
             ],
             time_lock: 0,
-            rent_collector: rent_collector,
+            rent_collector,
             memo: None,
         }
     }
@@ -125,7 +126,7 @@ impl SquadsMultisig {
                 },
             ],
             time_lock: 0,
-            rent_collector: rent_collector,
+            rent_collector,
             memo: None,
         }
     }
@@ -12,6 +12,7 @@ pub enum ProposalState {
     Setup,        // Options being added
     Pending,      // Betting active
     Resolved(u8), // Index of the winning option
+    Voided,       // Vault expired unresolved; every option redeems at 1/N
 }
 
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy)]
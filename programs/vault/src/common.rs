@@ -18,12 +18,14 @@
  */
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{ID as TOKEN_PROGRAM_ID, Mint, Token, TokenAccount};
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 
 use crate::constants::*;
 use crate::errors::VaultError;
 use crate::state::*;
+use crate::utils::{create_associated_token_account, create_pda_account, mint_to_signed};
 
 #[derive(Accounts)]
 #[instruction(vault_type: VaultType)]
@@ -50,7 +52,7 @@ pub struct UserVaultAction<'info> {
             vault.quote_mint.address
         } @ VaultError::InvalidMint
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Escrow ATA for regular mint
     #[account(
@@ -59,20 +61,18 @@ pub struct UserVaultAction<'info> {
         associated_token::authority = vault,
         associated_token::token_program = token_program,
     )]
-    pub vault_ata: Account<'info, TokenAccount>,
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // User ATA for regular mint
-    #[account(
-        init_if_needed,
-        payer = signer,
-        associated_token::mint = mint,
-        associated_token::authority = signer,
-        associated_token::token_program = token_program,
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
+    /// CHECK: User token account for regular mint; any of the signer's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_ata`
+    #[account(mut)]
+    pub user_ata: UncheckedAccount<'info>,
 
     // Programs
-    pub token_program: Program<'info, Token>,
+    // Token program of the regular mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 
@@ -80,20 +80,106 @@ pub struct UserVaultAction<'info> {
     // Expected order for each option i:
     // - remaining_accounts[i * 2 + 0]: cond_mint_i
     // - remaining_accounts[i * 2 + 1]: user_cond_ata_i (may need init)
+    // Deposits into vaults with an allow-list or per-user caps also take:
+    // - remaining_accounts[2N]: user's deposit record (see check_deposit_limits)
 }
 
-impl UserVaultAction<'_> {
-    pub fn validate_user_ata(mint: &Pubkey, user: &Pubkey, user_ata_info: &AccountInfo) -> Result<()>{
-        let expected_user_ata = get_associated_token_address(user, mint);
+impl<'info> UserVaultAction<'info> {
+    /// Reloads the escrow and checks it still covers outstanding conditional supply
+    pub fn check_escrow(&mut self, vault_type: VaultType) -> Result<()> {
+        self.vault_ata.reload()?;
+        self.vault.check_escrow(vault_type, self.vault_ata.amount)
+    }
+
+    /// Validates the user's regular token account, creating their ATA if it's missing
+    pub fn init_user_ata(&self) -> Result<()> {
+        Self::init_user_token_account(
+            self.signer.to_account_info(),
+            self.signer.to_account_info(),
+            self.user_ata.to_account_info(),
+            self.mint.to_account_info(),
+            self.token_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+
+    /// Validates a user token account, creating the user's ATA (paid by `payer`) if it's missing
+    pub fn init_user_token_account(
+        payer: AccountInfo<'info>,
+        user: AccountInfo<'info>,
+        user_token_account: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        associated_token_program: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+    ) -> Result<()> {
+        Self::validate_user_token_account(
+            &mint.key(),
+            &user.key(),
+            &token_program.key(),
+            &user_token_account,
+        )?;
+
+        if user_token_account.data_is_empty() {
+            create_associated_token_account(
+                payer,
+                user_token_account,
+                user,
+                mint,
+                token_program,
+                associated_token_program,
+                system_program,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Accepts any token account of `user` for `mint` (smart wallets, PDAs with several accounts),
+    /// or the user's ATA if it doesn't exist yet
+    pub fn validate_user_token_account(
+        mint: &Pubkey,
+        user: &Pubkey,
+        token_program: &Pubkey,
+        user_token_account_info: &AccountInfo,
+    ) -> Result<()> {
+        if user_token_account_info.data_is_empty() {
+            return Self::validate_user_ata(mint, user, token_program, user_token_account_info);
+        }
+
+        require!(
+            user_token_account_info.owner == token_program,
+            VaultError::InvalidAccountOwner
+        );
+
+        let user_token_account =
+            TokenAccount::try_deserialize(&mut &user_token_account_info.try_borrow_data()?[..])?;
+        require!(
+            user_token_account.mint == *mint && user_token_account.owner == *user,
+            VaultError::InvalidUserTokenAccount
+        );
+
+        Ok(())
+    }
+
+    pub fn validate_user_ata(
+        mint: &Pubkey,
+        user: &Pubkey,
+        token_program: &Pubkey,
+        user_ata_info: &AccountInfo,
+    ) -> Result<()> {
+        let expected_user_ata =
+            get_associated_token_address_with_program_id(user, mint, token_program);
         require!(
             user_ata_info.key() == expected_user_ata,
             VaultError::InvalidUserAta
         );
 
-        // Validate it's owned by the Token program, only if uninitialized
+        // Validate it's owned by the token program, only if initialized
         if !user_ata_info.data_is_empty() {
             require!(
-                user_ata_info.owner == &TOKEN_PROGRAM_ID,
+                user_ata_info.owner == token_program,
                 VaultError::InvalidAccountOwner
             );
         }
@@ -101,3 +187,453 @@ impl UserVaultAction<'_> {
         Ok(())
     }
 }
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType, mask: u64)]
+pub struct SetVaultAction<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+        // OR-position mints are Token-2022 with metadata
+        constraint = vault.conditional_token_program == Token2022::id() @ VaultError::Token2022Required,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Escrow ATA for the underlying; unchanged here, its balance is reported in the event
+    #[account(
+        associated_token::mint = vault.underlying(vault_type).address,
+        associated_token::authority = vault,
+        associated_token::token_program = vault_ata.to_account_info().owner,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // OR-position mint for the option set, created on first merge
+    #[account(
+        init_if_needed,
+        payer = signer,
+        mint::decimals = vault.underlying(vault_type).decimals,
+        mint::authority = vault,
+        mint::token_program = conditional_token_program,
+        extensions::metadata_pointer::authority = vault,
+        extensions::close_authority::authority = vault,
+        extensions::metadata_pointer::metadata_address = set_mint,
+        seeds = [
+            CONDITIONAL_SET_MINT_SEED,
+            vault.key().as_ref(),
+            &[vault_type as u8],
+            &mask.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub set_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: User token account for the OR-position mint; any of the signer's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub user_set_ata: UncheckedAccount<'info>,
+
+    // Programs
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Conditional mints passed via remaining_accounts
+    // Expected order for each option i in the set, ascending:
+    // - remaining_accounts[j * 2 + 0]: cond_mint_i
+    // - remaining_accounts[j * 2 + 1]: user_cond_ata_i (may need init)
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct ReceiptVaultAction<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+        // Receipt mints are Token-2022 with metadata
+        constraint = vault.conditional_token_program == Token2022::id() @ VaultError::Token2022Required,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Escrow ATA for the underlying; unchanged here, its balance is reported in the event
+    #[account(
+        associated_token::mint = vault.underlying(vault_type).address,
+        associated_token::authority = vault,
+        associated_token::token_program = vault_ata.to_account_info().owner,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receipt mint for a complete set, created on first wrap
+    #[account(
+        init_if_needed,
+        payer = signer,
+        mint::decimals = vault.underlying(vault_type).decimals,
+        mint::authority = vault,
+        mint::token_program = conditional_token_program,
+        extensions::metadata_pointer::authority = vault,
+        extensions::close_authority::authority = vault,
+        extensions::metadata_pointer::metadata_address = receipt_mint,
+        seeds = [
+            RECEIPT_MINT_SEED,
+            vault.key().as_ref(),
+            &[vault_type as u8],
+        ],
+        bump,
+    )]
+    pub receipt_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: User token account for the receipt mint; any of the signer's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub user_receipt_ata: UncheckedAccount<'info>,
+
+    // Programs
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Conditional mints passed via remaining_accounts
+    // Expected order for each option i:
+    // - remaining_accounts[i * 2 + 0]: cond_mint_i
+    // - remaining_accounts[i * 2 + 1]: user_cond_ata_i (may need init)
+}
+
+#[derive(Accounts)]
+pub struct DualVaultAction<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Regular mints
+    #[account(address = vault.base_mint.address @ VaultError::InvalidMint)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(address = vault.quote_mint.address @ VaultError::InvalidMint)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATAs for regular mints
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_base_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_quote_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: User token accounts for regular mints; any of the signer's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub user_base_ata: UncheckedAccount<'info>,
+    /// CHECK: See `user_base_ata`
+    #[account(mut)]
+    pub user_quote_ata: UncheckedAccount<'info>,
+
+    // Programs
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Conditional mints passed via remaining_accounts (for N options):
+    // 0..N: cond_base_mints[0..N]
+    // N..2N: cond_quote_mints[0..N]
+    // 2N..3N: user_cond_base_atas[0..N] (may need init on deposit)
+    // 3N..4N: user_cond_quote_atas[0..N] (may need init on deposit)
+    // 4N: user's deposit record, for deposits into vaults with an allow-list or per-user caps
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct OperatorVaultAction<'info> {
+    // Token delegate of the owner's accounts, or an approved operator; pays for missing accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Owner of the positions; authority comes from token delegation or `operator_approval`
+    pub owner: UncheckedAccount<'info>,
+
+    // Present for operators, absent when the signer acts as token delegate
+    #[account(
+        seeds = [
+            OPERATOR_APPROVAL_SEED,
+            vault.key().as_ref(),
+            owner.key().as_ref(),
+            signer.key().as_ref(),
+        ],
+        bump = operator_approval.bump,
+    )]
+    pub operator_approval: Option<Account<'info, OperatorApproval>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        address = vault.underlying(vault_type).address @ VaultError::InvalidMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Owner token account for regular mint; any of the owner's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub owner_ata: UncheckedAccount<'info>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Conditional mints passed via remaining_accounts
+    // Expected order for each option i:
+    // - remaining_accounts[i * 2 + 0]: cond_mint_i
+    // - remaining_accounts[i * 2 + 1]: owner_cond_ata_i (may need init on deposit)
+    // Deposits into vaults with an allow-list or per-user caps also take:
+    // - remaining_accounts[2N]: owner's deposit record (see check_deposit_limits)
+}
+
+impl<'info> OperatorVaultAction<'info> {
+    /// Validates the owner's regular token account, creating their ATA if it's missing
+    pub fn init_owner_ata(&self) -> Result<()> {
+        UserVaultAction::init_user_token_account(
+            self.signer.to_account_info(),
+            self.owner.to_account_info(),
+            self.owner_ata.to_account_info(),
+            self.mint.to_account_info(),
+            self.token_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+}
+
+/// Books a deposit into `vault_ata`, whose balance was `escrow_before` ahead of the transfer.
+/// Measures what the escrow actually received (transfer-fee mints deliver less than sent),
+/// keeps the protocol fee in escrow and tracks totals against the escrow invariant.
+/// Returns the conditional tokens owed per option and the fee
+pub fn record_deposit(
+    vault: &mut Account<'_, VaultAccount>,
+    vault_type: VaultType,
+    vault_ata: &mut InterfaceAccount<'_, TokenAccount>,
+    escrow_before: u64,
+) -> Result<(u64, u64)> {
+    vault_ata.reload()?;
+    let received = vault_ata
+        .amount
+        .checked_sub(escrow_before)
+        .ok_or(VaultError::MathOverflow)?;
+    require!(received > 0, VaultError::InvalidAmount);
+
+    let fee = vault.fee_rates.deposit_fee(received)?;
+    let minted = received - fee;
+    require!(minted > 0, VaultError::InvalidAmount);
+
+    let totals = vault.totals_mut(vault_type);
+    totals.deposited = totals
+        .deposited
+        .checked_add(minted)
+        .ok_or(VaultError::MathOverflow)?;
+    totals.accrue_fee(fee)?;
+    vault.check_escrow(vault_type, vault_ata.amount)?;
+
+    Ok((minted, fee))
+}
+
+/// Mints `amount` of every `vault_type` conditional mint to `holder`, given as
+/// (cond_mint, holder_cond_account) pairs in option order. Validates each mint PDA and
+/// holder account, creating the holder's ATA (paid by `payer`) if it's missing
+#[allow(clippy::too_many_arguments)]
+pub fn mint_conditional_tokens<'a, 'info: 'a>(
+    vault: &Account<'info, VaultAccount>,
+    vault_type: VaultType,
+    cond_accounts: impl Iterator<Item = (&'a AccountInfo<'info>, &'a AccountInfo<'info>)>,
+    amount: u64,
+    payer: AccountInfo<'info>,
+    holder: AccountInfo<'info>,
+    conditional_token_program: AccountInfo<'info>,
+    associated_token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    for (vault_cond_mint, (cond_mint_info, holder_cond_ata_info)) in
+        vault.cond_mints(vault_type).iter().zip(cond_accounts)
+    {
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        UserVaultAction::init_user_token_account(
+            payer.clone(),
+            holder.clone(),
+            holder_cond_ata_info.clone(),
+            cond_mint_info.clone(),
+            conditional_token_program.clone(),
+            associated_token_program.clone(),
+            system_program.clone(),
+        )?;
+
+        mint_to_signed(
+            cond_mint_info.clone(),
+            holder_cond_ata_info.clone(),
+            vault.to_account_info(),
+            conditional_token_program.clone(),
+            amount,
+            &[vault_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Enforces the vault's caps & allow-list once `received` of `vault_type` has been deposited
+/// for `user` (with totals already updated). `deposit_record` is the trailing remaining account,
+/// required when the vault tracks depositors; it's created, paid by `payer`, if missing
+pub fn check_deposit_limits<'info>(
+    vault: &Account<'info, VaultAccount>,
+    vault_type: VaultType,
+    user: &Pubkey,
+    received: u64,
+    deposit_record: Option<&AccountInfo<'info>>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let caps = vault.caps(vault_type);
+
+    if let Some(total) = caps.total {
+        let outstanding = if vault_type == VaultType::Base {
+            vault.base_totals
+        } else {
+            vault.quote_totals
+        }
+        .outstanding()?;
+        require!(outstanding <= total, VaultError::DepositCapExceeded);
+    }
+
+    if !vault.tracks_depositors() {
+        return Ok(());
+    }
+
+    let deposit_record = deposit_record.ok_or(VaultError::InvalidDepositRecord)?;
+    let vault_key = vault.key();
+    let (expected_record, bump) = Pubkey::find_program_address(
+        &[DEPOSIT_RECORD_SEED, vault_key.as_ref(), user.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        deposit_record.key(),
+        expected_record,
+        VaultError::InvalidDepositRecord
+    );
+
+    // First deposit on a capped vault without an allow-list
+    if deposit_record.data_is_empty() {
+        require!(!vault.allow_list, VaultError::DepositorNotAllowed);
+
+        // Pre-funding the address mustn't lock the user out
+        let record_seeds: &[&[u8]] = &[DEPOSIT_RECORD_SEED, vault_key.as_ref(), user.as_ref(), &[bump]];
+        create_pda_account(
+            payer,
+            deposit_record.clone(),
+            &crate::ID,
+            8 + DepositRecord::INIT_SPACE,
+            system_program,
+            &[record_seeds],
+        )?;
+
+        let new_record = DepositRecord {
+            bump,
+            vault: vault_key,
+            user: *user,
+            allowed: false,
+            base_deposited: 0,
+            quote_deposited: 0,
+        };
+        new_record.try_serialize(&mut &mut deposit_record.try_borrow_mut_data()?[..])?;
+    }
+
+    require!(
+        deposit_record.owner == &crate::ID,
+        VaultError::InvalidDepositRecord
+    );
+    let mut record = DepositRecord::try_deserialize(&mut &deposit_record.try_borrow_data()?[..])?;
+    require!(
+        !vault.allow_list || record.allowed,
+        VaultError::DepositorNotAllowed
+    );
+
+    let deposited = record.deposited_mut(vault_type);
+    *deposited = deposited
+        .checked_add(received)
+        .ok_or(VaultError::MathOverflow)?;
+    if let Some(per_user) = caps.per_user {
+        require!(*deposited <= per_user, VaultError::UserDepositCapExceeded);
+    }
+
+    record.try_serialize(&mut &mut deposit_record.try_borrow_mut_data()?[..])
+}
@@ -2,14 +2,33 @@ use anchor_lang::prelude::*;
 
 // Maximum number of conditional options per vault
 #[constant]
-pub const MAX_OPTIONS: u8 = 8;
+pub const MAX_OPTIONS: u8 = 64;
 
 // Minimum number of conditional options required
 #[constant]
 pub const MIN_OPTIONS: u8 = 2;
 
 #[constant]
-pub const VAULT_VERSION: u8 = 1;
+pub const VAULT_VERSION: u8 = 2;
+
+// Payout weights must sum to this (basis points)
+#[constant]
+pub const PAYOUT_WEIGHT_TOTAL: u16 = 10_000;
+
+// Maximum protocol fee on deposits or redemptions (basis points)
+#[constant]
+pub const MAX_PROTOCOL_FEE: u16 = 1000; // 10%
+
+// Maximum length of a conditional mint metadata URI
+#[constant]
+pub const MAX_METADATA_URI_LEN: u16 = 200;
+
+// Maximum lengths of the underlying symbol and option label in conditional mint names
+#[constant]
+pub const MAX_SYMBOL_LEN: u16 = 10;
+
+#[constant]
+pub const MAX_OPTION_LABEL_LEN: u16 = 32;
 
 // Seed constants for PDA derivation
 #[constant]
@@ -17,3 +36,24 @@ pub const VAULT_SEED: &[u8] = b"vault";
 
 #[constant]
 pub const CONDITIONAL_MINT_SEED: &[u8] = b"cmint";
+
+#[constant]
+pub const CONDITIONAL_SET_MINT_SEED: &[u8] = b"csmint";
+
+#[constant]
+pub const RECEIPT_MINT_SEED: &[u8] = b"receipt";
+
+#[constant]
+pub const OPERATOR_APPROVAL_SEED: &[u8] = b"operator";
+
+#[constant]
+pub const CHUNK_PROGRESS_SEED: &[u8] = b"chunk";
+
+#[constant]
+pub const DEPOSIT_RECORD_SEED: &[u8] = b"depositor";
+
+#[constant]
+pub const ORACLE_CONFIG_SEED: &[u8] = b"oracle";
+
+#[constant]
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol";
@@ -43,4 +43,100 @@ pub enum VaultError {
 
     #[msg("Invalid mint for vault type")]
     InvalidMint,
+
+    #[msg("Payout weights must cover every option and sum to 10,000 bps")]
+    InvalidPayoutWeights,
+
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("Resolution deadline must be in the future")]
+    InvalidResolutionDeadline,
+
+    #[msg("Resolution deadline has not passed")]
+    ResolutionDeadlineNotReached,
+
+    #[msg("Resolution deadline has passed")]
+    ResolutionDeadlinePassed,
+
+    #[msg("Metadata URI too long")]
+    MetadataUriTooLong,
+
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+
+    #[msg("Escrow balance below outstanding conditional supply")]
+    EscrowInvariantViolated,
+
+    #[msg("Winning conditional supply not fully redeemed")]
+    OutstandingSupply,
+
+    #[msg("Holder must be a wallet")]
+    InvalidHolder,
+
+    #[msg("Option set must be a proper subset of at least two options")]
+    InvalidOptionSet,
+
+    #[msg("Underlying mint is not the parent vault's conditional mint")]
+    InvalidParentVault,
+
+    #[msg("Vault has no parent vault for this type")]
+    NoParentVault,
+
+    #[msg("Token account must belong to the user and hold the expected mint")]
+    InvalidUserTokenAccount,
+
+    #[msg("Option already processed for this chunked action")]
+    OptionAlreadyProcessed,
+
+    #[msg("Chunked deposits or withdrawals still in progress")]
+    PendingChunkedActions,
+
+    #[msg("Deposit exceeds the vault's total cap")]
+    DepositCapExceeded,
+
+    #[msg("Deposit exceeds the per-user cap")]
+    UserDepositCapExceeded,
+
+    #[msg("Depositor is not on the vault's allow-list")]
+    DepositorNotAllowed,
+
+    #[msg("Missing or invalid deposit record")]
+    InvalidDepositRecord,
+
+    #[msg("Vault is resolved by its oracle")]
+    OracleResolved,
+
+    #[msg("Thresholds must be strictly ascending, one fewer than options")]
+    InvalidOracleThresholds,
+
+    #[msg("Oracle pool has not ceased trading at or after the resolution time")]
+    OracleNotReady,
+
+    #[msg("Invalid vault version")]
+    InvalidVersion,
+
+    #[msg("Invalid vault escrow account")]
+    InvalidEscrow,
+
+    #[msg("Protocol fee exceeds the maximum")]
+    InvalidFee,
+
+    #[msg("Protocol fees not yet collected")]
+    UncollectedFees,
+
+    #[msg("Metadata symbol or option label too long")]
+    MetadataLabelTooLong,
+
+    #[msg("Vault did not opt into the permanent delegate")]
+    NoPermanentDelegate,
+
+    #[msg("Option not processed for this chunked action")]
+    OptionNotProcessed,
+
+    #[msg("Requires Token-2022 conditional mints, unavailable on vaults migrated from v1")]
+    Token2022Required,
+
+    #[msg("Oracle pool does not trade the vault's base and quote mints")]
+    InvalidOraclePool,
 }
@@ -0,0 +1,60 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct ProtocolAdminAccepted {
+    pub previous_admin: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAdmin<'info> {
+    // Only the admin nominated with update_protocol_config
+    #[account(
+        constraint = protocol_config.pending_admin == Some(new_admin.key()) @ VaultError::Unauthorized,
+    )]
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn accept_protocol_admin_handler(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let previous_admin = protocol_config.admin;
+
+    protocol_config.admin = ctx.accounts.new_admin.key();
+    protocol_config.pending_admin = None;
+
+    emit!(ProtocolAdminAccepted {
+        previous_admin,
+        admin: protocol_config.admin,
+    });
+
+    Ok(())
+}
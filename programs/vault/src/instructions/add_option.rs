@@ -18,11 +18,12 @@
  */
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_2022::Token2022;
 
 use crate::constants::*;
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::{create_conditional_mint, initialize_token_metadata};
 
 #[event]
 pub struct OptionAdded {
@@ -51,15 +52,21 @@ pub struct AddOption<'info> {
         ],
         bump = vault.bump,
         constraint = vault.state == VaultState::Setup @ VaultError::InvalidState,
+        // New mints carry Token-2022 metadata; migrated v1 vaults can't add options
+        constraint = vault.conditional_token_program == Token2022::id() @ VaultError::Token2022Required,
+        // Thresholds are fixed to the option count in configure_oracle
+        constraint = !vault.oracle_resolved @ VaultError::OracleResolved,
+        // Grow the per-option vectors by one option
+        realloc = VaultAccount::space(vault.num_options as usize + 1),
+        realloc::payer = payer,
+        realloc::zero = false,
     )]
     pub vault: Box<Account<'info, VaultAccount>>,
 
     // Conditional base mint
+    /// CHECK: Mint PDA, created in handler (see create_conditional_mint)
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = vault.base_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -68,14 +75,12 @@ pub struct AddOption<'info> {
         ],
         bump,
     )]
-    pub cond_base_mint: Account<'info, Mint>,
+    pub cond_base_mint: UncheckedAccount<'info>,
 
     // Conditional quote mint
+    /// CHECK: Mint PDA, created in handler (see create_conditional_mint)
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = vault.quote_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -84,15 +89,20 @@ pub struct AddOption<'info> {
         ],
         bump,
     )]
-    pub cond_quote_mint: Account<'info, Mint>,
+    pub cond_quote_mint: UncheckedAccount<'info>,
 
     // Programs
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn add_option_handler(ctx: Context<AddOption>) -> Result<()> {
+pub fn add_option_handler(
+    ctx: Context<AddOption>,
+    uri: Option<String>,
+    label: String,
+) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     let curr_num_options = vault.num_options;
 
@@ -101,8 +111,75 @@ pub fn add_option_handler(ctx: Context<AddOption>) -> Result<()> {
         VaultError::OptionLimitReached
     );
 
-    vault.cond_base_mints[curr_num_options as usize] = ctx.accounts.cond_base_mint.key();
-    vault.cond_quote_mints[curr_num_options as usize] = ctx.accounts.cond_quote_mint.key();
+    let uri = uri.unwrap_or_default();
+    require!(
+        uri.len() <= MAX_METADATA_URI_LEN as usize,
+        VaultError::MetadataUriTooLong
+    );
+    require!(
+        label.len() <= MAX_OPTION_LABEL_LEN as usize,
+        VaultError::MetadataLabelTooLong
+    );
+
+    // Conditional mints with their metadata, same URI for both mints of the option
+    let vault_key = vault.key();
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+    let cond_mints = [
+        (
+            VaultType::Base,
+            ctx.accounts.cond_base_mint.to_account_info(),
+            ctx.bumps.cond_base_mint,
+        ),
+        (
+            VaultType::Quote,
+            ctx.accounts.cond_quote_mint.to_account_info(),
+            ctx.bumps.cond_quote_mint,
+        ),
+    ];
+    for (vault_type, cond_mint, bump) in cond_mints {
+        let mint_seeds: &[&[u8]] = &[
+            CONDITIONAL_MINT_SEED,
+            vault_key.as_ref(),
+            &[vault_type as u8],
+            &[curr_num_options],
+            &[bump],
+        ];
+        create_conditional_mint(
+            ctx.accounts.payer.to_account_info(),
+            cond_mint.clone(),
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            vault.underlying(vault_type).decimals,
+            vault.permanent_delegate,
+            &[mint_seeds],
+        )?;
+
+        let (name, symbol) = vault.conditional_mint_metadata(vault_type, curr_num_options, &label);
+        initialize_token_metadata(
+            ctx.accounts.payer.to_account_info(),
+            cond_mint,
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            name,
+            symbol,
+            uri.clone(),
+            &[vault_seeds],
+        )?;
+    }
+
+    vault.cond_base_mints.push(ctx.accounts.cond_base_mint.key());
+    vault.cond_quote_mints.push(ctx.accounts.cond_quote_mint.key());
+    vault.payout_weights.push(0);
+    vault.base_set_coverage.push(0);
+    vault.quote_set_coverage.push(0);
     vault.num_options += 1;
 
     emit!(OptionAdded {
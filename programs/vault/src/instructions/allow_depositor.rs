@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct DepositorAllowed {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub allowed: bool,
+}
+
+#[derive(Accounts)]
+pub struct AllowDepositor<'info> {
+    /// Payer for account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    // The allow-list can still change after activation
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = matches!(vault.state, VaultState::Setup | VaultState::Active)
+            @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    /// CHECK: Any wallet; the record is keyed by its address
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DepositRecord::INIT_SPACE,
+        seeds = [
+            DEPOSIT_RECORD_SEED,
+            vault.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn allow_depositor_handler(ctx: Context<AllowDepositor>, allowed: bool) -> Result<()> {
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    deposit_record.bump = ctx.bumps.deposit_record;
+    deposit_record.vault = ctx.accounts.vault.key();
+    deposit_record.user = ctx.accounts.user.key();
+    deposit_record.allowed = allowed;
+
+    emit!(DepositorAllowed {
+        vault: deposit_record.vault,
+        user: deposit_record.user,
+        allowed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[event]
+pub struct OperatorApproved {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct ApproveOperator<'info> {
+    /// Payer for account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Any account; the approval is keyed by its address
+    pub operator: UncheckedAccount<'info>,
+
+    // The approval only covers this vault
+    #[account(
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OperatorApproval::INIT_SPACE,
+        seeds = [
+            OPERATOR_APPROVAL_SEED,
+            vault.key().as_ref(),
+            owner.key().as_ref(),
+            operator.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub operator_approval: Account<'info, OperatorApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_operator_handler(ctx: Context<ApproveOperator>) -> Result<()> {
+    let operator_approval = &mut ctx.accounts.operator_approval;
+
+    operator_approval.bump = ctx.bumps.operator_approval;
+    operator_approval.vault = ctx.accounts.vault.key();
+    operator_approval.owner = ctx.accounts.owner.key();
+    operator_approval.operator = ctx.accounts.operator.key();
+
+    emit!(OperatorApproved {
+        vault: operator_approval.vault,
+        owner: operator_approval.owner,
+        operator: operator_approval.operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub receiver: Pubkey,
+    pub base_dust: u64,
+    pub quote_dust: u64,
+    pub closed_mints: u32,
+    pub escrow_balance: u64,   // Always 0: both escrows are swept and closed
+    pub cond_supply: Vec<u64>, // Supply left on each base, then quote, conditional mint (0 if closed)
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives rent from closed accounts; chosen by the owner
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state.is_resolved() @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(address = vault.base_mint.address @ VaultError::InvalidMint)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = vault.quote_mint.address @ VaultError::InvalidMint)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATAs
+    #[account(
+        mut,
+        associated_token::mint = base_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = base_token_program,
+    )]
+    pub vault_base_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = quote_token_program,
+    )]
+    pub vault_quote_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receive leftover escrow (rounding dust, donations)
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::token_program = base_token_program,
+    )]
+    pub base_dust_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::token_program = quote_token_program,
+    )]
+    pub quote_dust_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Programs
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    // Remaining accounts (mut):
+    // 0..N: cond_base_mints[0..N]
+    // N..2N: cond_quote_mints[0..N]
+    // 2N..2N+E: every OR-position and receipt mint of the vault, ascending by key
+    //   (E = vault.extra_mints)
+}
+
+pub fn close_vault_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseVault<'info>>,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let num_options = vault.num_options as usize;
+
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2 + vault.extra_mints as usize,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // OR-positions and receipts covering a paying option must be redeemed first;
+    // those covering only losing options are worthless and never block the close
+    let paying_set_supply = (0..num_options)
+        .filter(|&i| vault.pays_out(i))
+        .any(|i| vault.base_set_coverage[i] > 0 || vault.quote_set_coverage[i] > 0);
+    require!(!paying_set_supply, VaultError::OutstandingSupply);
+    require!(
+        vault.pending_chunks == 0,
+        VaultError::PendingChunkedActions
+    );
+    // The dust sweep would otherwise take the protocol's fees (see collect_fees)
+    require!(
+        vault.base_totals.fees == 0 && vault.quote_totals.fees == 0,
+        VaultError::UncollectedFees
+    );
+
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    // 1. Close conditional mints
+    // Paying options must be fully redeemed; losing options are closed only if empty
+    // Pre-Token-2022 vaults have no close authority on their mints
+    let can_close_mints = vault.conditional_token_program == token_2022::ID;
+    let mut closed_mints = 0u32;
+    let mut cond_supply = Vec::with_capacity(num_options * 2);
+
+    let vault_cond_mints = vault
+        .cond_base_mints
+        .iter()
+        .take(num_options)
+        .chain(vault.cond_quote_mints.iter().take(num_options));
+
+    let (cond_mint_infos, extra_mint_infos) = ctx.remaining_accounts.split_at(num_options * 2);
+
+    for (i, vault_cond_mint) in vault_cond_mints.enumerate() {
+        let cond_mint_info = &cond_mint_infos[i];
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        let supply = InterfaceAccount::<Mint>::try_from(cond_mint_info)?.supply;
+        cond_supply.push(supply);
+        if vault.pays_out(i % num_options) {
+            require!(supply == 0, VaultError::OutstandingSupply);
+        }
+
+        if can_close_mints && supply == 0 {
+            close_account_signed(
+                cond_mint_info.clone(),
+                ctx.accounts.receiver.to_account_info(),
+                vault.to_account_info(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+                &[vault_seeds],
+            )?;
+            closed_mints += 1;
+        }
+    }
+
+    // 2. Close OR-position and receipt mints
+    // Those covering a paying option are empty (checked above); the rest are closed only if empty
+    let mut prev_key: Option<Pubkey> = None;
+    for extra_mint_info in extra_mint_infos {
+        // Ascending keys keep a mint from being passed twice in place of another
+        require!(
+            prev_key.is_none_or(|prev| extra_mint_info.key() > prev),
+            VaultError::InvalidConditionalMint
+        );
+        prev_key = Some(extra_mint_info.key());
+
+        // The vault only has mint authority over its conditional, OR-position and receipt mints
+        require!(
+            !vault.cond_base_mints.contains(&extra_mint_info.key())
+                && !vault.cond_quote_mints.contains(&extra_mint_info.key()),
+            VaultError::InvalidConditionalMint
+        );
+        let extra_mint = InterfaceAccount::<Mint>::try_from(extra_mint_info)?;
+        require!(
+            extra_mint.mint_authority == Some(vault.key()).into(),
+            VaultError::InvalidConditionalMint
+        );
+
+        if extra_mint.supply == 0 {
+            close_account_signed(
+                extra_mint_info.clone(),
+                ctx.accounts.receiver.to_account_info(),
+                vault.to_account_info(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+                &[vault_seeds],
+            )?;
+            closed_mints += 1;
+        }
+    }
+
+    // 3. Sweep leftover escrow & close escrow ATAs
+    let escrows = [
+        (
+            &ctx.accounts.vault_base_ata,
+            &ctx.accounts.base_mint,
+            &ctx.accounts.base_dust_account,
+            &ctx.accounts.base_token_program,
+        ),
+        (
+            &ctx.accounts.vault_quote_ata,
+            &ctx.accounts.quote_mint,
+            &ctx.accounts.quote_dust_account,
+            &ctx.accounts.quote_token_program,
+        ),
+    ];
+    let mut dust = [0u64; 2];
+
+    for (i, (escrow, mint, dust_account, token_program)) in escrows.into_iter().enumerate() {
+        dust[i] = escrow.amount;
+        if dust[i] > 0 {
+            transfer_signed(
+                escrow.to_account_info(),
+                mint.to_account_info(),
+                dust_account.to_account_info(),
+                vault.to_account_info(),
+                token_program.to_account_info(),
+                dust[i],
+                mint.decimals,
+                &[vault_seeds],
+            )?;
+        }
+
+        close_account_signed(
+            escrow.to_account_info(),
+            ctx.accounts.receiver.to_account_info(),
+            vault.to_account_info(),
+            token_program.to_account_info(),
+            &[vault_seeds],
+        )?;
+    }
+
+    // 4. Vault account closed by Anchor (close = receiver)
+    emit!(VaultClosed {
+        vault: vault.key(),
+        receiver: ctx.accounts.receiver.key(),
+        base_dust: dust[0],
+        quote_dust: dust[1],
+        closed_mints,
+        escrow_balance: 0,
+        cond_supply,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct FeesCollected {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub fee_recipient: Pubkey,
+    pub amount: u64,
+    pub escrow_balance: u64,   // Escrow balance after the collection
+    pub cond_supply: Vec<u64>, // Empty: conditional mints are untouched
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct CollectFees<'info> {
+    // Permissionless; pays for the recipient's ATA if needed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Any state: fees accrue while Active and after resolution
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        has_one = fee_recipient,
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    /// CHECK: Current fee recipient from the protocol config
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        constraint = mint.key() == vault.underlying(vault_type).address @ VaultError::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn collect_fees_handler(ctx: Context<CollectFees>, vault_type: VaultType) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let amount = vault.totals(vault_type).fees;
+
+    if amount > 0 {
+        let nonce_bytes = vault.nonce.to_le_bytes();
+        let vault_seeds: &[&[u8]] = &[
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &nonce_bytes,
+            &[vault.bump],
+        ];
+        transfer_signed(
+            ctx.accounts.vault_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.fee_recipient_ata.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            amount,
+            ctx.accounts.mint.decimals,
+            &[vault_seeds],
+        )?;
+    }
+
+    // Track totals & check escrow invariant
+    ctx.accounts.vault.totals_mut(vault_type).fees = 0;
+    ctx.accounts.vault_ata.reload()?;
+    ctx.accounts
+        .vault
+        .check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+    emit!(FeesCollected {
+        vault: ctx.accounts.vault.key(),
+        vault_type,
+        fee_recipient: ctx.accounts.fee_recipient.key(),
+        amount,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: Vec::new(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
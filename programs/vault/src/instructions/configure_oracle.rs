@@ -0,0 +1,116 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct OracleConfigured {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub resolve_at: i64,
+    pub thresholds: Vec<u128>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureOracle<'info> {
+    /// Payer for account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    // Fixed once the vault is activated
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Setup @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    /// CHECK: Validated as an amm pool in the handler
+    pub pool: UncheckedAccount<'info>,
+
+    // Can be reconfigured until activation
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [ORACLE_CONFIG_SEED, vault.key().as_ref()],
+        bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_oracle_handler(
+    ctx: Context<ConfigureOracle>,
+    resolve_at: i64,
+    thresholds: Vec<u128>,
+) -> Result<()> {
+    let pool = amm::reader::load_pool(&ctx.accounts.pool)?;
+
+    let vault = &mut ctx.accounts.vault;
+
+    // The TWAP must price the vault's own underlyings, in either orientation
+    let (base, quote) = (vault.base_mint.address, vault.quote_mint.address);
+    require!(
+        (pool.mint_a, pool.mint_b) == (base, quote) || (pool.mint_a, pool.mint_b) == (quote, base),
+        VaultError::InvalidOraclePool
+    );
+
+    // One threshold between each pair of neighbouring options
+    require!(
+        thresholds.len() + 1 == vault.num_options as usize
+            && thresholds.windows(2).all(|pair| pair[0] < pair[1]),
+        VaultError::InvalidOracleThresholds
+    );
+    require!(
+        resolve_at > Clock::get()?.unix_timestamp && !vault.is_past_deadline(resolve_at),
+        VaultError::InvalidResolutionDeadline
+    );
+
+    vault.oracle_resolved = true;
+
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.bump = ctx.bumps.oracle_config;
+    oracle_config.vault = vault.key();
+    oracle_config.pool = ctx.accounts.pool.key();
+    oracle_config.resolve_at = resolve_at;
+    oracle_config.thresholds = thresholds.clone();
+
+    emit!(OracleConfigured {
+        vault: vault.key(),
+        pool: oracle_config.pool,
+        resolve_at,
+        thresholds,
+    });
+
+    Ok(())
+}
@@ -18,8 +18,9 @@
  */
 use anchor_lang::prelude::*;
 
-use crate::common::UserVaultAction;
-use crate::constants::*;
+use crate::common::{
+    check_deposit_limits, mint_conditional_tokens, record_deposit, UserVaultAction,
+};
 use crate::errors::*;
 use crate::state::VaultState;
 use crate::utils::*;
@@ -30,7 +31,12 @@ pub struct VaultDeposit {
     pub vault: Pubkey,
     pub user: Pubkey,
     pub vault_type: VaultType,
-    pub amount: u64,
+    pub amount: u64,         // Conditional tokens minted per option
+    pub fee: u64,            // Protocol fee kept in escrow
+    pub escrow_balance: u64, // Escrow balance after the deposit
+    // Supply of each conditional mint after the deposit; empty for chunked actions (see ChunkProcessed)
+    pub cond_supply: Vec<u64>,
+    pub slot: u64,
 }
 
 pub fn deposit_handler<'info>(
@@ -42,86 +48,76 @@ pub fn deposit_handler<'info>(
 
     require!(vault.state == VaultState::Active, VaultError::InvalidState);
 
+    ctx.accounts.init_user_ata()?;
+
     let num_options = vault.num_options as usize;
 
     // Validate we have the right number of remaining accounts
+    // (plus the depositor's record when the vault has an allow-list or per-user caps)
     require!(
-        ctx.remaining_accounts.len() == num_options * 2,
+        ctx.remaining_accounts.len() == num_options * 2 + vault.tracks_depositors() as usize,
         VaultError::InvalidNumberOfAccounts
     );
 
     // Validate that amount is non-zero
     require!(amount > 0, VaultError::InvalidAmount);
 
-    let vault_cond_mints = if vault_type == VaultType::Base {
-        vault.cond_base_mints
-    } else {
-        vault.cond_quote_mints
-    };
-
     // 1. Transfer regular tokens: user -> vault
+    let escrow_before = ctx.accounts.vault_ata.amount;
     transfer_tokens(
         ctx.accounts.user_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
         ctx.accounts.vault_ata.to_account_info(),
         ctx.accounts.signer.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    let (minted, fee) = record_deposit(
+        &mut ctx.accounts.vault,
+        vault_type,
+        &mut ctx.accounts.vault_ata,
+        escrow_before,
     )?;
 
     // 2. For each conditional mint, mint tokens to user
-    let nonce_bytes = vault.nonce.to_le_bytes();
-    let vault_seeds: &[&[u8]] = &[
-        VAULT_SEED,
-        vault.owner.as_ref(),
-        &nonce_bytes,
-        &[vault.bump],
-    ];
-
-    for i in 0..num_options {
-        let cond_mint_info = &ctx.remaining_accounts[i * 2];
-        let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
-
-        // Validate the conditional mint PDA
-        require!(
-            cond_mint_info.key() == vault_cond_mints[i],
-            VaultError::InvalidConditionalMint
-        );
-
-        UserVaultAction::validate_user_ata(
-            &cond_mint_info.key(),
-            &ctx.accounts.signer.key(),
-            user_cond_ata_info,
-        )?;
-
-        // Create user's ATA if needed
-        if user_cond_ata_info.data_is_empty() {
-            create_associated_token_account(
-                ctx.accounts.signer.to_account_info(),
-                user_cond_ata_info.clone(),
-                ctx.accounts.signer.to_account_info(),
-                cond_mint_info.clone(),
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.associated_token_program.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            )?;
-        }
+    mint_conditional_tokens(
+        &ctx.accounts.vault,
+        vault_type,
+        ctx.remaining_accounts[..num_options * 2]
+            .chunks(2)
+            .map(|accounts| (&accounts[0], &accounts[1])),
+        minted,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
 
-        // Mint conditional tokens to user's ATA
-        mint_to_signed(
-            cond_mint_info.clone(),
-            user_cond_ata_info.clone(),
-            vault.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            amount,
-            &[vault_seeds],
-        )?;
-    }
+    check_deposit_limits(
+        &ctx.accounts.vault,
+        vault_type,
+        &ctx.accounts.signer.key(),
+        minted,
+        ctx.remaining_accounts.get(num_options * 2),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
 
     emit!(VaultDeposit {
-        vault: vault.key(),
+        vault: ctx.accounts.vault.key(),
         user: ctx.accounts.signer.key(),
         vault_type,
-        amount,
+        amount: minted,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
     });
 
     Ok(())
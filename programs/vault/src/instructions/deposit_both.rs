@@ -0,0 +1,164 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    check_deposit_limits, mint_conditional_tokens, record_deposit, DualVaultAction, UserVaultAction,
+};
+use crate::errors::*;
+use crate::instructions::deposit::VaultDeposit;
+use crate::utils::*;
+use crate::VaultType;
+
+pub fn deposit_both_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DualVaultAction<'info>>,
+    base_amount: u64,
+    quote_amount: u64,
+) -> Result<()> {
+    let num_options = ctx.accounts.vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    // (plus the depositor's record when the vault has an allow-list or per-user caps)
+    let tracks_depositors = ctx.accounts.vault.tracks_depositors();
+    require!(
+        ctx.remaining_accounts.len() == num_options * 4 + tracks_depositors as usize,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that at least one amount is non-zero
+    require!(
+        base_amount > 0 || quote_amount > 0,
+        VaultError::InvalidAmount
+    );
+
+    let (cond_mints, user_cond_atas) =
+        ctx.remaining_accounts[..num_options * 4].split_at(num_options * 2);
+    let deposit_record = ctx.remaining_accounts.get(num_options * 4);
+    let sides = [
+        (VaultType::Base, base_amount),
+        (VaultType::Quote, quote_amount),
+    ];
+
+    for (i, (vault_type, amount)) in sides.into_iter().enumerate() {
+        if amount == 0 {
+            continue;
+        }
+
+        let options = i * num_options..(i + 1) * num_options;
+        let (minted, fee) = deposit_side(
+            ctx.accounts,
+            vault_type,
+            amount,
+            &cond_mints[options.clone()],
+            &user_cond_atas[options.clone()],
+        )?;
+        check_deposit_limits(
+            &ctx.accounts.vault,
+            vault_type,
+            &ctx.accounts.signer.key(),
+            minted,
+            deposit_record,
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        emit!(VaultDeposit {
+            vault: ctx.accounts.vault.key(),
+            user: ctx.accounts.signer.key(),
+            vault_type,
+            amount: minted,
+            fee,
+            escrow_balance: if vault_type == VaultType::Base {
+                ctx.accounts.vault_base_ata.amount
+            } else {
+                ctx.accounts.vault_quote_ata.amount
+            },
+            cond_supply: cond_mints[options]
+                .iter()
+                .map(mint_supply)
+                .collect::<Result<_>>()?,
+            slot: Clock::get()?.slot,
+        });
+    }
+
+    Ok(())
+}
+
+// Same steps as `deposit` for one vault type; returns the amount minted and the protocol fee
+fn deposit_side<'info>(
+    accounts: &mut DualVaultAction<'info>,
+    vault_type: VaultType,
+    amount: u64,
+    cond_mints: &[AccountInfo<'info>],
+    user_cond_atas: &[AccountInfo<'info>],
+) -> Result<(u64, u64)> {
+    let (mint, vault_ata, user_ata, token_program) = if vault_type == VaultType::Base {
+        (
+            &accounts.base_mint,
+            &mut accounts.vault_base_ata,
+            &accounts.user_base_ata,
+            &accounts.base_token_program,
+        )
+    } else {
+        (
+            &accounts.quote_mint,
+            &mut accounts.vault_quote_ata,
+            &accounts.user_quote_ata,
+            &accounts.quote_token_program,
+        )
+    };
+
+    UserVaultAction::init_user_token_account(
+        accounts.signer.to_account_info(),
+        accounts.signer.to_account_info(),
+        user_ata.to_account_info(),
+        mint.to_account_info(),
+        token_program.to_account_info(),
+        accounts.associated_token_program.to_account_info(),
+        accounts.system_program.to_account_info(),
+    )?;
+
+    // 1. Transfer regular tokens: user -> vault
+    let escrow_before = vault_ata.amount;
+    transfer_tokens(
+        user_ata.to_account_info(),
+        mint.to_account_info(),
+        vault_ata.to_account_info(),
+        accounts.signer.to_account_info(),
+        token_program.to_account_info(),
+        amount,
+        mint.decimals,
+    )?;
+    let (minted, fee) = record_deposit(&mut accounts.vault, vault_type, vault_ata, escrow_before)?;
+
+    // 2. For each conditional mint, mint tokens to user
+    mint_conditional_tokens(
+        &accounts.vault,
+        vault_type,
+        cond_mints.iter().zip(user_cond_atas.iter()),
+        minted,
+        accounts.signer.to_account_info(),
+        accounts.signer.to_account_info(),
+        accounts.conditional_token_program.to_account_info(),
+        accounts.associated_token_program.to_account_info(),
+        accounts.system_program.to_account_info(),
+    )?;
+
+    Ok((minted, fee))
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    check_deposit_limits, mint_conditional_tokens, record_deposit, OperatorVaultAction,
+};
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::VaultType;
+
+#[event]
+pub struct VaultDepositFor {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub signer: Pubkey,
+    pub vault_type: VaultType,
+    pub amount: u64,           // Conditional tokens minted per option
+    pub fee: u64,              // Protocol fee kept in escrow
+    pub escrow_balance: u64,   // Escrow balance after the deposit
+    pub cond_supply: Vec<u64>, // Supply of each conditional mint after the deposit
+    pub slot: u64,
+}
+
+pub fn deposit_for_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OperatorVaultAction<'info>>,
+    vault_type: VaultType,
+    amount: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let num_options = vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    // (plus the owner's deposit record when the vault has an allow-list or per-user caps)
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2 + vault.tracks_depositors() as usize,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that amount is non-zero
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    ctx.accounts.init_owner_ata()?;
+
+    // 1. Transfer regular tokens: owner -> vault
+    // Operators move funds through the approval PDA, which the owner set as SPL delegate;
+    // otherwise the signer must be the SPL delegate itself
+    let escrow_before = ctx.accounts.vault_ata.amount;
+    match &ctx.accounts.operator_approval {
+        Some(operator_approval) => {
+            let approval_seeds: &[&[u8]] = &[
+                OPERATOR_APPROVAL_SEED,
+                operator_approval.vault.as_ref(),
+                operator_approval.owner.as_ref(),
+                operator_approval.operator.as_ref(),
+                &[operator_approval.bump],
+            ];
+            transfer_signed(
+                ctx.accounts.owner_ata.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.vault_ata.to_account_info(),
+                operator_approval.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                amount,
+                ctx.accounts.mint.decimals,
+                &[approval_seeds],
+            )?;
+        }
+        None => {
+            transfer_tokens(
+                ctx.accounts.owner_ata.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.vault_ata.to_account_info(),
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                amount,
+                ctx.accounts.mint.decimals,
+            )?;
+        }
+    }
+    let (minted, fee) = record_deposit(
+        &mut ctx.accounts.vault,
+        vault_type,
+        &mut ctx.accounts.vault_ata,
+        escrow_before,
+    )?;
+
+    // 2. For each conditional mint, mint tokens to the owner (missing ATAs paid by the signer)
+    mint_conditional_tokens(
+        &ctx.accounts.vault,
+        vault_type,
+        ctx.remaining_accounts[..num_options * 2]
+            .chunks(2)
+            .map(|accounts| (&accounts[0], &accounts[1])),
+        minted,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    check_deposit_limits(
+        &ctx.accounts.vault,
+        vault_type,
+        &ctx.accounts.owner.key(),
+        minted,
+        ctx.remaining_accounts.get(num_options * 2),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(VaultDepositFor {
+        vault: ctx.accounts.vault.key(),
+        owner: ctx.accounts.owner.key(),
+        signer: ctx.accounts.signer.key(),
+        vault_type,
+        amount: minted,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct VaultExpired {
+    pub vault: Pubkey,
+    pub resolution_deadline: i64,
+}
+
+#[derive(Accounts)]
+pub struct ExpireVault<'info> {
+    // Permissionless
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+}
+
+pub fn expire_vault_handler(ctx: Context<ExpireVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let resolution_deadline = vault
+        .resolution_deadline
+        .ok_or(VaultError::ResolutionDeadlineNotReached)?;
+
+    require!(
+        vault.is_past_deadline(Clock::get()?.unix_timestamp),
+        VaultError::ResolutionDeadlineNotReached
+    );
+
+    // Owner never resolved; refund holders as if voided
+    vault.state = VaultState::Voided;
+
+    // The resolution must leave OR-position and receipt claims covered
+    vault.check_set_coverage(VaultType::Base)?;
+    vault.check_set_coverage(VaultType::Quote)?;
+
+    emit!(VaultExpired {
+        vault: vault.key(),
+        resolution_deadline,
+    });
+
+    Ok(())
+}
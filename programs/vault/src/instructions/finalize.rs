@@ -50,12 +50,19 @@ pub struct FinalizeVault<'info> {
         ],
         bump = vault.bump,
         constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+        constraint = !vault.oracle_resolved @ VaultError::OracleResolved,
     )]
     pub vault: Box<Account<'info, VaultAccount>>,
 }
 
 pub fn finalize_vault_handler(ctx: Context<FinalizeVault>, winning_idx: u8) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
+
+    // Past the deadline, the vault can only be voided
+    require!(
+        !vault.is_past_deadline(Clock::get()?.unix_timestamp),
+        VaultError::ResolutionDeadlinePassed
+    );
     require!(
         winning_idx < vault.num_options,
         VaultError::IndexOutOfBounds
@@ -64,6 +71,10 @@ pub fn finalize_vault_handler(ctx: Context<FinalizeVault>, winning_idx: u8) -> R
     // Finalize state
     vault.state = VaultState::Finalized(winning_idx);
 
+    // The resolution must leave OR-position and receipt claims covered
+    vault.check_set_coverage(VaultType::Base)?;
+    vault.check_set_coverage(VaultType::Quote)?;
+
     emit!(VaultFinalized {
         vault: vault.key(),
         winning_idx,
@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct VaultFinalizedWeighted {
+    pub vault: Pubkey,
+    pub payout_weights: Vec<u16>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeWeightedVault<'info> {
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+        constraint = !vault.oracle_resolved @ VaultError::OracleResolved,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+}
+
+pub fn finalize_weighted_handler(
+    ctx: Context<FinalizeWeightedVault>,
+    payout_weights: Vec<u16>,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    // Past the deadline, the vault can only be voided
+    require!(
+        !vault.is_past_deadline(Clock::get()?.unix_timestamp),
+        VaultError::ResolutionDeadlinePassed
+    );
+
+    // One weight per option, summing to 100%
+    require!(
+        payout_weights.len() == vault.num_options as usize,
+        VaultError::InvalidPayoutWeights
+    );
+    let total: u32 = payout_weights.iter().map(|&w| w as u32).sum();
+    require!(
+        total == PAYOUT_WEIGHT_TOTAL as u32,
+        VaultError::InvalidPayoutWeights
+    );
+
+    // Finalize state
+    vault.payout_weights.copy_from_slice(&payout_weights);
+    vault.state = VaultState::FinalizedWeighted;
+
+    // The resolution must leave OR-position and receipt claims covered
+    vault.check_set_coverage(VaultType::Base)?;
+    vault.check_set_coverage(VaultType::Quote)?;
+
+    emit!(VaultFinalizedWeighted {
+        vault: vault.key(),
+        payout_weights,
+    });
+
+    Ok(())
+}
@@ -18,10 +18,13 @@
  */
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::*;
+use crate::errors::*;
 use crate::state::*;
+use crate::utils::{create_conditional_mint, initialize_token_metadata, token_metadata_symbol};
 
 #[event]
 pub struct VaultInitialized {
@@ -46,7 +49,7 @@ pub struct InitializeVault<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + VaultAccount::INIT_SPACE,
+        space = VaultAccount::space(2),
         seeds = [
             VAULT_SEED,
             owner.key().as_ref(),
@@ -56,8 +59,11 @@ pub struct InitializeVault<'info> {
     )]
     pub vault: Box<Account<'info, VaultAccount>>,
 
-    pub base_mint: Account<'info, Mint>,
-    pub quote_mint: Account<'info, Mint>,
+    // Regular mints, each under SPL Token or Token-2022
+    #[account(mint::token_program = base_token_program)]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
     // Escrow ATA for base mint
     #[account(
@@ -65,9 +71,9 @@ pub struct InitializeVault<'info> {
         payer = payer,
         associated_token::mint = base_mint,
         associated_token::authority = vault,
-        associated_token::token_program = token_program,
+        associated_token::token_program = base_token_program,
     )]
-    pub base_token_acc: Account<'info, TokenAccount>,
+    pub base_token_acc: Box<InterfaceAccount<'info, TokenAccount>>,
 
     // Escrow ATA for quote mint
     #[account(
@@ -75,17 +81,16 @@ pub struct InitializeVault<'info> {
         payer = payer,
         associated_token::mint = quote_mint,
         associated_token::authority = vault,
-        associated_token::token_program = token_program,
+        associated_token::token_program = quote_token_program,
     )]
-    pub quote_token_acc: Account<'info, TokenAccount>,
+    pub quote_token_acc: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    // Conditional mints are Token-2022, created in the handler (see create_conditional_mint)
     // Create initial 2 base conditional mints
     // Conditional mint 0
+    /// CHECK: Mint PDA, created in handler
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = base_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -94,14 +99,12 @@ pub struct InitializeVault<'info> {
         ],
         bump,
     )]
-    pub cond_base_mint_0: Account<'info, Mint>,
+    pub cond_base_mint_0: UncheckedAccount<'info>,
 
     // Conditional mint 1
+    /// CHECK: Mint PDA, created in handler
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = base_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -110,15 +113,13 @@ pub struct InitializeVault<'info> {
         ],
         bump,
     )]
-    pub cond_base_mint_1: Account<'info, Mint>,
+    pub cond_base_mint_1: UncheckedAccount<'info>,
 
     // Create initial 2 quote conditional mints
     // Conditional quote mint 0
+    /// CHECK: Mint PDA, created in handler
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = quote_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -127,14 +128,12 @@ pub struct InitializeVault<'info> {
         ],
         bump,
     )]
-    pub cond_quote_mint_0: Account<'info, Mint>,
+    pub cond_quote_mint_0: UncheckedAccount<'info>,
 
     // Conditional quote mint 1
+    /// CHECK: Mint PDA, created in handler
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = quote_mint.decimals,
-        mint::authority = vault,
+        mut,
         seeds = [
             CONDITIONAL_MINT_SEED,
             vault.key().as_ref(),
@@ -143,15 +142,35 @@ pub struct InitializeVault<'info> {
         ],
         bump,
     )]
-    pub cond_quote_mint_1: Account<'info, Mint>,
+    pub cond_quote_mint_1: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol config PDA, read in handler; the vault charges no fees if it doesn't exist
+    #[account(seeds = [PROTOCOL_CONFIG_SEED], bump)]
+    pub protocol_config: UncheckedAccount<'info>,
 
     // Programs
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub conditional_token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn initialize_handler(ctx: Context<InitializeVault>, nonce: u16) -> Result<()> {
+pub fn initialize_handler(
+    ctx: Context<InitializeVault>,
+    nonce: u16,
+    base_symbol: Option<String>,
+    quote_symbol: Option<String>,
+    option_labels: [String; 2],
+    permanent_delegate: bool,
+) -> Result<()> {
+    for label in &option_labels {
+        require!(
+            label.len() <= MAX_OPTION_LABEL_LEN as usize,
+            VaultError::MetadataLabelTooLong
+        );
+    }
+
     let vault = &mut ctx.accounts.vault;
 
     vault.version = VAULT_VERSION;
@@ -166,18 +185,104 @@ pub fn initialize_handler(ctx: Context<InitializeVault>, nonce: u16) -> Result<(
     };
     vault.nonce = nonce;
     vault.num_options = 2; // First 2 options generated atomically
+    vault.base_symbol = resolve_symbol(base_symbol, &ctx.accounts.base_mint.to_account_info())?;
+    vault.quote_symbol = resolve_symbol(quote_symbol, &ctx.accounts.quote_mint.to_account_info())?;
 
     // Store conditional mints
-    // cond_*_mints[2..] zero'd on account creation
-    vault.cond_base_mints[0] = ctx.accounts.cond_base_mint_0.key();
-    vault.cond_base_mints[1] = ctx.accounts.cond_base_mint_1.key();
-
-    vault.cond_quote_mints[0] = ctx.accounts.cond_quote_mint_0.key();
-    vault.cond_quote_mints[1] = ctx.accounts.cond_quote_mint_1.key();
+    vault.cond_base_mints = vec![
+        ctx.accounts.cond_base_mint_0.key(),
+        ctx.accounts.cond_base_mint_1.key(),
+    ];
+    vault.cond_quote_mints = vec![
+        ctx.accounts.cond_quote_mint_0.key(),
+        ctx.accounts.cond_quote_mint_1.key(),
+    ];
+    vault.payout_weights = vec![0; 2];
+    vault.base_set_coverage = vec![0; 2];
+    vault.quote_set_coverage = vec![0; 2];
 
+    vault.conditional_token_program = ctx.accounts.conditional_token_program.key();
+    vault.permanent_delegate = permanent_delegate;
     vault.state = VaultState::Setup;
+
+    // Fix the current protocol fee rates for the vault's lifetime
+    let protocol_config_info = ctx.accounts.protocol_config.to_account_info();
+    if protocol_config_info.owner == &crate::ID {
+        let data = protocol_config_info.try_borrow_data()?;
+        vault.fee_rates = ProtocolConfig::try_deserialize(&mut &data[..])?.fee_rates;
+    }
     vault.bump = ctx.bumps.vault;
 
+    // Conditional mints with their metadata; URIs can only be supplied for added options
+    let vault_key = vault.key();
+    let owner_key = vault.owner;
+    let nonce_bytes = nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        owner_key.as_ref(),
+        &nonce_bytes,
+        &[ctx.bumps.vault],
+    ];
+    let cond_mints = [
+        (
+            VaultType::Base,
+            0u8,
+            ctx.accounts.cond_base_mint_0.to_account_info(),
+            ctx.bumps.cond_base_mint_0,
+        ),
+        (
+            VaultType::Base,
+            1,
+            ctx.accounts.cond_base_mint_1.to_account_info(),
+            ctx.bumps.cond_base_mint_1,
+        ),
+        (
+            VaultType::Quote,
+            0,
+            ctx.accounts.cond_quote_mint_0.to_account_info(),
+            ctx.bumps.cond_quote_mint_0,
+        ),
+        (
+            VaultType::Quote,
+            1,
+            ctx.accounts.cond_quote_mint_1.to_account_info(),
+            ctx.bumps.cond_quote_mint_1,
+        ),
+    ];
+    for (vault_type, idx, cond_mint, bump) in cond_mints {
+        let mint_seeds: &[&[u8]] = &[
+            CONDITIONAL_MINT_SEED,
+            vault_key.as_ref(),
+            &[vault_type as u8],
+            &[idx],
+            &[bump],
+        ];
+        create_conditional_mint(
+            ctx.accounts.payer.to_account_info(),
+            cond_mint.clone(),
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            vault.underlying(vault_type).decimals,
+            permanent_delegate,
+            &[mint_seeds],
+        )?;
+
+        let (name, symbol) =
+            vault.conditional_mint_metadata(vault_type, idx, &option_labels[idx as usize]);
+        initialize_token_metadata(
+            ctx.accounts.payer.to_account_info(),
+            cond_mint,
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            name,
+            symbol,
+            String::new(),
+            &[vault_seeds],
+        )?;
+    }
+
     emit!(VaultInitialized {
         version: VAULT_VERSION,
         vault: vault.key(),
@@ -189,3 +294,26 @@ pub fn initialize_handler(ctx: Context<InitializeVault>, nonce: u16) -> Result<(
 
     Ok(())
 }
+
+// Owner-supplied symbol, else the underlying's Token-2022 metadata symbol, else its address prefix
+fn resolve_symbol(symbol: Option<String>, mint: &AccountInfo) -> Result<String> {
+    if let Some(symbol) = symbol.filter(|symbol| !symbol.is_empty()) {
+        require!(
+            symbol.len() <= MAX_SYMBOL_LEN as usize,
+            VaultError::MetadataLabelTooLong
+        );
+        return Ok(symbol);
+    }
+
+    let symbol = match token_metadata_symbol(mint)? {
+        Some(symbol) if !symbol.is_empty() => symbol,
+        _ => return Ok(VaultAccount::default_symbol(&mint.key())),
+    };
+
+    // Truncate at a char boundary to fit the stored length
+    let mut end = symbol.len().min(MAX_SYMBOL_LEN as usize);
+    while !symbol.is_char_boundary(end) {
+        end -= 1;
+    }
+    Ok(symbol[..end].to_string())
+}
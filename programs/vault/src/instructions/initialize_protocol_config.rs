@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct ProtocolConfigInitialized {
+    pub admin: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub fee_rates: FeeRates,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    /// Payer for account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Only the vault program's upgrade authority
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ VaultError::Unauthorized,
+    )]
+    pub program: Program<'info, crate::program::Vault>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ VaultError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_protocol_config_handler(
+    ctx: Context<InitializeProtocolConfig>,
+    admin: Pubkey,
+    fee_recipient: Pubkey,
+    fee_rates: FeeRates,
+) -> Result<()> {
+    require!(
+        fee_rates.deposit <= MAX_PROTOCOL_FEE && fee_rates.redeem <= MAX_PROTOCOL_FEE,
+        VaultError::InvalidFee
+    );
+
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.bump = ctx.bumps.protocol_config;
+    protocol_config.admin = admin;
+    protocol_config.pending_admin = None;
+    protocol_config.fee_recipient = fee_recipient;
+    protocol_config.fee_rates = fee_rates;
+
+    emit!(ProtocolConfigInitialized {
+        admin,
+        fee_recipient,
+        fee_rates,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{SetVaultAction, UserVaultAction};
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::VaultAccount;
+use crate::utils::*;
+use crate::VaultType;
+
+#[event]
+pub struct OptionsMerged {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub mask: u64,
+    pub amount: u64,
+    pub escrow_balance: u64,   // Escrow balance, unchanged
+    pub cond_supply: Vec<u64>, // Supply of each option in the set afterwards, ascending
+    pub slot: u64,
+}
+
+pub fn merge_options_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetVaultAction<'info>>,
+    vault_type: VaultType,
+    mask: u64,
+    amount: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    vault.check_option_set(mask)?;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == mask.count_ones() as usize * 2,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that amount is non-zero
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let vault_cond_mints = vault.cond_mints(vault_type);
+
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    // 1. Add token metadata on the set's first merge
+    let set_mint_info = ctx.accounts.set_mint.to_account_info();
+    let is_new_mint = !has_token_metadata(&set_mint_info)?;
+    if is_new_mint {
+        let (name, symbol) = vault.set_mint_metadata(vault_type, mask);
+        initialize_token_metadata(
+            ctx.accounts.signer.to_account_info(),
+            set_mint_info.clone(),
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            name,
+            symbol,
+            String::new(),
+            &[vault_seeds],
+        )?;
+    }
+
+    UserVaultAction::init_user_token_account(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.user_set_ata.to_account_info(),
+        set_mint_info.clone(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // 2. Burn `amount` of each option in the set
+    for (j, i) in VaultAccount::set_options(mask).enumerate() {
+        let cond_mint_info = &ctx.remaining_accounts[j * 2];
+        let user_cond_ata_info = &ctx.remaining_accounts[j * 2 + 1];
+
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == vault_cond_mints[i],
+            VaultError::InvalidConditionalMint
+        );
+
+        // User must have conditional tokens to merge
+        require!(
+            !user_cond_ata_info.data_is_empty(),
+            VaultError::InvalidUserAta
+        );
+
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.conditional_token_program.key(),
+            user_cond_ata_info,
+        )?;
+
+        // Will throw if token account doesn't have enough tokens
+        burn_tokens(
+            cond_mint_info.clone(),
+            user_cond_ata_info.clone(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            amount,
+        )?;
+    }
+
+    // 3. Mint the OR-position to the user
+    mint_to_signed(
+        set_mint_info,
+        ctx.accounts.user_set_ata.to_account_info(),
+        vault.to_account_info(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+        amount,
+        &[vault_seeds],
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.add_set_coverage(vault_type, mask, amount)?;
+    vault.check_set_coverage(vault_type)?;
+    if is_new_mint {
+        vault.extra_mints = vault
+            .extra_mints
+            .checked_add(1)
+            .ok_or(VaultError::MathOverflow)?;
+    }
+
+    emit!(OptionsMerged {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        mask,
+        amount,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct VaultMigrated {
+    pub vault: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    // Permissionless; covers any additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Older layouts can't be deserialized as VaultAccount.
+    /// Owner checked here, discriminator and version checked in handler
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Vault's base escrow ATA, checked in handler when seeding totals
+    pub base_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Vault's quote escrow ATA, checked in handler when seeding totals
+    pub quote_escrow: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_vault_handler(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+
+    // Decode under the stored version's layout
+    let (from_version, mut migrated) = {
+        let data = vault_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data.starts_with(VaultAccount::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let version = data[8];
+        (version, VaultAccount::upgrade(version, &data[8..])?)
+    };
+
+    // v1 didn't track totals; its escrows hold exactly what's owed
+    if from_version == 1 {
+        migrated.base_totals.deposited = escrow_balance(
+            &vault_info.key(),
+            &migrated.base_mint.address,
+            &ctx.accounts.base_escrow,
+        )?;
+        migrated.quote_totals.deposited = escrow_balance(
+            &vault_info.key(),
+            &migrated.quote_mint.address,
+            &ctx.accounts.quote_escrow,
+        )?;
+    }
+
+    // Grow the account to the current layout, topping up rent from the payer
+    let new_len = VaultAccount::space(migrated.num_options as usize);
+    if vault_info.data_len() < new_len {
+        let rent_due = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(vault_info.lamports());
+
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: vault_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        vault_info.resize(new_len)?;
+    }
+
+    // Write back under the current layout
+    let mut data = vault_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    emit!(VaultMigrated {
+        vault: vault_info.key(),
+        from_version,
+        to_version: VAULT_VERSION,
+    });
+
+    Ok(())
+}
+
+// Balance of the vault's SPL Token ATA for `mint` (v1 vaults only took SPL Token mints)
+fn escrow_balance(vault: &Pubkey, mint: &Pubkey, escrow_info: &AccountInfo) -> Result<u64> {
+    require_keys_eq!(
+        escrow_info.key(),
+        get_associated_token_address_with_program_id(vault, mint, &anchor_spl::token::ID),
+        VaultError::InvalidEscrow
+    );
+    require_keys_eq!(
+        *escrow_info.owner,
+        anchor_spl::token::ID,
+        VaultError::InvalidEscrow
+    );
+    let escrow = TokenAccount::try_deserialize(&mut &escrow_info.try_borrow_data()?[..])?;
+    Ok(escrow.amount)
+}
@@ -1,15 +1,81 @@
+pub mod accept_protocol_admin;
 pub mod activate_vault;
 pub mod add_option;
+pub mod allow_depositor;
+pub mod approve_operator;
+pub mod cancel_chunked;
+pub mod close_vault;
+pub mod collect_fees;
+pub mod configure_oracle;
 pub mod deposit;
+pub mod deposit_both;
+pub mod deposit_for;
+pub mod expire;
 pub mod finalize;
+pub mod finalize_weighted;
 pub mod initialize;
+pub mod initialize_protocol_config;
+pub mod merge_options;
+pub mod migrate;
+pub mod process_chunk;
+pub mod redeem;
+pub mod redeem_for;
+pub mod redeem_nested;
+pub mod redeem_option_set;
+pub mod redeem_receipt;
 pub mod redeem_winnings;
+pub mod resolve;
+pub mod revoke_operator;
+pub mod set_deposit_limits;
+pub mod set_parent;
+pub mod set_resolution_deadline;
+pub mod split_option_set;
+pub mod start_chunked;
+pub mod unwrap;
+pub mod update_protocol_config;
+pub mod void;
+pub mod withdraw_both;
+pub mod withdraw_for;
 pub mod withdrawal;
+pub mod wrap;
 
+pub use accept_protocol_admin::*;
 pub use activate_vault::*;
 pub use add_option::*;
+pub use allow_depositor::*;
+pub use approve_operator::*;
+pub use cancel_chunked::*;
+pub use close_vault::*;
+pub use collect_fees::*;
+pub use configure_oracle::*;
 pub use deposit::*;
+pub use deposit_both::*;
+pub use deposit_for::*;
+pub use expire::*;
 pub use finalize::*;
+pub use finalize_weighted::*;
 pub use initialize::*;
+pub use initialize_protocol_config::*;
+pub use merge_options::*;
+pub use migrate::*;
+pub use process_chunk::*;
+pub use redeem::*;
+pub use redeem_for::*;
+pub use redeem_nested::*;
+pub use redeem_option_set::*;
+pub use redeem_receipt::*;
 pub use redeem_winnings::*;
+pub use resolve::*;
+pub use revoke_operator::*;
+pub use set_deposit_limits::*;
+pub use set_parent::*;
+pub use set_resolution_deadline::*;
+pub use split_option_set::*;
+pub use start_chunked::*;
+pub use unwrap::*;
+pub use update_protocol_config::*;
+pub use void::*;
+pub use withdraw_both::*;
+pub use withdraw_for::*;
 pub use withdrawal::*;
+pub use wrap::*;
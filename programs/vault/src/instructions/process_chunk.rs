@@ -0,0 +1,313 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::common::UserVaultAction;
+use crate::constants::*;
+use crate::errors::*;
+use crate::instructions::deposit::VaultDeposit;
+use crate::instructions::withdrawal::VaultWithdrawal;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct ChunkProcessed {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub action: ChunkedAction,
+    pub start_option: u8,
+    pub num_options: u8,
+    pub cond_supply: Vec<u64>, // Supply of each processed conditional mint afterwards
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct ProcessChunk<'info> {
+    // The user; or, for a deposit into a resolved vault, anyone finishing it on their behalf.
+    // Pays for missing accounts
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Owner of the chunked action; receives the progress account's rent
+    #[account(mut, address = chunk_progress.user @ VaultError::Unauthorized)]
+    pub user: UncheckedAccount<'info>,
+
+    // Deposits can finish in any state, since the full set they mint is worth its amount
+    // under every resolution. Withdrawals only while Active (see cancel_chunked)
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = chunk_progress.action == ChunkedAction::Deposit
+            || vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHUNK_PROGRESS_SEED,
+            vault.key().as_ref(),
+            chunk_progress.user.as_ref(),
+            &[vault_type as u8],
+        ],
+        bump = chunk_progress.bump,
+        constraint = chunk_progress.user == signer.key()
+            || (chunk_progress.action == ChunkedAction::Deposit && vault.state.is_resolved())
+            @ VaultError::Unauthorized,
+    )]
+    pub chunk_progress: Box<Account<'info, ChunkProgress>>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        address = vault.underlying(vault_type).address @ VaultError::InvalidMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: User token account for regular mint, paid once a withdrawal completes.
+    /// Validated in handler via `init_user_token_account`, unused for deposits
+    #[account(mut)]
+    pub user_ata: UncheckedAccount<'info>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Conditional mints passed via remaining_accounts, for options start_option..start_option + k:
+    // - remaining_accounts[j * 2 + 0]: cond_mint_(start_option + j)
+    // - remaining_accounts[j * 2 + 1]: user_cond_ata_(start_option + j) (may need init on deposit)
+}
+
+pub fn process_chunk_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessChunk<'info>>,
+    vault_type: VaultType,
+    start_option: u8,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let chunk_progress = &ctx.accounts.chunk_progress;
+    let action = chunk_progress.action;
+    let amount = chunk_progress.amount;
+    let held_value = chunk_progress.holds_value();
+
+    // Validate we have a non-empty chunk of existing options
+    let chunk_len = ctx.remaining_accounts.len() / 2;
+    require!(
+        chunk_len > 0 && ctx.remaining_accounts.len() % 2 == 0,
+        VaultError::InvalidNumberOfAccounts
+    );
+    let options = start_option as usize..start_option as usize + chunk_len;
+    let vault_cond_mints = vault
+        .cond_mints(vault_type)
+        .get(options.clone())
+        .ok_or(VaultError::IndexOutOfBounds)?;
+
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    let mut processed = chunk_progress.processed;
+
+    for ((i, vault_cond_mint), accounts) in options
+        .zip(vault_cond_mints.iter())
+        .zip(ctx.remaining_accounts.chunks(2))
+    {
+        let cond_mint_info = &accounts[0];
+        let user_cond_ata_info = &accounts[1];
+
+        // Each option is minted or burned exactly once
+        require!(processed >> i & 1 == 0, VaultError::OptionAlreadyProcessed);
+        processed |= 1 << i;
+
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        match action {
+            ChunkedAction::Deposit => {
+                UserVaultAction::init_user_token_account(
+                    ctx.accounts.signer.to_account_info(),
+                    ctx.accounts.user.to_account_info(),
+                    user_cond_ata_info.clone(),
+                    cond_mint_info.clone(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                )?;
+
+                mint_to_signed(
+                    cond_mint_info.clone(),
+                    user_cond_ata_info.clone(),
+                    vault.to_account_info(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    amount,
+                    &[vault_seeds],
+                )?;
+            }
+            ChunkedAction::Withdraw => {
+                UserVaultAction::validate_user_token_account(
+                    &cond_mint_info.key(),
+                    &ctx.accounts.signer.key(),
+                    &ctx.accounts.conditional_token_program.key(),
+                    user_cond_ata_info,
+                )?;
+
+                // Will throw if token account doesn't have enough tokens
+                burn_tokens(
+                    cond_mint_info.clone(),
+                    user_cond_ata_info.clone(),
+                    ctx.accounts.signer.to_account_info(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    amount,
+                )?;
+            }
+        }
+    }
+
+    let is_complete = processed == vault.all_options_mask();
+    ctx.accounts.chunk_progress.processed = processed;
+
+    emit!(ChunkProcessed {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        vault_type,
+        action,
+        start_option,
+        num_options: chunk_len as u8,
+        cond_supply: ctx
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    if !is_complete {
+        // A withdrawal's first burn leaves part of the escrow owed to it
+        if !held_value {
+            let vault = &mut ctx.accounts.vault;
+            vault.pending_chunks = vault
+                .pending_chunks
+                .checked_add(1)
+                .ok_or(VaultError::MathOverflow)?;
+        }
+        return Ok(());
+    }
+
+    // Every option done: pay out a withdrawal & close the progress account
+    if action == ChunkedAction::Withdraw {
+        ctx.accounts.init_user_ata()?;
+
+        transfer_signed(
+            ctx.accounts.vault_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.user_ata.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            amount,
+            ctx.accounts.mint.decimals,
+            &[vault_seeds],
+        )?;
+
+        // Track totals & check escrow invariant
+        ctx.accounts.vault_ata.reload()?;
+        let vault = &mut ctx.accounts.vault;
+        let totals = vault.totals_mut(vault_type);
+        totals.withdrawn = totals
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(VaultError::MathOverflow)?;
+        vault.check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+        emit!(VaultWithdrawal {
+            vault: vault.key(),
+            user: ctx.accounts.user.key(),
+            vault_type,
+            amount,
+            escrow_balance: ctx.accounts.vault_ata.amount,
+            cond_supply: Vec::new(),
+            slot: Clock::get()?.slot,
+        });
+    } else {
+        emit!(VaultDeposit {
+            vault: ctx.accounts.vault.key(),
+            user: ctx.accounts.user.key(),
+            vault_type,
+            amount,
+            fee: 0, // Charged in start_chunked
+            escrow_balance: ctx.accounts.vault_ata.amount,
+            cond_supply: Vec::new(),
+            slot: Clock::get()?.slot,
+        });
+    }
+
+    // A withdrawal finishing in one call was never counted
+    if held_value {
+        let vault = &mut ctx.accounts.vault;
+        vault.pending_chunks = vault
+            .pending_chunks
+            .checked_sub(1)
+            .ok_or(VaultError::MathOverflow)?;
+    }
+
+    ctx.accounts
+        .chunk_progress
+        .close(ctx.accounts.user.to_account_info())
+}
+
+impl ProcessChunk<'_> {
+    /// Validates the user's regular token account, creating their ATA if it's missing
+    fn init_user_ata(&self) -> Result<()> {
+        UserVaultAction::init_user_token_account(
+            self.signer.to_account_info(),
+            self.user.to_account_info(),
+            self.user_ata.to_account_info(),
+            self.mint.to_account_info(),
+            self.token_program.to_account_info(),
+            self.associated_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+        )
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::common::UserVaultAction;
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::VaultType;
+
+#[event]
+pub struct WinningsRedeemed {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub amount: u64,           // Amount paid to the user, net of fees
+    pub fee: u64,              // Protocol fee kept in escrow
+    pub escrow_balance: u64,   // Escrow balance after the redemption
+    pub cond_supply: Vec<u64>, // Supply of each conditional mint after the redemption
+    pub slot: u64,
+}
+
+// Burns up to `amount` (all if None) of each paying option and every losing token,
+// paying out for what was burned. Emptied accounts are closed if `close_accounts`.
+// Weighted and voided payouts round down per call, so redeeming in k parts forfeits
+// less than k base units to the vault (see tests/payout.rs)
+pub fn redeem_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UserVaultAction<'info>>,
+    vault_type: VaultType,
+    amount: Option<u64>,
+    close_accounts: bool,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    require!(vault.state.is_resolved(), VaultError::InvalidState);
+
+    ctx.accounts.init_user_ata()?;
+
+    let num_options = vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    let vault_cond_mints = vault.cond_mints(vault_type);
+
+    // Per-option burned amounts, zero for missing accounts
+    let mut balances = vec![0u64; num_options];
+
+    for (i, vault_cond_mint) in vault_cond_mints.iter().enumerate().take(num_options) {
+        let cond_mint_info = &ctx.remaining_accounts[i * 2];
+        let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
+
+        // Validate the conditional mint PDA (its supply is reported in the event)
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        // Skip if ATA doesn't exist or is empty
+        if user_cond_ata_info.data_is_empty() {
+            continue;
+        }
+
+        // Validate user's token account
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.conditional_token_program.key(),
+            user_cond_ata_info,
+        )?;
+
+        let user_cond_ata = InterfaceAccount::<TokenAccount>::try_from(user_cond_ata_info)?;
+        let balance = user_cond_ata.amount;
+
+        // Losing tokens are worthless, so they're always burned in full
+        let burn_amount = match amount {
+            Some(amount) if vault.pays_out(i) => balance.min(amount),
+            _ => balance,
+        };
+
+        balances[i] = burn_amount;
+
+        if burn_amount > 0 {
+            burn_tokens(
+                cond_mint_info.clone(),
+                user_cond_ata_info.clone(),
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+                burn_amount,
+            )?;
+        }
+
+        // Close user token account once empty
+        if close_accounts && burn_amount == balance {
+            close_token_account(
+                user_cond_ata_info.clone(),
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+            )?;
+        }
+    }
+
+    let winning_amount = vault.payout(&balances[..num_options])?;
+    let fee = vault.fee_rates.redeem_fee(winning_amount)?;
+    let paid = winning_amount - fee;
+
+    // 2. Transfer winning amount net of fees: vault -> user, if any
+    if paid > 0 {
+        let nonce_bytes = vault.nonce.to_le_bytes();
+        let vault_seeds: &[&[u8]] = &[
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &nonce_bytes,
+            &[vault.bump],
+        ];
+        transfer_signed(
+            ctx.accounts.vault_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.user_ata.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            paid,
+            ctx.accounts.mint.decimals,
+            &[vault_seeds],
+        )?;
+    }
+
+    // Track totals, the fee stays in escrow until collected
+    let totals = ctx.accounts.vault.totals_mut(vault_type);
+    totals.redeemed = totals
+        .redeemed
+        .checked_add(winning_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    totals.accrue_fee(fee)?;
+
+    // Check escrow invariant
+    ctx.accounts.check_escrow(vault_type)?;
+
+    emit!(WinningsRedeemed {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        amount: paid,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
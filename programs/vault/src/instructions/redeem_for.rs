@@ -0,0 +1,246 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::common::UserVaultAction;
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::{VaultAccount, VaultType};
+
+#[event]
+pub struct WinningsRedeemedFor {
+    pub vault: Pubkey,
+    pub holder: Pubkey,
+    pub caller: Pubkey,
+    pub vault_type: VaultType,
+    pub amount: u64,           // Amount paid to the holder, net of fees
+    pub fee: u64,              // Protocol fee kept in escrow
+    pub escrow_balance: u64,   // Escrow balance after the holder's redemption
+    pub cond_supply: Vec<u64>, // Supply of each conditional mint after the holder's redemption
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct RedeemFor<'info> {
+    // Permissionless; pays rent for any missing holder ATAs
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state.is_resolved() @ VaultError::InvalidState,
+        // Burning on behalf of holders needs the permanent delegate, opted into at initialize
+        constraint = vault.permanent_delegate @ VaultError::NoPermanentDelegate,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        constraint = mint.key() == vault.underlying(vault_type).address @ VaultError::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    // Remaining accounts:
+    // - remaining_accounts[0..N]: cond_mint_i
+    // Then per holder, N + 2 accounts:
+    // - holder (wallet)
+    // - holder_ata for regular mint (created if missing)
+    // - holder_cond_ata_i for each option i
+}
+
+pub fn redeem_for_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemFor<'info>>,
+    vault_type: VaultType,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let num_options = vault.num_options as usize;
+    let chunk_len = num_options + 2;
+
+    // Validate we have the right number of remaining accounts
+    let remaining = ctx.remaining_accounts;
+    require!(
+        remaining.len() > num_options && (remaining.len() - num_options) % chunk_len == 0,
+        VaultError::InvalidNumberOfAccounts
+    );
+    let (cond_mints, holders) = remaining.split_at(num_options);
+
+    // Validate the conditional mint PDAs
+    let vault_cond_mints = vault.cond_mints(vault_type);
+    for (cond_mint_info, vault_cond_mint) in cond_mints.iter().zip(vault_cond_mints.iter()) {
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+    }
+
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    let vault_key = vault.key();
+    let mint_key = ctx.accounts.mint.key();
+    let token_program_key = ctx.accounts.token_program.key();
+    let conditional_token_program_key = ctx.accounts.conditional_token_program.key();
+    let mut total_redeemed: u64 = 0;
+    let mut total_fees: u64 = 0;
+    let slot = Clock::get()?.slot;
+
+    for chunk in holders.chunks(chunk_len) {
+        let holder = &chunk[0];
+        let holder_ata = &chunk[1];
+        let holder_cond_atas = &chunk[2..];
+
+        // Only system-owned holders' canonical ATAs are swept. That includes system-owned PDAs such
+        // as Squads vaults, whose winnings land in their own ATA; positions held by program-owned
+        // accounts (vaults, pools, proposals) are never touched and redeem through their own flows
+        require!(holder.owner == &System::id(), VaultError::InvalidHolder);
+
+        // Winnings only ever go to the holder's canonical ATA
+        UserVaultAction::validate_user_ata(&mint_key, holder.key, &token_program_key, holder_ata)?;
+
+        // Per-option balances, zero for missing accounts
+        let mut balances = vec![0u64; num_options];
+
+        for (i, (cond_mint_info, holder_cond_ata_info)) in
+            cond_mints.iter().zip(holder_cond_atas.iter()).enumerate()
+        {
+            UserVaultAction::validate_user_ata(
+                &cond_mint_info.key(),
+                holder.key,
+                &conditional_token_program_key,
+                holder_cond_ata_info,
+            )?;
+
+            // Skip if ATA doesn't exist
+            if holder_cond_ata_info.data_is_empty() {
+                continue;
+            }
+
+            let balance = InterfaceAccount::<TokenAccount>::try_from(holder_cond_ata_info)?.amount;
+            balances[i] = balance;
+
+            // Burn as permanent delegate; the holder keeps the emptied account
+            if balance > 0 {
+                burn_signed(
+                    cond_mint_info.clone(),
+                    holder_cond_ata_info.clone(),
+                    ctx.accounts.vault.to_account_info(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    balance,
+                    &[vault_seeds],
+                )?;
+            }
+        }
+
+        // Nothing burned, nothing to report
+        if balances.iter().all(|&balance| balance == 0) {
+            continue;
+        }
+
+        let winning_amount = ctx.accounts.vault.payout(&balances[..num_options])?;
+        let fee = ctx.accounts.vault.fee_rates.redeem_fee(winning_amount)?;
+        let paid = winning_amount - fee;
+        total_redeemed = total_redeemed
+            .checked_add(winning_amount)
+            .ok_or(VaultError::MathOverflow)?;
+        total_fees = total_fees
+            .checked_add(fee)
+            .ok_or(VaultError::MathOverflow)?;
+        if paid > 0 {
+            if holder_ata.data_is_empty() {
+                create_associated_token_account(
+                    ctx.accounts.caller.to_account_info(),
+                    holder_ata.clone(),
+                    holder.clone(),
+                    ctx.accounts.mint.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                )?;
+            }
+
+            transfer_signed(
+                ctx.accounts.vault_ata.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                holder_ata.clone(),
+                ctx.accounts.vault.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                paid,
+                ctx.accounts.mint.decimals,
+                &[vault_seeds],
+            )?;
+        }
+        ctx.accounts.vault_ata.reload()?;
+
+        emit!(WinningsRedeemedFor {
+            vault: vault_key,
+            holder: holder.key(),
+            caller: ctx.accounts.caller.key(),
+            vault_type,
+            amount: paid,
+            fee,
+            escrow_balance: ctx.accounts.vault_ata.amount,
+            cond_supply: cond_mints.iter().map(mint_supply).collect::<Result<_>>()?,
+            slot,
+        });
+    }
+
+    // Track totals & check escrow invariant
+    let totals = ctx.accounts.vault.totals_mut(vault_type);
+    totals.redeemed = totals
+        .redeemed
+        .checked_add(total_redeemed)
+        .ok_or(VaultError::MathOverflow)?;
+    totals.accrue_fee(total_fees)?;
+    ctx.accounts.vault_ata.reload()?;
+    ctx.accounts
+        .vault
+        .check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+    Ok(())
+}
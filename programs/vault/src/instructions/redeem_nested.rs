@@ -0,0 +1,309 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::common::UserVaultAction;
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct NestedWinningsRedeemed {
+    pub vault: Pubkey,
+    pub parent_vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub parent_amount: u64, // Child winnings in parent conditional tokens, including child_fee
+    pub child_fee: u64,     // Child protocol fee kept in the child escrow, in parent tokens
+    pub amount: u64,        // Parent underlying paid to the user, net of fees
+    pub fee: u64,           // Parent protocol fee kept in the parent escrow
+    // Child escrow balance afterwards, in parent conditional tokens
+    pub escrow_balance: u64,
+    // Supply of each child conditional mint afterwards
+    pub cond_supply: Vec<u64>,
+    // Parent escrow balance and supply of the linked parent conditional mint afterwards
+    pub parent_escrow_balance: u64,
+    pub parent_cond_supply: u64,
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct RedeemNested<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    // Child vault, whose underlying is a parent conditional mint
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state.is_resolved() @ VaultError::InvalidState,
+        constraint = vault.parent(vault_type).is_some() @ VaultError::NoParentVault,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            parent_vault.owner.as_ref(),
+            &parent_vault.nonce.to_le_bytes(),
+        ],
+        bump = parent_vault.bump,
+        constraint = vault.parent(vault_type).is_some_and(|link| link.vault == parent_vault.key())
+            @ VaultError::InvalidParentVault,
+        constraint = parent_vault.state.is_resolved() @ VaultError::InvalidState,
+    )]
+    pub parent_vault: Box<Account<'info, VaultAccount>>,
+
+    // Parent conditional mint held in the child's escrow
+    #[account(
+        mut,
+        address = vault.underlying(vault_type).address @ VaultError::InvalidMint,
+    )]
+    pub parent_cond_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Child escrow ATA for the parent conditional mint
+    #[account(
+        mut,
+        associated_token::mint = parent_cond_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = conditional_token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Parent's regular mint for the linked type
+    #[account(
+        constraint = vault.parent(vault_type).is_some_and(|link| {
+            mint.key() == parent_vault.underlying(link.vault_type).address
+        }) @ VaultError::InvalidMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Parent escrow ATA for its regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = parent_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub parent_vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: User token account for the parent's regular mint; any of the signer's accounts
+    /// for the mint, or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub user_ata: UncheckedAccount<'info>,
+
+    // Programs
+    // Token program of the parent's regular mint
+    pub token_program: Interface<'info, TokenInterface>,
+    // Shared by both vaults (checked in set_parent)
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    // Child conditional mints passed via remaining_accounts
+    // Expected order for each option i:
+    // - remaining_accounts[i * 2 + 0]: cond_mint_i
+    // - remaining_accounts[i * 2 + 1]: user_cond_ata_i
+}
+
+pub fn redeem_nested_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemNested<'info>>,
+    vault_type: VaultType,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let parent_vault = &ctx.accounts.parent_vault;
+    let link = vault.parent(vault_type).ok_or(VaultError::NoParentVault)?;
+
+    let num_options = vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    UserVaultAction::init_user_token_account(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.user_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // 1. Burn & close the user's child conditional tokens
+    // Per-option balances, zero for missing accounts
+    let mut balances = vec![0u64; num_options];
+
+    for (i, vault_cond_mint) in vault.cond_mints(vault_type).iter().enumerate() {
+        let cond_mint_info = &ctx.remaining_accounts[i * 2];
+        let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
+
+        // Validate the conditional mint PDA (its supply is reported in the event)
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        // Skip if ATA doesn't exist
+        if user_cond_ata_info.data_is_empty() {
+            continue;
+        }
+
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.conditional_token_program.key(),
+            user_cond_ata_info,
+        )?;
+
+        let balance = InterfaceAccount::<TokenAccount>::try_from(user_cond_ata_info)?.amount;
+        balances[i] = balance;
+
+        if balance > 0 {
+            burn_tokens(
+                cond_mint_info.clone(),
+                user_cond_ata_info.clone(),
+                ctx.accounts.signer.to_account_info(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+                balance,
+            )?;
+        }
+
+        close_token_account(
+            user_cond_ata_info.clone(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+        )?;
+    }
+
+    // Child winnings, in parent conditional tokens; zero just burns & closes accounts
+    let parent_amount = vault.payout(&balances[..num_options])?;
+
+    // The child's redeem fee is charged like in `redeem`, and stays in the child escrow
+    // as parent conditional tokens (see collect_fees); only the rest is unwound
+    let child_fee = vault.fee_rates.redeem_fee(parent_amount)?;
+    let unwound = parent_amount - child_fee;
+
+    // 2. Unwind the parent conditional tokens out of the child escrow
+    if unwound > 0 {
+        let nonce_bytes = vault.nonce.to_le_bytes();
+        let vault_seeds: &[&[u8]] = &[
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &nonce_bytes,
+            &[vault.bump],
+        ];
+        burn_signed(
+            ctx.accounts.parent_cond_mint.to_account_info(),
+            ctx.accounts.vault_ata.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            unwound,
+            &[vault_seeds],
+        )?;
+    }
+
+    // 3. Pay the parent's winnings on them: parent vault -> user
+    let mut parent_balances = vec![0u64; parent_vault.num_options as usize];
+    parent_balances[link.option as usize] = unwound;
+    let payout = parent_vault.payout(&parent_balances)?;
+
+    // The parent's redeem fee applies on top, as for any parent holder
+    let fee = parent_vault.fee_rates.redeem_fee(payout)?;
+    let amount = payout - fee;
+
+    if amount > 0 {
+        let parent_nonce_bytes = parent_vault.nonce.to_le_bytes();
+        let parent_vault_seeds: &[&[u8]] = &[
+            VAULT_SEED,
+            parent_vault.owner.as_ref(),
+            &parent_nonce_bytes,
+            &[parent_vault.bump],
+        ];
+        transfer_signed(
+            ctx.accounts.parent_vault_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.user_ata.to_account_info(),
+            parent_vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            amount,
+            ctx.accounts.mint.decimals,
+            &[parent_vault_seeds],
+        )?;
+    }
+
+    // Track totals & check escrow invariant on both vaults
+    let totals = ctx.accounts.vault.totals_mut(vault_type);
+    totals.redeemed = totals
+        .redeemed
+        .checked_add(parent_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    totals.accrue_fee(child_fee)?;
+    ctx.accounts.vault_ata.reload()?;
+    ctx.accounts
+        .vault
+        .check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+    let parent_totals = ctx.accounts.parent_vault.totals_mut(link.vault_type);
+    parent_totals.redeemed = parent_totals
+        .redeemed
+        .checked_add(payout)
+        .ok_or(VaultError::MathOverflow)?;
+    parent_totals.accrue_fee(fee)?;
+    ctx.accounts.parent_vault_ata.reload()?;
+    ctx.accounts
+        .parent_vault
+        .check_escrow(link.vault_type, ctx.accounts.parent_vault_ata.amount)?;
+
+    emit!(NestedWinningsRedeemed {
+        vault: ctx.accounts.vault.key(),
+        parent_vault: ctx.accounts.parent_vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        parent_amount,
+        child_fee,
+        amount,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        parent_escrow_balance: ctx.accounts.parent_vault_ata.amount,
+        parent_cond_supply: mint_supply(&ctx.accounts.parent_cond_mint.to_account_info())?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::common::UserVaultAction;
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct OptionSetRedeemed {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub mask: u64,
+    pub burned: u64,
+    pub amount: u64,           // Amount paid to the user, net of fees
+    pub fee: u64,              // Protocol fee kept in escrow
+    pub escrow_balance: u64,   // Escrow balance after the redemption
+    pub cond_supply: Vec<u64>, // Empty: only the OR-position mint is burned
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType, mask: u64)]
+pub struct RedeemOptionSet<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state.is_resolved() @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        constraint = mint.key() == vault.underlying(vault_type).address @ VaultError::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: User token account for regular mint; any of the signer's accounts for the mint,
+    /// or their ATA (created if missing). Validated in handler via `init_user_token_account`
+    #[account(mut)]
+    pub user_ata: UncheckedAccount<'info>,
+
+    // OR-position mint for the option set
+    #[account(
+        mut,
+        seeds = [
+            CONDITIONAL_SET_MINT_SEED,
+            vault.key().as_ref(),
+            &[vault_type as u8],
+            &mask.to_le_bytes(),
+        ],
+        bump,
+        mint::token_program = conditional_token_program,
+    )]
+    pub set_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // User token account for the OR-position mint, closed after redemption
+    #[account(
+        mut,
+        token::mint = set_mint,
+        token::authority = signer,
+        token::token_program = conditional_token_program,
+    )]
+    pub user_set_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(address = vault.conditional_token_program @ VaultError::InvalidTokenProgram)]
+    pub conditional_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn redeem_option_set_handler(
+    ctx: Context<RedeemOptionSet>,
+    vault_type: VaultType,
+    mask: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let balance = ctx.accounts.user_set_ata.amount;
+
+    UserVaultAction::init_user_token_account(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.user_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // 1. Burn the whole OR-position & close the user's account
+    if balance > 0 {
+        burn_tokens(
+            ctx.accounts.set_mint.to_account_info(),
+            ctx.accounts.user_set_ata.to_account_info(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            balance,
+        )?;
+    }
+
+    close_token_account(
+        ctx.accounts.user_set_ata.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+    )?;
+
+    let winning_amount = vault.set_payout(mask, balance)?;
+
+    let fee = vault.fee_rates.redeem_fee(winning_amount)?;
+    let paid = winning_amount - fee;
+
+    // 2. Transfer winning amount net of fees: vault -> user
+    if paid > 0 {
+        let nonce_bytes = vault.nonce.to_le_bytes();
+        let vault_seeds: &[&[u8]] = &[
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &nonce_bytes,
+            &[vault.bump],
+        ];
+        transfer_signed(
+            ctx.accounts.vault_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.user_ata.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            paid,
+            ctx.accounts.mint.decimals,
+            &[vault_seeds],
+        )?;
+    }
+
+    // Track totals & check escrow invariant
+    let vault = &mut ctx.accounts.vault;
+    vault.remove_set_coverage(vault_type, mask, balance)?;
+    let totals = vault.totals_mut(vault_type);
+    totals.redeemed = totals
+        .redeemed
+        .checked_add(winning_amount)
+        .ok_or(VaultError::MathOverflow)?;
+    totals.accrue_fee(fee)?;
+    ctx.accounts.vault_ata.reload()?;
+    ctx.accounts
+        .vault
+        .check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+    emit!(OptionSetRedeemed {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        mask,
+        burned: balance,
+        amount: paid,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: Vec::new(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
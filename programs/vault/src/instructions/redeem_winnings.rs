@@ -17,134 +17,15 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
 
 use crate::common::UserVaultAction;
-use crate::constants::*;
-use crate::errors::*;
-use crate::state::VaultState;
-use crate::utils::*;
+use crate::instructions::redeem::redeem_handler;
 use crate::VaultType;
 
-#[event]
-pub struct WinningsRedeemed {
-    pub vault: Pubkey,
-    pub user: Pubkey,
-    pub vault_type: VaultType,
-    pub amount: u64,
-}
-
+// Redeems every conditional token and closes the user's conditional accounts
 pub fn redeem_winnings_handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, UserVaultAction<'info>>,
     vault_type: VaultType,
 ) -> Result<()> {
-    let vault = &ctx.accounts.vault;
-
-    let winning_idx = if let VaultState::Finalized(idx) = vault.state {
-        idx
-    } else {
-        return err!(VaultError::InvalidState);
-    };
-
-    let num_options = vault.num_options as usize;
-
-    // Validate we have the right number of remaining accounts
-    require!(
-        ctx.remaining_accounts.len() == num_options * 2,
-        VaultError::InvalidNumberOfAccounts
-    );
-
-    let vault_cond_mints = if vault_type == VaultType::Base {
-        vault.cond_base_mints
-    } else {
-        vault.cond_quote_mints
-    };
-
-    let mut winning_amount = 0u64;
-
-    for i in 0..num_options {
-        let cond_mint_info = &ctx.remaining_accounts[i * 2];
-        let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
-
-        // Skip if ATA doesn't exist or is empty
-        if user_cond_ata_info.data_is_empty() {
-            continue;
-        }
-
-        // Validate the conditional mint PDA
-        require!(
-            cond_mint_info.key() == vault_cond_mints[i],
-            VaultError::InvalidConditionalMint
-        );
-
-        // Validate user's ATA
-        UserVaultAction::validate_user_ata(
-            &cond_mint_info.key(),
-            &ctx.accounts.signer.key(),
-            user_cond_ata_info,
-        )?;
-
-        require!(
-            user_cond_ata_info.owner == &ctx.accounts.token_program.key(),
-            VaultError::InvalidAccountOwner
-        );
-
-        let user_cond_ata = Account::<TokenAccount>::try_from(user_cond_ata_info)?;
-        let balance = user_cond_ata.amount;
-
-        // Save balance for winning optio
-        if i == winning_idx as usize {
-            winning_amount = balance;
-        }
-
-        // Burn all tokens
-        if balance > 0 {
-            burn_tokens(
-                cond_mint_info.clone(),
-                user_cond_ata_info.clone(),
-                ctx.accounts.signer.to_account_info(),
-                ctx.accounts.token_program.to_account_info(),
-                balance,
-            )?;
-        }
-
-        // Close user token account
-        close_token_account(
-            user_cond_ata_info.clone(),
-            ctx.accounts.signer.to_account_info(),
-            ctx.accounts.signer.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-        )?;
-    }
-
-    // Exit if no winnings, aka just burn & close accounts
-    if winning_amount == 0 {
-        return Ok(());
-    }
-
-    // 2. Transfer winning amount of regular tokens: vault -> user
-    let nonce_bytes = vault.nonce.to_le_bytes();
-    let vault_seeds: &[&[u8]] = &[
-        VAULT_SEED,
-        vault.owner.as_ref(),
-        &nonce_bytes,
-        &[vault.bump],
-    ];
-    transfer_signed(
-        ctx.accounts.vault_ata.to_account_info(),
-        ctx.accounts.user_ata.to_account_info(),
-        vault.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-        winning_amount,
-        &[vault_seeds],
-    )?;
-
-    emit!(WinningsRedeemed {
-        vault: vault.key(),
-        user: ctx.accounts.signer.key(),
-        vault_type,
-        amount: winning_amount,
-    });
-
-    Ok(())
+    redeem_handler(ctx, vault_type, None, true)
 }
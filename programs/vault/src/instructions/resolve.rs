@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct VaultResolved {
+    pub vault: Pubkey,
+    pub pool: Pubkey,
+    pub twap: u128,
+    pub winning_idx: u8,
+}
+
+#[derive(Accounts)]
+pub struct ResolveVault<'info> {
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        seeds = [ORACLE_CONFIG_SEED, vault.key().as_ref()],
+        bump = oracle_config.bump,
+        has_one = vault,
+        has_one = pool,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// CHECK: Matched against oracle_config.pool; its final snapshot is read via amm::reader
+    pub pool: UncheckedAccount<'info>,
+}
+
+pub fn resolve_vault_handler(ctx: Context<ResolveVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let oracle_config = &ctx.accounts.oracle_config;
+
+    // Past the deadline, the vault can only be voided
+    require!(
+        !vault.is_past_deadline(Clock::get()?.unix_timestamp),
+        VaultError::ResolutionDeadlinePassed
+    );
+
+    // Resolve from the TWAP frozen when the pool ceased trading, so neither later trades
+    // nor when `resolve` is called can move the outcome. The pool must cease at or after
+    // resolve_at for its window to cover it
+    let snapshot = amm::reader::load_pool(&ctx.accounts.pool)?
+        .final_snapshot
+        .ok_or(VaultError::OracleNotReady)?;
+    require!(
+        snapshot.unix_time >= oracle_config.resolve_at,
+        VaultError::OracleNotReady
+    );
+    let twap = snapshot.twap.ok_or(VaultError::OracleNotReady)?;
+
+    let winning_idx = oracle_config.winning_option(twap);
+    vault.state = VaultState::Finalized(winning_idx);
+
+    // The resolution must leave OR-position and receipt claims covered
+    vault.check_set_coverage(VaultType::Base)?;
+    vault.check_set_coverage(VaultType::Quote)?;
+
+    emit!(VaultResolved {
+        vault: vault.key(),
+        pool: oracle_config.pool,
+        twap,
+        winning_idx,
+    });
+
+    Ok(())
+}
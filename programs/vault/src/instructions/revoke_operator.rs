@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct OperatorRevoked {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    // Receives the approval's rent
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            OPERATOR_APPROVAL_SEED,
+            operator_approval.vault.as_ref(),
+            owner.key().as_ref(),
+            operator_approval.operator.as_ref(),
+        ],
+        bump = operator_approval.bump,
+        has_one = owner @ VaultError::Unauthorized,
+    )]
+    pub operator_approval: Account<'info, OperatorApproval>,
+}
+
+pub fn revoke_operator_handler(ctx: Context<RevokeOperator>) -> Result<()> {
+    emit!(OperatorRevoked {
+        vault: ctx.accounts.operator_approval.vault,
+        owner: ctx.accounts.owner.key(),
+        operator: ctx.accounts.operator_approval.operator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct ParentVaultSet {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub parent_vault: Pubkey,
+    pub parent_vault_type: VaultType,
+    pub parent_option: u8,
+}
+
+#[derive(Accounts)]
+pub struct SetParent<'info> {
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    // Fixed once the vault is activated
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Setup @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            parent_vault.owner.as_ref(),
+            &parent_vault.nonce.to_le_bytes(),
+        ],
+        bump = parent_vault.bump,
+        constraint = parent_vault.conditional_token_program == vault.conditional_token_program
+            @ VaultError::InvalidTokenProgram,
+    )]
+    pub parent_vault: Box<Account<'info, VaultAccount>>,
+}
+
+pub fn set_parent_handler(
+    ctx: Context<SetParent>,
+    vault_type: VaultType,
+    parent_vault_type: VaultType,
+    parent_option: u8,
+) -> Result<()> {
+    let parent_vault = &ctx.accounts.parent_vault;
+    let vault = &mut ctx.accounts.vault;
+
+    // The underlying must be the parent's conditional mint for that option
+    let underlying = vault.underlying(vault_type).address;
+    let parent_cond_mint = parent_vault
+        .cond_mints(parent_vault_type)
+        .get(parent_option as usize)
+        .ok_or(VaultError::IndexOutOfBounds)?;
+    require!(
+        *parent_cond_mint == underlying,
+        VaultError::InvalidParentVault
+    );
+
+    let link = Some(ParentLink {
+        vault: parent_vault.key(),
+        vault_type: parent_vault_type,
+        option: parent_option,
+    });
+    if vault_type == VaultType::Base {
+        vault.base_parent = link;
+    } else {
+        vault.quote_parent = link;
+    }
+
+    emit!(ParentVaultSet {
+        vault: vault.key(),
+        vault_type,
+        parent_vault: parent_vault.key(),
+        parent_vault_type,
+        parent_option,
+    });
+
+    Ok(())
+}
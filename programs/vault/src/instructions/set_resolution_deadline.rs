@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct ResolutionDeadlineSet {
+    pub vault: Pubkey,
+    pub resolution_deadline: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct SetResolutionDeadline<'info> {
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    // Fixed once the vault is activated
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Setup @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+}
+
+pub fn set_resolution_deadline_handler(
+    ctx: Context<SetResolutionDeadline>,
+    resolution_deadline: Option<i64>,
+) -> Result<()> {
+    if let Some(deadline) = resolution_deadline {
+        require!(
+            deadline > Clock::get()?.unix_timestamp,
+            VaultError::InvalidResolutionDeadline
+        );
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.resolution_deadline = resolution_deadline;
+
+    emit!(ResolutionDeadlineSet {
+        vault: vault.key(),
+        resolution_deadline,
+    });
+
+    Ok(())
+}
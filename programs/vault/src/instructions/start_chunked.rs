@@ -0,0 +1,181 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::common::{check_deposit_limits, record_deposit, UserVaultAction};
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[event]
+pub struct ChunkedActionStarted {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub action: ChunkedAction,
+    pub amount: u64,
+    pub fee: u64,              // Protocol deposit fee, 0 for withdrawals
+    pub escrow_balance: u64,   // Escrow balance afterwards
+    pub cond_supply: Vec<u64>, // Empty: conditional mints change in process_chunk
+    pub slot: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(vault_type: VaultType)]
+pub struct StartChunked<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>, // User
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+
+    // One chunked action per user and vault type at a time
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ChunkProgress::INIT_SPACE,
+        seeds = [
+            CHUNK_PROGRESS_SEED,
+            vault.key().as_ref(),
+            signer.key().as_ref(),
+            &[vault_type as u8],
+        ],
+        bump,
+    )]
+    pub chunk_progress: Box<Account<'info, ChunkProgress>>,
+
+    // Regular Mint (base or quote depending on vault_type)
+    #[account(
+        address = vault.underlying(vault_type).address @ VaultError::InvalidMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Escrow ATA for regular mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: User token account for regular mint, only used for deposits.
+    /// Validated in handler via `validate_user_token_account`
+    #[account(mut)]
+    pub user_ata: UncheckedAccount<'info>,
+
+    // Programs
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    // Remaining accounts:
+    // - remaining_accounts[0]: user's deposit record, for deposits into vaults with an
+    //   allow-list or per-user caps
+}
+
+pub fn start_chunked_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, StartChunked<'info>>,
+    vault_type: VaultType,
+    action: ChunkedAction,
+    amount: u64,
+) -> Result<()> {
+    // Validate that amount is non-zero
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    // Deposits pay in up front; conditional tokens are minted chunk by chunk.
+    // Withdrawals pay out once every option has been burned
+    let mut chunk_amount = amount;
+    let mut fee = 0;
+    if action == ChunkedAction::Deposit {
+        UserVaultAction::validate_user_token_account(
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.token_program.key(),
+            &ctx.accounts.user_ata,
+        )?;
+
+        let escrow_before = ctx.accounts.vault_ata.amount;
+        transfer_tokens(
+            ctx.accounts.user_ata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.vault_ata.to_account_info(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        (chunk_amount, fee) = record_deposit(
+            &mut ctx.accounts.vault,
+            vault_type,
+            &mut ctx.accounts.vault_ata,
+            escrow_before,
+        )?;
+        check_deposit_limits(
+            &ctx.accounts.vault,
+            vault_type,
+            &ctx.accounts.signer.key(),
+            chunk_amount,
+            ctx.remaining_accounts.first(),
+            ctx.accounts.signer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    let chunk_progress = &mut ctx.accounts.chunk_progress;
+    chunk_progress.bump = ctx.bumps.chunk_progress;
+    chunk_progress.vault = ctx.accounts.vault.key();
+    chunk_progress.user = ctx.accounts.signer.key();
+    chunk_progress.vault_type = vault_type;
+    chunk_progress.action = action;
+    chunk_progress.amount = chunk_amount;
+    chunk_progress.processed = 0;
+
+    // A deposit's escrow is owed to it from here on; a withdrawal's from its first burn
+    let vault = &mut ctx.accounts.vault;
+    if chunk_progress.holds_value() {
+        vault.pending_chunks = vault
+            .pending_chunks
+            .checked_add(1)
+            .ok_or(VaultError::MathOverflow)?;
+    }
+
+    emit!(ChunkedActionStarted {
+        vault: vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        action,
+        amount: chunk_amount,
+        fee,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: Vec::new(),
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
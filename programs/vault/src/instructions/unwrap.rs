@@ -0,0 +1,144 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{ReceiptVaultAction, UserVaultAction};
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::VaultType;
+
+#[event]
+pub struct ReceiptUnwrapped {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub amount: u64,
+    pub escrow_balance: u64,   // Escrow balance, unchanged
+    pub cond_supply: Vec<u64>, // Supply of each conditional mint afterwards
+    pub slot: u64,
+}
+
+pub fn unwrap_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiptVaultAction<'info>>,
+    vault_type: VaultType,
+    amount: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+
+    let num_options = vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that amount is non-zero
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    let vault_cond_mints = vault.cond_mints(vault_type);
+
+    UserVaultAction::validate_user_token_account(
+        &ctx.accounts.receipt_mint.key(),
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.conditional_token_program.key(),
+        &ctx.accounts.user_receipt_ata,
+    )?;
+
+    // 1. Burn the receipt; will throw if the user doesn't hold enough
+    burn_tokens(
+        ctx.accounts.receipt_mint.to_account_info(),
+        ctx.accounts.user_receipt_ata.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.conditional_token_program.to_account_info(),
+        amount,
+    )?;
+
+    // 2. Mint `amount` of every option back to the user
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    for (i, vault_cond_mint) in vault_cond_mints.iter().enumerate() {
+        let cond_mint_info = &ctx.remaining_accounts[i * 2];
+        let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
+
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.conditional_token_program.key(),
+            user_cond_ata_info,
+        )?;
+
+        // Create user's ATA if needed
+        if user_cond_ata_info.data_is_empty() {
+            create_associated_token_account(
+                ctx.accounts.signer.to_account_info(),
+                user_cond_ata_info.clone(),
+                ctx.accounts.signer.to_account_info(),
+                cond_mint_info.clone(),
+                ctx.accounts.conditional_token_program.to_account_info(),
+                ctx.accounts.associated_token_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+
+        mint_to_signed(
+            cond_mint_info.clone(),
+            user_cond_ata_info.clone(),
+            vault.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
+            amount,
+            &[vault_seeds],
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    let mask = vault.all_options_mask();
+    vault.remove_set_coverage(vault_type, mask, amount)?;
+    vault.check_set_coverage(vault_type)?;
+
+    emit!(ReceiptUnwrapped {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.signer.key(),
+        vault_type,
+        amount,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub fee_recipient: Pubkey,
+    pub fee_rates: FeeRates,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(address = protocol_config.admin @ VaultError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+// New rates only apply to vaults initialized afterwards.
+// `pending_admin` nominates a new admin, who takes over with accept_protocol_admin; None cancels a pending handover
+pub fn update_protocol_config_handler(
+    ctx: Context<UpdateProtocolConfig>,
+    pending_admin: Option<Pubkey>,
+    fee_recipient: Pubkey,
+    fee_rates: FeeRates,
+) -> Result<()> {
+    require!(
+        fee_rates.deposit <= MAX_PROTOCOL_FEE && fee_rates.redeem <= MAX_PROTOCOL_FEE,
+        VaultError::InvalidFee
+    );
+
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.pending_admin = pending_admin;
+    protocol_config.fee_recipient = fee_recipient;
+    protocol_config.fee_rates = fee_rates;
+
+    emit!(ProtocolConfigUpdated {
+        admin: protocol_config.admin,
+        pending_admin,
+        fee_recipient,
+        fee_rates,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[event]
+pub struct VaultVoided {
+    pub vault: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct VoidVault<'info> {
+    /// Owner of the vault — needs to sign
+    #[account(address = vault.owner @ VaultError::Unauthorized)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            vault.owner.as_ref(),
+            &vault.nonce.to_le_bytes(),
+        ],
+        bump = vault.bump,
+        constraint = vault.state == VaultState::Active @ VaultError::InvalidState,
+        // The owner can't override the oracle; oracle vaults only void through expire
+        constraint = !vault.oracle_resolved @ VaultError::OracleResolved,
+    )]
+    pub vault: Box<Account<'info, VaultAccount>>,
+}
+
+pub fn void_vault_handler(ctx: Context<VoidVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.state = VaultState::Voided;
+
+    // The resolution must leave OR-position and receipt claims covered
+    vault.check_set_coverage(VaultType::Base)?;
+    vault.check_set_coverage(VaultType::Quote)?;
+
+    emit!(VaultVoided { vault: vault.key() });
+
+    Ok(())
+}
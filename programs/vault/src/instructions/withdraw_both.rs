@@ -0,0 +1,187 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{DualVaultAction, UserVaultAction};
+use crate::constants::*;
+use crate::errors::*;
+use crate::instructions::withdrawal::VaultWithdrawal;
+use crate::utils::*;
+use crate::VaultType;
+
+pub fn withdraw_both_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DualVaultAction<'info>>,
+    base_amount: u64,
+    quote_amount: u64,
+) -> Result<()> {
+    let num_options = ctx.accounts.vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == num_options * 4,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that at least one amount is non-zero
+    require!(
+        base_amount > 0 || quote_amount > 0,
+        VaultError::InvalidAmount
+    );
+
+    let (cond_mints, user_cond_atas) = ctx.remaining_accounts.split_at(num_options * 2);
+    let sides = [
+        (VaultType::Base, base_amount),
+        (VaultType::Quote, quote_amount),
+    ];
+
+    for (i, (vault_type, amount)) in sides.into_iter().enumerate() {
+        if amount == 0 {
+            continue;
+        }
+
+        let options = i * num_options..(i + 1) * num_options;
+        withdraw_side(
+            ctx.accounts,
+            vault_type,
+            amount,
+            &cond_mints[options.clone()],
+            &user_cond_atas[options.clone()],
+        )?;
+
+        emit!(VaultWithdrawal {
+            vault: ctx.accounts.vault.key(),
+            user: ctx.accounts.signer.key(),
+            vault_type,
+            amount,
+            escrow_balance: if vault_type == VaultType::Base {
+                ctx.accounts.vault_base_ata.amount
+            } else {
+                ctx.accounts.vault_quote_ata.amount
+            },
+            cond_supply: cond_mints[options]
+                .iter()
+                .map(mint_supply)
+                .collect::<Result<_>>()?,
+            slot: Clock::get()?.slot,
+        });
+    }
+
+    Ok(())
+}
+
+// Same steps as `withdraw` for one vault type
+fn withdraw_side<'info>(
+    accounts: &mut DualVaultAction<'info>,
+    vault_type: VaultType,
+    amount: u64,
+    cond_mints: &[AccountInfo<'info>],
+    user_cond_atas: &[AccountInfo<'info>],
+) -> Result<()> {
+    let (mint, vault_ata, user_ata, token_program) = if vault_type == VaultType::Base {
+        (
+            &accounts.base_mint,
+            &mut accounts.vault_base_ata,
+            &accounts.user_base_ata,
+            &accounts.base_token_program,
+        )
+    } else {
+        (
+            &accounts.quote_mint,
+            &mut accounts.vault_quote_ata,
+            &accounts.user_quote_ata,
+            &accounts.quote_token_program,
+        )
+    };
+    let vault = &accounts.vault;
+
+    UserVaultAction::init_user_token_account(
+        accounts.signer.to_account_info(),
+        accounts.signer.to_account_info(),
+        user_ata.to_account_info(),
+        mint.to_account_info(),
+        token_program.to_account_info(),
+        accounts.associated_token_program.to_account_info(),
+        accounts.system_program.to_account_info(),
+    )?;
+
+    // 1. Burn `amount` of every conditional token
+    let cond_accounts = cond_mints.iter().zip(user_cond_atas.iter());
+    for (vault_cond_mint, (cond_mint_info, user_cond_ata_info)) in
+        vault.cond_mints(vault_type).iter().zip(cond_accounts)
+    {
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        // User must have conditional tokens to withdraw
+        require!(
+            !user_cond_ata_info.data_is_empty(),
+            VaultError::InvalidUserAta
+        );
+
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &accounts.signer.key(),
+            &accounts.conditional_token_program.key(),
+            user_cond_ata_info,
+        )?;
+
+        // Will throw if token account doesn't have enough tokens
+        burn_tokens(
+            cond_mint_info.clone(),
+            user_cond_ata_info.clone(),
+            accounts.signer.to_account_info(),
+            accounts.conditional_token_program.to_account_info(),
+            amount,
+        )?;
+    }
+
+    // 2. Transfer regular tokens: vault -> user
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+    transfer_signed(
+        vault_ata.to_account_info(),
+        mint.to_account_info(),
+        user_ata.to_account_info(),
+        vault.to_account_info(),
+        token_program.to_account_info(),
+        amount,
+        mint.decimals,
+        &[vault_seeds],
+    )?;
+
+    // Track totals & check escrow invariant
+    vault_ata.reload()?;
+    let escrow_amount = vault_ata.amount;
+    let totals = accounts.vault.totals_mut(vault_type);
+    totals.withdrawn = totals
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(VaultError::MathOverflow)?;
+    accounts.vault.check_escrow(vault_type, escrow_amount)?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+use anchor_lang::prelude::*;
+
+use crate::common::{OperatorVaultAction, UserVaultAction};
+use crate::constants::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::VaultType;
+
+#[event]
+pub struct VaultWithdrawalFor {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub signer: Pubkey,
+    pub vault_type: VaultType,
+    pub amount: u64,
+    pub escrow_balance: u64,   // Escrow balance after the withdrawal
+    pub cond_supply: Vec<u64>, // Supply of each conditional mint after the withdrawal
+    pub slot: u64,
+}
+
+pub fn withdraw_for_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, OperatorVaultAction<'info>>,
+    vault_type: VaultType,
+    amount: u64,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let num_options = vault.num_options as usize;
+
+    // Validate we have the right number of remaining accounts
+    require!(
+        ctx.remaining_accounts.len() == num_options * 2,
+        VaultError::InvalidNumberOfAccounts
+    );
+
+    // Validate that amount is non-zero
+    require!(amount > 0, VaultError::InvalidAmount);
+
+    ctx.accounts.init_owner_ata()?;
+
+    let nonce_bytes = vault.nonce.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        VAULT_SEED,
+        vault.owner.as_ref(),
+        &nonce_bytes,
+        &[vault.bump],
+    ];
+
+    // 1. Burn `amount` of each of the owner's conditional tokens
+    for (i, vault_cond_mint) in vault.cond_mints(vault_type).iter().enumerate() {
+        let cond_mint_info = &ctx.remaining_accounts[i * 2];
+        let owner_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
+
+        // Validate the conditional mint PDA
+        require!(
+            cond_mint_info.key() == *vault_cond_mint,
+            VaultError::InvalidConditionalMint
+        );
+
+        // Owner must have conditional tokens to withdraw
+        require!(
+            !owner_cond_ata_info.data_is_empty(),
+            VaultError::InvalidUserAta
+        );
+
+        UserVaultAction::validate_user_token_account(
+            &cond_mint_info.key(),
+            &ctx.accounts.owner.key(),
+            &ctx.accounts.conditional_token_program.key(),
+            owner_cond_ata_info,
+        )?;
+
+        // Operators burn through the approval PDA, which the owner set as SPL delegate;
+        // otherwise the signer must be the SPL delegate itself.
+        // Will throw if token account doesn't have enough tokens,
+        // or if the delegate's allowance doesn't cover `amount`
+        match &ctx.accounts.operator_approval {
+            Some(operator_approval) => {
+                let approval_seeds: &[&[u8]] = &[
+                    OPERATOR_APPROVAL_SEED,
+                    operator_approval.vault.as_ref(),
+                    operator_approval.owner.as_ref(),
+                    operator_approval.operator.as_ref(),
+                    &[operator_approval.bump],
+                ];
+                burn_signed(
+                    cond_mint_info.clone(),
+                    owner_cond_ata_info.clone(),
+                    operator_approval.to_account_info(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    amount,
+                    &[approval_seeds],
+                )?;
+            }
+            None => {
+                burn_tokens(
+                    cond_mint_info.clone(),
+                    owner_cond_ata_info.clone(),
+                    ctx.accounts.signer.to_account_info(),
+                    ctx.accounts.conditional_token_program.to_account_info(),
+                    amount,
+                )?;
+            }
+        }
+    }
+
+    // 2. Transfer regular tokens: vault -> owner
+    transfer_signed(
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.owner_ata.to_account_info(),
+        vault.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.mint.decimals,
+        &[vault_seeds],
+    )?;
+
+    // Track totals & check escrow invariant
+    ctx.accounts.vault_ata.reload()?;
+    let vault = &mut ctx.accounts.vault;
+    let totals = vault.totals_mut(vault_type);
+    totals.withdrawn = totals
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(VaultError::MathOverflow)?;
+    vault.check_escrow(vault_type, ctx.accounts.vault_ata.amount)?;
+
+    emit!(VaultWithdrawalFor {
+        vault: ctx.accounts.vault.key(),
+        owner: ctx.accounts.owner.key(),
+        signer: ctx.accounts.signer.key(),
+        vault_type,
+        amount,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
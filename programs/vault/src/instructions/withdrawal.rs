@@ -31,6 +31,10 @@ pub struct VaultWithdrawal {
     pub user: Pubkey,
     pub vault_type: VaultType,
     pub amount: u64,
+    pub escrow_balance: u64, // Escrow balance after the withdrawal
+    // Supply of each conditional mint after the withdrawal; empty for chunked actions (see ChunkProcessed)
+    pub cond_supply: Vec<u64>,
+    pub slot: u64,
 }
 
 pub fn withdrawal_handler<'info>(
@@ -42,6 +46,8 @@ pub fn withdrawal_handler<'info>(
 
     require!(vault.state == VaultState::Active, VaultError::InvalidState);
 
+    ctx.accounts.init_user_ata()?;
+
     let num_options = vault.num_options as usize;
 
     // Validate we have the right number of remaining accounts
@@ -53,19 +59,15 @@ pub fn withdrawal_handler<'info>(
     // Validate that amount is non-zero
     require!(amount > 0, VaultError::InvalidAmount);
 
-    let vault_cond_mints = if vault_type == VaultType::Base {
-        vault.cond_base_mints
-    } else {
-        vault.cond_quote_mints
-    };
+    let vault_cond_mints = vault.cond_mints(vault_type);
 
-    for i in 0..num_options {
+    for (i, vault_cond_mint) in vault_cond_mints.iter().enumerate().take(num_options) {
         let cond_mint_info = &ctx.remaining_accounts[i * 2];
         let user_cond_ata_info = &ctx.remaining_accounts[i * 2 + 1];
 
         // Validate the conditional mint PDA
         require!(
-            cond_mint_info.key() == vault_cond_mints[i],
+            cond_mint_info.key() == *vault_cond_mint,
             VaultError::InvalidConditionalMint
         );
 
@@ -75,9 +77,10 @@ pub fn withdrawal_handler<'info>(
             VaultError::InvalidUserAta
         );
 
-        UserVaultAction::validate_user_ata(
+        UserVaultAction::validate_user_token_account(
             &cond_mint_info.key(),
             &ctx.accounts.signer.key(),
+            &ctx.accounts.conditional_token_program.key(),
             user_cond_ata_info,
         )?;
 
@@ -86,7 +89,7 @@ pub fn withdrawal_handler<'info>(
             cond_mint_info.clone(),
             user_cond_ata_info.clone(),
             ctx.accounts.signer.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.conditional_token_program.to_account_info(),
             amount,
         )?;
     }
@@ -102,18 +105,35 @@ pub fn withdrawal_handler<'info>(
     // 2. Transfer regular tokens: vault -> user
     transfer_signed(
         ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
         ctx.accounts.user_ata.to_account_info(),
         vault.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         amount,
+        ctx.accounts.mint.decimals,
         &[vault_seeds],
     )?;
 
+    // Track totals & check escrow invariant
+    let totals = ctx.accounts.vault.totals_mut(vault_type);
+    totals.withdrawn = totals
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(VaultError::MathOverflow)?;
+    ctx.accounts.check_escrow(vault_type)?;
+
     emit!(VaultWithdrawal {
-        vault: vault.key(),
+        vault: ctx.accounts.vault.key(),
         user: ctx.accounts.signer.key(),
         vault_type,
         amount,
+        escrow_balance: ctx.accounts.vault_ata.amount,
+        cond_supply: ctx.remaining_accounts[..num_options * 2]
+            .iter()
+            .step_by(2)
+            .map(mint_supply)
+            .collect::<Result<_>>()?,
+        slot: Clock::get()?.slot,
     });
 
     Ok(())
@@ -41,12 +41,73 @@ pub mod vault {
     /*
      * Admin Actions
      */
-    pub fn initialize(ctx: Context<InitializeVault>, nonce: u16) -> Result<()> {
-        instructions::initialize::initialize_handler(ctx, nonce)
+    pub fn initialize(
+        ctx: Context<InitializeVault>,
+        nonce: u16,
+        base_symbol: Option<String>,
+        quote_symbol: Option<String>,
+        option_labels: [String; 2],
+        permanent_delegate: bool,
+    ) -> Result<()> {
+        instructions::initialize::initialize_handler(
+            ctx,
+            nonce,
+            base_symbol,
+            quote_symbol,
+            option_labels,
+            permanent_delegate,
+        )
+    }
+
+    pub fn add_option(ctx: Context<AddOption>, uri: Option<String>, label: String) -> Result<()> {
+        instructions::add_option::add_option_handler(ctx, uri, label)
+    }
+
+    pub fn set_resolution_deadline(
+        ctx: Context<SetResolutionDeadline>,
+        resolution_deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_resolution_deadline::set_resolution_deadline_handler(
+            ctx,
+            resolution_deadline,
+        )
+    }
+
+    pub fn set_parent(
+        ctx: Context<SetParent>,
+        vault_type: VaultType,
+        parent_vault_type: VaultType,
+        parent_option: u8,
+    ) -> Result<()> {
+        instructions::set_parent::set_parent_handler(
+            ctx,
+            vault_type,
+            parent_vault_type,
+            parent_option,
+        )
+    }
+
+    pub fn set_deposit_limits(
+        ctx: Context<SetDepositLimits>,
+        base_caps: DepositCaps,
+        quote_caps: DepositCaps,
+        allow_list: bool,
+    ) -> Result<()> {
+        instructions::set_deposit_limits::set_deposit_limits_handler(
+            ctx, base_caps, quote_caps, allow_list,
+        )
+    }
+
+    pub fn allow_depositor(ctx: Context<AllowDepositor>, allowed: bool) -> Result<()> {
+        instructions::allow_depositor::allow_depositor_handler(ctx, allowed)
     }
 
-    pub fn add_option(ctx: Context<AddOption>) -> Result<()> {
-        instructions::add_option::add_option_handler(ctx)
+    pub fn configure_oracle(
+        ctx: Context<ConfigureOracle>,
+        resolve_at: i64,
+        thresholds: Vec<u128>,
+    ) -> Result<()> {
+        instructions::configure_oracle::configure_oracle_handler(ctx, resolve_at, thresholds)
     }
 
     pub fn activate(ctx: Context<ActivateVault>) -> Result<()> {
@@ -57,6 +118,40 @@ pub mod vault {
         instructions::finalize::finalize_vault_handler(ctx, winning_idx)
     }
 
+    pub fn finalize_weighted(
+        ctx: Context<FinalizeWeightedVault>,
+        payout_weights: Vec<u16>,
+    ) -> Result<()> {
+        instructions::finalize_weighted::finalize_weighted_handler(ctx, payout_weights)
+    }
+
+    pub fn void(ctx: Context<VoidVault>) -> Result<()> {
+        instructions::void::void_vault_handler(ctx)
+    }
+
+    pub fn close_vault<'info>(ctx: Context<'_, '_, 'info, 'info, CloseVault<'info>>) -> Result<()> {
+        instructions::close_vault::close_vault_handler(ctx)
+    }
+
+    /*
+     * Permissionless Actions
+     */
+    pub fn expire(ctx: Context<ExpireVault>) -> Result<()> {
+        instructions::expire::expire_vault_handler(ctx)
+    }
+
+    pub fn resolve(ctx: Context<ResolveVault>) -> Result<()> {
+        instructions::resolve::resolve_vault_handler(ctx)
+    }
+
+    pub fn migrate(ctx: Context<MigrateVault>) -> Result<()> {
+        instructions::migrate::migrate_vault_handler(ctx)
+    }
+
+    pub fn collect_fees(ctx: Context<CollectFees>, vault_type: VaultType) -> Result<()> {
+        instructions::collect_fees::collect_fees_handler(ctx, vault_type)
+    }
+
     /*
      * User Vault Actions
      */
@@ -76,10 +171,188 @@ pub mod vault {
         instructions::withdrawal::withdrawal_handler(ctx, vault_type, amount)
     }
 
+    pub fn deposit_both<'info>(
+        ctx: Context<'_, '_, '_, 'info, DualVaultAction<'info>>,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_both::deposit_both_handler(ctx, base_amount, quote_amount)
+    }
+
+    pub fn withdraw_both<'info>(
+        ctx: Context<'_, '_, '_, 'info, DualVaultAction<'info>>,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_both::withdraw_both_handler(ctx, base_amount, quote_amount)
+    }
+
+    pub fn start_chunked<'info>(
+        ctx: Context<'_, '_, '_, 'info, StartChunked<'info>>,
+        vault_type: VaultType,
+        action: ChunkedAction,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::start_chunked::start_chunked_handler(ctx, vault_type, action, amount)
+    }
+
+    pub fn process_chunk<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessChunk<'info>>,
+        vault_type: VaultType,
+        start_option: u8,
+    ) -> Result<()> {
+        instructions::process_chunk::process_chunk_handler(ctx, vault_type, start_option)
+    }
+
+    pub fn cancel_chunked<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelChunked<'info>>,
+        vault_type: VaultType,
+        start_option: u8,
+    ) -> Result<()> {
+        instructions::cancel_chunked::cancel_chunked_handler(ctx, vault_type, start_option)
+    }
+
     pub fn redeem_winnings<'info>(
         ctx: Context<'_, '_, 'info, 'info, UserVaultAction<'info>>,
         vault_type: VaultType,
     ) -> Result<()> {
         instructions::redeem_winnings::redeem_winnings_handler(ctx, vault_type)
     }
+
+    pub fn redeem<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UserVaultAction<'info>>,
+        vault_type: VaultType,
+        amount: Option<u64>,
+        close_accounts: bool,
+    ) -> Result<()> {
+        instructions::redeem::redeem_handler(ctx, vault_type, amount, close_accounts)
+    }
+
+    pub fn redeem_for<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemFor<'info>>,
+        vault_type: VaultType,
+    ) -> Result<()> {
+        instructions::redeem_for::redeem_for_handler(ctx, vault_type)
+    }
+
+    pub fn merge_options<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetVaultAction<'info>>,
+        vault_type: VaultType,
+        mask: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::merge_options::merge_options_handler(ctx, vault_type, mask, amount)
+    }
+
+    pub fn split_option_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetVaultAction<'info>>,
+        vault_type: VaultType,
+        mask: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::split_option_set::split_option_set_handler(ctx, vault_type, mask, amount)
+    }
+
+    pub fn redeem_option_set(
+        ctx: Context<RedeemOptionSet>,
+        vault_type: VaultType,
+        mask: u64,
+    ) -> Result<()> {
+        instructions::redeem_option_set::redeem_option_set_handler(ctx, vault_type, mask)
+    }
+
+    pub fn wrap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiptVaultAction<'info>>,
+        vault_type: VaultType,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::wrap::wrap_handler(ctx, vault_type, amount)
+    }
+
+    pub fn unwrap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiptVaultAction<'info>>,
+        vault_type: VaultType,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unwrap::unwrap_handler(ctx, vault_type, amount)
+    }
+
+    pub fn redeem_receipt(
+        ctx: Context<RedeemReceipt>,
+        vault_type: VaultType,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_receipt::redeem_receipt_handler(ctx, vault_type, amount)
+    }
+
+    pub fn redeem_nested<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemNested<'info>>,
+        vault_type: VaultType,
+    ) -> Result<()> {
+        instructions::redeem_nested::redeem_nested_handler(ctx, vault_type)
+    }
+
+    /*
+     * Operator Actions
+     */
+
+    pub fn approve_operator(ctx: Context<ApproveOperator>) -> Result<()> {
+        instructions::approve_operator::approve_operator_handler(ctx)
+    }
+
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        instructions::revoke_operator::revoke_operator_handler(ctx)
+    }
+
+    pub fn deposit_for<'info>(
+        ctx: Context<'_, '_, '_, 'info, OperatorVaultAction<'info>>,
+        vault_type: VaultType,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_for::deposit_for_handler(ctx, vault_type, amount)
+    }
+
+    pub fn withdraw_for<'info>(
+        ctx: Context<'_, '_, '_, 'info, OperatorVaultAction<'info>>,
+        vault_type: VaultType,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_for::withdraw_for_handler(ctx, vault_type, amount)
+    }
+
+    /*
+     * Protocol Actions
+     */
+
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        admin: Pubkey,
+        fee_recipient: Pubkey,
+        fee_rates: FeeRates,
+    ) -> Result<()> {
+        instructions::initialize_protocol_config::initialize_protocol_config_handler(
+            ctx,
+            admin,
+            fee_recipient,
+            fee_rates,
+        )
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        pending_admin: Option<Pubkey>,
+        fee_recipient: Pubkey,
+        fee_rates: FeeRates,
+    ) -> Result<()> {
+        instructions::update_protocol_config::update_protocol_config_handler(
+            ctx,
+            pending_admin,
+            fee_recipient,
+            fee_rates,
+        )
+    }
+
+    pub fn accept_protocol_admin(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+        instructions::accept_protocol_admin::accept_protocol_admin_handler(ctx)
+    }
 }
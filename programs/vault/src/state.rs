@@ -17,8 +17,10 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
-use crate::constants::MAX_OPTIONS;
+use crate::constants::{MAX_OPTIONS, MAX_SYMBOL_LEN, PAYOUT_WEIGHT_TOTAL, VAULT_VERSION};
+use crate::errors::VaultError;
 
 #[derive(Copy, Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub enum VaultType {
@@ -35,6 +37,20 @@ pub enum VaultState {
     // Deposits / Withdrawals revoked
     // Redeem Winnings allowed
     Finalized(u8), // winning index
+    // Same as Finalized, but every option pays out pro-rata by payout_weights
+    FinalizedWeighted,
+    // Cancelled; every option redeems at 1/num_options
+    Voided,
+}
+
+impl VaultState {
+    /// Whether conditional tokens can be redeemed for underlying
+    pub fn is_resolved(&self) -> bool {
+        matches!(
+            self,
+            VaultState::Finalized(_) | VaultState::FinalizedWeighted | VaultState::Voided
+        )
+    }
 }
 
 #[derive(Copy, Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
@@ -43,8 +59,80 @@ pub struct TokenMint {
     pub decimals: u8
 }
 
-#[derive(InitSpace)]
-#[account]
+// A nested vault's underlying is `option` of the parent vault's `vault_type` conditional mints
+#[derive(Copy, Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ParentLink {
+    pub vault: Pubkey,
+    pub vault_type: VaultType,
+    pub option: u8,
+}
+
+#[derive(Copy, Clone, Default, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct DepositCaps {
+    pub total: Option<u64>,    // Max outstanding escrow
+    pub per_user: Option<u64>, // Max cumulative deposits per depositor
+}
+
+// Protocol fee rates in basis points, copied from ProtocolConfig at initialize
+#[derive(Copy, Clone, Default, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct FeeRates {
+    pub deposit: u16, // Of what the escrow receives
+    pub redeem: u16,  // Of each payout
+}
+
+impl FeeRates {
+    pub fn deposit_fee(&self, received: u64) -> Result<u64> {
+        Self::compute_fee(received, self.deposit)
+    }
+
+    pub fn redeem_fee(&self, payout: u64) -> Result<u64> {
+        Self::compute_fee(payout, self.redeem)
+    }
+
+    /// Fee in basis points, rounded up to 1 when nonzero bps would truncate to 0
+    fn compute_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+        let mut fee = (amount as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(VaultError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(VaultError::MathOverflow)? as u64;
+
+        // Prevent dust amounts from avoiding fees via integer truncation
+        if fee_bps > 0 && fee == 0 && amount > 0 {
+            fee = 1;
+        }
+
+        Ok(fee)
+    }
+}
+
+// Running totals of regular tokens moved through the escrow, per vault type
+#[derive(Copy, Clone, Default, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct VaultTotals {
+    pub deposited: u64, // Amount backing minted conditional tokens (net of fees)
+    pub withdrawn: u64,
+    pub redeemed: u64, // Conditional claims paid out, including redeem fees
+    pub fees: u64,     // Protocol fees held in escrow until collect_fees
+}
+
+impl VaultTotals {
+    /// Regular tokens the escrow still owes to conditional holders
+    pub fn outstanding(&self) -> Result<u64> {
+        self.deposited
+            .checked_sub(self.withdrawn)
+            .and_then(|net| net.checked_sub(self.redeemed))
+            .ok_or(VaultError::EscrowInvariantViolated.into())
+    }
+
+    pub fn accrue_fee(&mut self, fee: u64) -> Result<()> {
+        self.fees = self.fees.checked_add(fee).ok_or(VaultError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+// Account traits are implemented below instead of by #[account], so that
+// un-migrated vaults fail with InvalidVersion rather than failing to decode
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct VaultAccount {
     pub version: u8,
     pub bump: u8,
@@ -56,6 +144,544 @@ pub struct VaultAccount {
 
     // Number of markets (2 <= n <= MAX_OPTIONS)
     pub num_options: u8,
-    pub cond_base_mints: [Pubkey; MAX_OPTIONS as usize], // allocate for max options
-    pub cond_quote_mints: [Pubkey; MAX_OPTIONS as usize], // allocate for max options
+    // Per-option vectors, grown with the account in add_option (see VaultAccount::space)
+    #[max_len(0)]
+    pub cond_base_mints: Vec<Pubkey>,
+    #[max_len(0)]
+    pub cond_quote_mints: Vec<Pubkey>,
+
+    // Per-option payout in bps, set when FinalizedWeighted
+    #[max_len(0)]
+    pub payout_weights: Vec<u16>,
+
+    // Per-option OR-position and receipt supply covering the option, per type
+    // (see add_set_coverage)
+    #[max_len(0)]
+    pub base_set_coverage: Vec<u64>,
+    #[max_len(0)]
+    pub quote_set_coverage: Vec<u64>,
+
+    // After this unix time, anyone can void an unresolved vault
+    pub resolution_deadline: Option<i64>,
+
+    // Token program owning the conditional mints (Token-2022 for new vaults)
+    pub conditional_token_program: Pubkey,
+    // Vault is permanent delegate of its conditional mints; opted into at initialize
+    pub permanent_delegate: bool,
+
+    pub base_totals: VaultTotals,
+    pub quote_totals: VaultTotals,
+
+    // Set when the underlying is another vault's conditional mint (see set_parent)
+    pub base_parent: Option<ParentLink>,
+    pub quote_parent: Option<ParentLink>,
+
+    // ChunkProgress accounts holding escrowed value; close_vault waits for them
+    // (see ChunkProgress::holds_value)
+    pub pending_chunks: u32,
+
+    // OR-position and receipt mints created by merge_options and wrap;
+    // close_vault takes and closes them all
+    pub extra_mints: u32,
+
+    // Optional deposit limits, set during Setup (see set_deposit_limits)
+    pub base_caps: DepositCaps,
+    pub quote_caps: DepositCaps,
+    pub allow_list: bool, // Only depositors allowed via allow_depositor
+
+    // Resolved from an OracleConfig by `resolve` instead of by the owner
+    pub oracle_resolved: bool,
+
+    // Fixed at initialize, so protocol fee changes never apply to live vaults
+    pub fee_rates: FeeRates,
+
+    // Underlying symbols used in conditional mint names (see conditional_mint_metadata)
+    #[max_len(MAX_SYMBOL_LEN)]
+    pub base_symbol: String,
+    #[max_len(MAX_SYMBOL_LEN)]
+    pub quote_symbol: String,
+}
+
+impl Discriminator for VaultAccount {
+    // sha256("account:VaultAccount")[..8], as #[account] would derive it
+    const DISCRIMINATOR: &'static [u8] = &[230, 251, 241, 83, 139, 202, 93, 28];
+}
+
+impl Owner for VaultAccount {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for VaultAccount {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        writer
+            .write_all(Self::DISCRIMINATOR)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        AnchorSerialize::serialize(self, writer).map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+
+impl AccountDeserialize for VaultAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        let disc_len = Self::DISCRIMINATOR.len();
+        require!(buf.len() > disc_len, ErrorCode::AccountDiscriminatorNotFound);
+        require!(
+            buf.starts_with(Self::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Older layouts don't decode as the current one, so check the version first
+        require!(buf[disc_len] == VAULT_VERSION, VaultError::InvalidVersion);
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data = &buf[Self::DISCRIMINATOR.len()..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+impl VaultAccount {
+    /// Bytes per option across the per-option vectors
+    pub const OPTION_SPACE: usize = 32 + 32 + 2 + 8 + 8;
+
+    /// Account size (with discriminator) for a vault with `num_options` options
+    pub fn space(num_options: usize) -> usize {
+        8 + Self::INIT_SPACE + num_options * Self::OPTION_SPACE
+    }
+
+    /// Whether the resolution deadline (if any) has passed
+    pub fn is_past_deadline(&self, now: i64) -> bool {
+        self.resolution_deadline.is_some_and(|deadline| now >= deadline)
+    }
+
+    /// Token metadata (name, symbol) for a conditional mint, e.g. ("cBASE-12-PASS", "cBASE").
+    /// Derived from the underlying symbol, vault nonce and option label (index if empty).
+    pub fn conditional_mint_metadata(
+        &self,
+        vault_type: VaultType,
+        idx: u8,
+        label: &str,
+    ) -> (String, String) {
+        let ticker = self.symbol(vault_type);
+        let label = if label.is_empty() {
+            idx.to_string()
+        } else {
+            label.to_string()
+        };
+
+        (
+            format!("c{}-{}-{}", ticker, self.nonce, label),
+            format!("c{}", ticker),
+        )
+    }
+
+    /// Token metadata for an OR-position mint, e.g. ("cBASE-12-1|2", "cBASE")
+    pub fn set_mint_metadata(&self, vault_type: VaultType, mask: u64) -> (String, String) {
+        let ticker = self.symbol(vault_type);
+        let options: Vec<String> = Self::set_options(mask).map(|i| i.to_string()).collect();
+
+        (
+            format!("c{}-{}-{}", ticker, self.nonce, options.join("|")),
+            format!("c{}", ticker),
+        )
+    }
+
+    /// Token metadata for a receipt (complete set) mint, e.g. ("cBASE-12-all", "cBASE")
+    pub fn receipt_mint_metadata(&self, vault_type: VaultType) -> (String, String) {
+        let ticker = self.symbol(vault_type);
+
+        (
+            format!("c{}-{}-all", ticker, self.nonce),
+            format!("c{}", ticker),
+        )
+    }
+
+    fn symbol(&self, vault_type: VaultType) -> &str {
+        if vault_type == VaultType::Base {
+            &self.base_symbol
+        } else {
+            &self.quote_symbol
+        }
+    }
+
+    /// Fallback symbol for an underlying without one, e.g. "So11"
+    pub fn default_symbol(mint: &Pubkey) -> String {
+        mint.to_string().chars().take(4).collect()
+    }
+
+    /// Regular mint backing the given vault type
+    pub fn underlying(&self, vault_type: VaultType) -> TokenMint {
+        if vault_type == VaultType::Base {
+            self.base_mint
+        } else {
+            self.quote_mint
+        }
+    }
+
+    /// Mask with a bit set for every option
+    pub fn all_options_mask(&self) -> u64 {
+        u64::MAX
+            .checked_shr(64 - self.num_options as u32)
+            .unwrap_or(0)
+    }
+
+    /// Option indices in a set, ascending
+    pub fn set_options(mask: u64) -> impl Iterator<Item = usize> {
+        (0..MAX_OPTIONS as usize).filter(move |i| mask >> i & 1 == 1)
+    }
+
+    /// An option set must name existing options, at least two, and not all of them
+    pub fn check_option_set(&self, mask: u64) -> Result<()> {
+        let count = mask.count_ones() as u8;
+        require!(
+            mask.checked_shr(self.num_options as u32).unwrap_or(0) == 0
+                && count >= 2
+                && count < self.num_options,
+            VaultError::InvalidOptionSet
+        );
+        Ok(())
+    }
+
+    pub fn parent(&self, vault_type: VaultType) -> Option<ParentLink> {
+        if vault_type == VaultType::Base {
+            self.base_parent
+        } else {
+            self.quote_parent
+        }
+    }
+
+    pub fn cond_mints(&self, vault_type: VaultType) -> &[Pubkey] {
+        if vault_type == VaultType::Base {
+            &self.cond_base_mints
+        } else {
+            &self.cond_quote_mints
+        }
+    }
+
+    pub fn set_coverage(&self, vault_type: VaultType) -> &[u64] {
+        if vault_type == VaultType::Base {
+            &self.base_set_coverage
+        } else {
+            &self.quote_set_coverage
+        }
+    }
+
+    fn set_coverage_mut(&mut self, vault_type: VaultType) -> &mut [u64] {
+        if vault_type == VaultType::Base {
+            &mut self.base_set_coverage
+        } else {
+            &mut self.quote_set_coverage
+        }
+    }
+
+    /// Counts `amount` of the OR-position over `mask` (or of receipts, over every option)
+    /// toward each option it covers
+    pub fn add_set_coverage(
+        &mut self,
+        vault_type: VaultType,
+        mask: u64,
+        amount: u64,
+    ) -> Result<()> {
+        let coverage = self.set_coverage_mut(vault_type);
+        for i in Self::set_options(mask) {
+            coverage[i] = coverage[i]
+                .checked_add(amount)
+                .ok_or(VaultError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn remove_set_coverage(
+        &mut self,
+        vault_type: VaultType,
+        mask: u64,
+        amount: u64,
+    ) -> Result<()> {
+        let coverage = self.set_coverage_mut(vault_type);
+        for i in Self::set_options(mask) {
+            coverage[i] = coverage[i]
+                .checked_sub(amount)
+                .ok_or(VaultError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn caps(&self, vault_type: VaultType) -> DepositCaps {
+        if vault_type == VaultType::Base {
+            self.base_caps
+        } else {
+            self.quote_caps
+        }
+    }
+
+    /// Whether deposits need the depositor's DepositRecord (allow-list or per-user caps)
+    pub fn tracks_depositors(&self) -> bool {
+        self.allow_list || self.base_caps.per_user.is_some() || self.quote_caps.per_user.is_some()
+    }
+
+    pub fn totals(&self, vault_type: VaultType) -> VaultTotals {
+        if vault_type == VaultType::Base {
+            self.base_totals
+        } else {
+            self.quote_totals
+        }
+    }
+
+    pub fn totals_mut(&mut self, vault_type: VaultType) -> &mut VaultTotals {
+        if vault_type == VaultType::Base {
+            &mut self.base_totals
+        } else {
+            &mut self.quote_totals
+        }
+    }
+
+    /// Invariant: escrow holds at least what's still owed to conditional holders
+    /// plus uncollected protocol fees, and the owed amount covers OR-positions and receipts
+    pub fn check_escrow(&self, vault_type: VaultType, escrow_amount: u64) -> Result<()> {
+        let totals = self.totals(vault_type);
+        let owed = totals
+            .outstanding()?
+            .checked_add(totals.fees)
+            .ok_or(VaultError::MathOverflow)?;
+        require!(escrow_amount >= owed, VaultError::EscrowInvariantViolated);
+        self.check_set_coverage(vault_type)
+    }
+
+    /// Invariant: what's still owed to conditional holders covers what OR-position and
+    /// receipt holders can claim: any single option's coverage while unresolved,
+    /// else the coverage's payout under the resolution.
+    /// Needs no escrow account, so handlers that only move supply check it directly.
+    pub fn check_set_coverage(&self, vault_type: VaultType) -> Result<()> {
+        let coverage = self.set_coverage(vault_type);
+        let claims = if self.state.is_resolved() {
+            self.payout(coverage)?
+        } else {
+            coverage.iter().copied().max().unwrap_or(0)
+        };
+        require!(
+            self.totals(vault_type).outstanding()? >= claims,
+            VaultError::EscrowInvariantViolated
+        );
+        Ok(())
+    }
+
+    /// Whether option `idx` pays anything out under the current resolution
+    pub fn pays_out(&self, idx: usize) -> bool {
+        match self.state {
+            VaultState::Finalized(winning_idx) => idx == winning_idx as usize,
+            VaultState::FinalizedWeighted => self.payout_weights[idx] > 0,
+            VaultState::Voided => true,
+            _ => false,
+        }
+    }
+
+    /// Underlying owed for per-option conditional balances, once finalized.
+    /// Weighted and voided payouts round down once over the sum, leaving dust in the vault.
+    pub fn payout(&self, balances: &[u64]) -> Result<u64> {
+        match self.state {
+            VaultState::Finalized(winning_idx) => {
+                Ok(balances.get(winning_idx as usize).copied().unwrap_or(0))
+            }
+            VaultState::FinalizedWeighted => {
+                let weighted = balances
+                    .iter()
+                    .zip(self.payout_weights.iter())
+                    .try_fold(0u128, |acc, (&balance, &weight)| {
+                        acc.checked_add(balance as u128 * weight as u128)
+                    })
+                    .ok_or(VaultError::MathOverflow)?;
+
+                (weighted / PAYOUT_WEIGHT_TOTAL as u128)
+                    .try_into()
+                    .map_err(|_| VaultError::MathOverflow.into())
+            }
+            VaultState::Voided => {
+                let total = balances
+                    .iter()
+                    .try_fold(0u128, |acc, &balance| acc.checked_add(balance as u128))
+                    .ok_or(VaultError::MathOverflow)?;
+
+                (total / self.num_options as u128)
+                    .try_into()
+                    .map_err(|_| VaultError::MathOverflow.into())
+            }
+            _ => err!(VaultError::InvalidState),
+        }
+    }
+
+    /// Underlying owed for `amount` of the OR-position over `mask`:
+    /// the same payout as holding `amount` of every option in the set
+    pub fn set_payout(&self, mask: u64, amount: u64) -> Result<u64> {
+        let balances: Vec<u64> = (0..self.num_options as usize)
+            .map(|i| if mask >> i & 1 == 1 { amount } else { 0 })
+            .collect();
+        self.payout(&balances)
+    }
+
+    /// Decodes a vault written under an older `version` and upgrades it to the
+    /// current layout, filling any new fields with defaults.
+    /// `data` is the account data without the 8-byte discriminator.
+    ///
+    /// When `VaultAccount` changes shape, freeze the previous layout as
+    /// `VaultAccountV{n}` and add a decode arm for it here.
+    pub fn upgrade(version: u8, data: &[u8]) -> Result<Self> {
+        let mut vault: Self = match version {
+            1 => VaultAccountV1::deserialize(&mut &data[..])?.into(),
+            // Current or unknown versions have nothing to upgrade from
+            _ => return err!(VaultError::InvalidVersion),
+        };
+        vault.version = VAULT_VERSION;
+        Ok(vault)
+    }
+}
+
+/// Frozen v1 layout, with fixed option arrays and SPL Token conditional mints
+#[derive(AnchorDeserialize)]
+pub struct VaultAccountV1 {
+    pub version: u8,
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub base_mint: TokenMint,
+    pub quote_mint: TokenMint,
+    pub nonce: u16,
+    pub state: VaultState,
+    pub num_options: u8,
+    pub cond_base_mints: [Pubkey; 8], // v1 MAX_OPTIONS
+    pub cond_quote_mints: [Pubkey; 8],
+}
+
+// Totals start at zero; migrate seeds them from the escrow balances
+impl From<VaultAccountV1> for VaultAccount {
+    fn from(v1: VaultAccountV1) -> Self {
+        let num_options = v1.num_options as usize;
+
+        Self {
+            version: v1.version,
+            bump: v1.bump,
+            owner: v1.owner,
+            base_mint: v1.base_mint,
+            quote_mint: v1.quote_mint,
+            nonce: v1.nonce,
+            state: v1.state,
+            num_options: v1.num_options,
+            cond_base_mints: v1.cond_base_mints[..num_options].to_vec(),
+            cond_quote_mints: v1.cond_quote_mints[..num_options].to_vec(),
+            payout_weights: vec![0; num_options],
+            base_set_coverage: vec![0; num_options],
+            quote_set_coverage: vec![0; num_options],
+            resolution_deadline: None,
+            conditional_token_program: anchor_spl::token::ID,
+            permanent_delegate: false,
+            base_totals: VaultTotals::default(),
+            quote_totals: VaultTotals::default(),
+            base_parent: None,
+            quote_parent: None,
+            pending_chunks: 0,
+            extra_mints: 0,
+            base_caps: DepositCaps::default(),
+            quote_caps: DepositCaps::default(),
+            allow_list: false,
+            oracle_resolved: false,
+            fee_rates: FeeRates::default(),
+            base_symbol: Self::default_symbol(&v1.base_mint.address),
+            quote_symbol: Self::default_symbol(&v1.quote_mint.address),
+        }
+    }
+}
+
+// Protocol-wide fee settings; vaults copy the rates at initialize.
+// Without this account, new vaults charge no fees
+#[derive(InitSpace)]
+#[account]
+pub struct ProtocolConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // Nominated by update_protocol_config
+    pub fee_recipient: Pubkey, // Wallet whose ATAs receive collected fees
+    pub fee_rates: FeeRates,
+}
+
+// Lets `operator` deposit and withdraw on `owner`'s behalf in `vault`.
+// The PDA also acts as SPL delegate on the owner's regular token accounts (see deposit_for)
+// and conditional token accounts (see withdraw_for)
+#[derive(InitSpace)]
+#[account]
+pub struct OperatorApproval {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[derive(Copy, Clone, InitSpace, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub enum ChunkedAction {
+    Deposit,
+    Withdraw,
+}
+
+// A deposit or withdrawal split across transactions, for vaults with more options
+// than one transaction can touch (see start_chunked / process_chunk)
+#[derive(InitSpace)]
+#[account]
+pub struct ChunkProgress {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub vault_type: VaultType,
+    pub action: ChunkedAction,
+    pub amount: u64,
+    pub processed: u64, // Bitmask of options minted (deposit) or burned (withdraw)
+}
+
+impl ChunkProgress {
+    /// Whether part of the escrow belongs to this action: a deposit from the start,
+    /// a withdrawal once it has burned an option. Counted in `pending_chunks`
+    pub fn holds_value(&self) -> bool {
+        self.action == ChunkedAction::Deposit || self.processed != 0
+    }
+}
+
+// Per-depositor state for allow-listed or per-user capped vaults
+#[derive(InitSpace)]
+#[account]
+pub struct DepositRecord {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub allowed: bool,
+    pub base_deposited: u64,
+    pub quote_deposited: u64,
+}
+
+impl DepositRecord {
+    pub fn deposited_mut(&mut self, vault_type: VaultType) -> &mut u64 {
+        if vault_type == VaultType::Base {
+            &mut self.base_deposited
+        } else {
+            &mut self.quote_deposited
+        }
+    }
+}
+
+// Resolution rule for an oracle-resolved vault (see configure_oracle / resolve).
+// Option i wins when thresholds[i - 1] <= twap < thresholds[i]
+#[derive(InitSpace)]
+#[account]
+pub struct OracleConfig {
+    pub bump: u8,
+    pub vault: Pubkey,
+    pub pool: Pubkey,    // amm pool whose TWAP resolves the vault
+    pub resolve_at: i64, // Pool must cease trading at or after this unix time
+    #[max_len(MAX_OPTIONS - 1)]
+    pub thresholds: Vec<u128>, // Ascending, in the pool's TWAP price scale
+}
+
+impl OracleConfig {
+    /// Index of the option whose price range contains `twap`
+    pub fn winning_option(&self, twap: u128) -> u8 {
+        self.thresholds.iter().filter(|&&threshold| twap >= threshold).count() as u8
+    }
 }
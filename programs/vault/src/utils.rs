@@ -17,44 +17,75 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::associated_token;
-use anchor_spl::token::{self, Burn, MintTo, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint;
+use anchor_spl::token_interface::{
+    self, Burn, InitializeMint2, MetadataPointerInitialize, MintCloseAuthorityInitialize,
+    MintTo, PermanentDelegateInitialize, TokenMetadataInitialize, TransferChecked,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+#[event]
+pub struct TokenAccountCreated {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[event]
+pub struct TokenAccountClosed {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+}
 
 // User-signed token transfer
 pub fn transfer_tokens<'info>(
     from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
     to: AccountInfo<'info>,
     authority: AccountInfo<'info>,
     token_program: AccountInfo<'info>,
     amount: u64,
+    decimals: u8,
 ) -> Result<()> {
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from,
+        mint,
         to,
         authority,
     };
     let cpi_program = token_program;
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, amount)
+    token_interface::transfer_checked(cpi_ctx, amount, decimals)
 }
 
 // PDA-signed token transfer
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_signed<'info>(
     from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
     to: AccountInfo<'info>,
     authority: AccountInfo<'info>,
     token_program: AccountInfo<'info>,
     amount: u64,
+    decimals: u8,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from,
+        mint,
         to,
         authority,
     };
     let cpi_program = token_program;
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, amount)
+    token_interface::transfer_checked(cpi_ctx, amount, decimals)
 }
 
 // PDA-signed minting
@@ -73,7 +104,7 @@ pub fn mint_to_signed<'info>(
     };
     let cpi_program = token_program;
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::mint_to(cpi_ctx, amount)
+    token_interface::mint_to(cpi_ctx, amount)
 }
 
 // User-signed token burn
@@ -91,7 +122,26 @@ pub fn burn_tokens<'info>(
     };
     let cpi_program = token_program;
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::burn(cpi_ctx, amount)
+    token_interface::burn(cpi_ctx, amount)
+}
+
+// PDA-signed token burn (PDA as owner, SPL delegate or permanent delegate)
+pub fn burn_signed<'info>(
+    mint: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = Burn {
+        mint,
+        from,
+        authority,
+    };
+    let cpi_program = token_program;
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token_interface::burn(cpi_ctx, amount)
 }
 
 // Create ATA
@@ -104,6 +154,12 @@ pub fn create_associated_token_account<'info>(
     associated_token_program: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
+    let created = TokenAccountCreated {
+        account: associated_token.key(),
+        owner: authority.key(),
+        mint: mint.key(),
+        payer: payer.key(),
+    };
     let cpi_accounts = associated_token::Create {
         payer,
         associated_token,
@@ -114,7 +170,10 @@ pub fn create_associated_token_account<'info>(
     };
     let cpi_program = associated_token_program;
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    associated_token::create(cpi_ctx)
+    associated_token::create(cpi_ctx)?;
+
+    emit!(created);
+    Ok(())
 }
 
 pub fn close_token_account<'info>(
@@ -123,11 +182,221 @@ pub fn close_token_account<'info>(
     authority: AccountInfo<'info>,
     token_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let cpi_accounts = anchor_spl::token::CloseAccount {
+    let closed = TokenAccountClosed {
+        account: account.key(),
+        owner: authority.key(),
+        destination: destination.key(),
+    };
+    let cpi_accounts = token_interface::CloseAccount {
         account,
         destination,
         authority,
     };
     let cpi_ctx = CpiContext::new(token_program, cpi_accounts);
-    anchor_spl::token::close_account(cpi_ctx)
+    token_interface::close_account(cpi_ctx)?;
+
+    emit!(closed);
+    Ok(())
+}
+
+// PDA-signed close (token account, or mint with the close authority extension)
+pub fn close_account_signed<'info>(
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let closed = TokenAccountClosed {
+        account: account.key(),
+        owner: authority.key(),
+        destination: destination.key(),
+    };
+    let cpi_accounts = token_interface::CloseAccount {
+        account,
+        destination,
+        authority,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+    token_interface::close_account(cpi_ctx)?;
+
+    emit!(closed);
+    Ok(())
+}
+
+// Creates a rent-exempt PDA of `space` bytes owned by `owner`. Like Anchor's `init`,
+// tolerates lamports sent to the address ahead of time, which would fail `create_account`
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    owner: &Pubkey,
+    space: usize,
+    system_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        let cpi_accounts = system_program::CreateAccount {
+            from: payer,
+            to: account,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+        return system_program::create_account(cpi_ctx, rent, space as u64, owner);
+    }
+
+    let rent_due = rent.saturating_sub(account.lamports());
+    if rent_due > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: payer,
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        system_program::transfer(cpi_ctx, rent_due)?;
+    }
+
+    let cpi_accounts = system_program::Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+    system_program::allocate(cpi_ctx, space as u64)?;
+
+    let cpi_accounts = system_program::Assign {
+        account_to_assign: account,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+    system_program::assign(cpi_ctx, owner)
+}
+
+// Creates a conditional mint PDA under Token-2022 with a self-referencing metadata pointer
+// and the vault as mint and close authority (see close_vault). The vault is also made
+// permanent delegate when it opted in at initialize (see redeem_for)
+#[allow(clippy::too_many_arguments)]
+pub fn create_conditional_mint<'info>(
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    decimals: u8,
+    permanent_delegate: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut extensions = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::MintCloseAuthority,
+    ];
+    if permanent_delegate {
+        extensions.push(ExtensionType::PermanentDelegate);
+    }
+    let space = ExtensionType::try_calculate_account_len::<Mint>(&extensions)?;
+
+    // 1. Create the account, owned by the token program
+    create_pda_account(
+        payer,
+        mint.clone(),
+        token_program.key,
+        space,
+        system_program,
+        signer_seeds,
+    )?;
+
+    // 2. Extensions, which must precede the mint itself
+    let cpi_accounts = MetadataPointerInitialize {
+        token_program_id: token_program.clone(),
+        mint: mint.clone(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+    token_interface::metadata_pointer_initialize(cpi_ctx, Some(vault.key()), Some(mint.key()))?;
+
+    let cpi_accounts = MintCloseAuthorityInitialize {
+        token_program_id: token_program.clone(),
+        mint: mint.clone(),
+    };
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+    token_interface::mint_close_authority_initialize(cpi_ctx, Some(&vault.key()))?;
+
+    if permanent_delegate {
+        let cpi_accounts = PermanentDelegateInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint.clone(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+        token_interface::permanent_delegate_initialize(cpi_ctx, &vault.key())?;
+    }
+
+    // 3. The mint, with the vault as mint authority
+    let cpi_accounts = InitializeMint2 { mint };
+    let cpi_ctx = CpiContext::new(token_program, cpi_accounts);
+    token_interface::initialize_mint2(cpi_ctx, decimals, &vault.key(), None)
+}
+
+// PDA-signed token metadata init on a mint with a self-referencing metadata pointer
+// Tops up rent for the metadata TLV from the payer first
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_token_metadata<'info>(
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    name: String,
+    symbol: String,
+    uri: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let metadata = TokenMetadata {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        ..Default::default()
+    };
+    let new_len = mint.data_len() + metadata.tlv_size_of()?;
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint.lamports());
+
+    if rent_due > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: payer,
+            to: mint.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program, cpi_accounts);
+        system_program::transfer(cpi_ctx, rent_due)?;
+    }
+
+    let cpi_accounts = TokenMetadataInitialize {
+        program_id: token_program.clone(),
+        metadata: mint.clone(),
+        update_authority: authority.clone(),
+        mint_authority: authority,
+        mint,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+    token_interface::token_metadata_initialize(cpi_ctx, name, symbol, uri)
+}
+
+// Whether a Token-2022 mint already carries its token metadata
+pub fn has_token_metadata(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .is_ok())
+}
+
+// Symbol from a Token-2022 mint's own token metadata, if it carries one
+pub fn token_metadata_symbol(mint: &AccountInfo) -> Result<Option<String>> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .ok()
+        .map(|metadata| metadata.symbol))
+}
+
+// Current supply of a mint (SPL Token or Token-2022)
+pub fn mint_supply(mint: &AccountInfo) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    Ok(StateWithExtensions::<Mint>::unpack(&data)?.base.supply)
 }
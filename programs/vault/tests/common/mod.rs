@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Hand-built vault account data shared by the account tests.
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use vault::{TokenMint, VaultAccount, VaultState};
+
+/// v1 vault data: fixed arrays of 8 conditional mints per type, SPL Token conditional mints
+pub fn v1_vault_data(owner: Pubkey, num_options: u8, state: VaultState) -> Vec<u8> {
+    let mut data = VaultAccount::DISCRIMINATOR.to_vec();
+    data.push(1); // version
+    data.push(255); // bump
+    data.extend(owner.to_bytes());
+    for mint in [Pubkey::new_unique(), Pubkey::new_unique()] {
+        TokenMint {
+            address: mint,
+            decimals: 6,
+        }
+        .serialize(&mut data)
+        .unwrap();
+    }
+    data.extend(1u16.to_le_bytes()); // nonce
+    state.serialize(&mut data).unwrap();
+    data.push(num_options);
+    for i in 0..16 {
+        let mint = if i % 8 < num_options as usize {
+            Pubkey::new_unique()
+        } else {
+            Pubkey::default()
+        };
+        data.extend(mint.to_bytes());
+    }
+    data
+}
+
+/// A vault at the current version, as upgraded from v1
+pub fn vault(num_options: u8, state: VaultState) -> VaultAccount {
+    let data = v1_vault_data(Pubkey::new_unique(), num_options, state);
+    let mut vault = VaultAccount::upgrade(1, &data[8..]).unwrap();
+    vault.conditional_token_program = anchor_spl::token_2022::ID;
+    vault
+}
+
+pub fn vault_data(vault: &VaultAccount) -> Vec<u8> {
+    let mut data = Vec::new();
+    vault.try_serialize(&mut data).unwrap();
+    data
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Property tests bounding the rounding loss of partial redemptions.
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use vault::{VaultAccount, VaultState, PAYOUT_WEIGHT_TOTAL};
+
+/// Redeems `balances` with `amount` per call, as redeem does: up to `amount` of each paying
+/// option and every losing token. Returns the total paid and the number of calls
+fn redeem_in_parts(vault: &VaultAccount, mut balances: Vec<u64>, amount: u64) -> (u64, u64) {
+    let (mut paid, mut calls) = (0u64, 0u64);
+    while balances.iter().any(|&b| b > 0) {
+        let burned: Vec<u64> = balances
+            .iter()
+            .enumerate()
+            .map(|(i, &b)| if vault.pays_out(i) { b.min(amount) } else { b })
+            .collect();
+        paid += vault.payout(&burned).unwrap();
+        calls += 1;
+        for (b, burned) in balances.iter_mut().zip(burned) {
+            *b -= burned;
+        }
+    }
+    (paid, calls)
+}
+
+proptest! {
+    #[test]
+    fn voided_partial_redeems_lose_less_than_one_unit_per_call(
+        balances in prop::collection::vec(0..100_000u64, 2..=8),
+        amount in 1..1_000u64,
+    ) {
+        let vault = vault(balances.len() as u8, VaultState::Voided);
+        let full = vault.payout(&balances).unwrap();
+
+        let (paid, calls) = redeem_in_parts(&vault, balances, amount);
+        prop_assert!(paid <= full);
+        prop_assert!(full - paid < calls.max(1));
+    }
+
+    #[test]
+    fn weighted_partial_redeems_lose_less_than_one_unit_per_call(
+        balances in prop::collection::vec(0..100_000u64, 2),
+        weight in 0..=PAYOUT_WEIGHT_TOTAL,
+        amount in 1..1_000u64,
+    ) {
+        let mut vault = vault(2, VaultState::FinalizedWeighted);
+        vault.payout_weights = vec![weight, PAYOUT_WEIGHT_TOTAL - weight];
+        let full = vault.payout(&balances).unwrap();
+
+        let (paid, calls) = redeem_in_parts(&vault, balances, amount);
+        prop_assert!(paid <= full);
+        prop_assert!(full - paid < calls.max(1));
+    }
+
+    #[test]
+    fn full_redeem_loses_nothing(
+        balances in prop::collection::vec(0..1_000_000_000u64, 2..=8),
+    ) {
+        let vault = vault(balances.len() as u8, VaultState::Voided);
+        let full = vault.payout(&balances).unwrap();
+
+        let (paid, _) = redeem_in_parts(&vault, balances, u64::MAX);
+        prop_assert_eq!(paid, full);
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2025 Spice Finance Inc.
+ *
+ * This file is part of Z Combinator.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Tests for the vault account's version guard and v1 upgrade.
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use vault::errors::VaultError;
+use vault::{VaultAccount, VaultState, VAULT_VERSION};
+
+#[test]
+fn current_vault_round_trips() {
+    let vault = vault(3, VaultState::Active);
+    let decoded = VaultAccount::try_deserialize(&mut &vault_data(&vault)[..]).unwrap();
+
+    assert_eq!(decoded.version, VAULT_VERSION);
+    assert_eq!(decoded.owner, vault.owner);
+    assert_eq!(decoded.cond_quote_mints, vault.cond_quote_mints);
+}
+
+#[test]
+fn unmigrated_vault_is_rejected_with_invalid_version() {
+    let data = v1_vault_data(Pubkey::new_unique(), 2, VaultState::Active);
+
+    let err = VaultAccount::try_deserialize(&mut &data[..]).err().unwrap();
+    assert_eq!(err, VaultError::InvalidVersion.into());
+}
+
+#[test]
+fn unknown_version_is_rejected_with_invalid_version() {
+    let mut data = vault_data(&vault(2, VaultState::Active));
+    data[8] = VAULT_VERSION + 1;
+
+    let err = VaultAccount::try_deserialize(&mut &data[..]).err().unwrap();
+    assert_eq!(err, VaultError::InvalidVersion.into());
+}
+
+#[test]
+fn v1_vault_upgrades_to_current_layout() {
+    let owner = Pubkey::new_unique();
+    let data = v1_vault_data(owner, 2, VaultState::Finalized(1));
+
+    let upgraded = VaultAccount::upgrade(data[8], &data[8..]).unwrap();
+    assert_eq!(upgraded.version, VAULT_VERSION);
+    assert_eq!(upgraded.owner, owner);
+    assert!(upgraded.state == VaultState::Finalized(1));
+    assert_eq!(upgraded.cond_base_mints.len(), 2);
+    assert_eq!(upgraded.conditional_token_program, anchor_spl::token::ID);
+    assert_eq!(upgraded.extra_mints, 0);
+
+    // Written back, it fits the migrated size and decodes under the current layout
+    let migrated = vault_data(&upgraded);
+    assert!(migrated.len() <= VaultAccount::space(2));
+    VaultAccount::try_deserialize(&mut &migrated[..]).unwrap();
+}
+
+#[test]
+fn current_version_has_nothing_to_upgrade() {
+    let data = vault_data(&vault(2, VaultState::Active));
+
+    let err = VaultAccount::upgrade(data[8], &data[8..]).err().unwrap();
+    assert_eq!(err, VaultError::InvalidVersion.into());
+}
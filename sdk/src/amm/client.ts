@@ -19,9 +19,10 @@ import {
   createSyncNativeInstruction,
   createCloseAccountInstruction,
   createAssociatedTokenAccountIdempotentInstruction,
+  TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { PROGRAM_ID } from "./constants";
-import { Amm, PoolAccount, SwapQuote, AmmActionOptions } from "./types";
+import { Amm, PoolAccount, FinalSnapshot, SwapQuote, AmmActionOptions } from "./types";
 import {
   derivePoolPDA,
   deriveReservePDA,
@@ -38,9 +39,16 @@ import {
   swap as swapIx,
   crankTwap as crankTwapIx,
   ceaseTrading as ceaseTradingIx,
+  migratePool as migratePoolIx,
+  getFinalSnapshot as getFinalSnapshotIx,
+  proposeAdmin as proposeAdminIx,
+  acceptAdmin as acceptAdminIx,
+  proposeLiquidityProvider as proposeLiquidityProviderIx,
+  acceptLiquidityProvider as acceptLiquidityProviderIx,
 } from "./instructions";
 
 import { AmmIDL } from "../generated/idls";
+import { fetchTokenProgram } from "../utils";
 
 const DEFAULT_COMPUTE_UNITS = 300_000;
 
@@ -83,16 +91,33 @@ export class AMMClient {
     return fetchPoolAccount(this.program, poolPda);
   }
 
+  // Layout version the pool is stored under; fetchPool only decodes AMM_VERSION
+  async fetchPoolVersion(poolPda: PublicKey): Promise<number> {
+    const info = await this.program.provider.connection.getAccountInfo(poolPda);
+    if (!info) {
+      throw new Error("Pool not found");
+    }
+    return info.data[8];
+  }
+
+  // Snapshot recorded by cease_trading, read through get_final_snapshot's return data.
+  // Fails with InvalidState while the pool is still trading
+  async fetchFinalSnapshot(poolPda: PublicKey): Promise<FinalSnapshot> {
+    return getFinalSnapshotIx(this.program, poolPda).view();
+  }
+
   async fetchReserves(poolPda: PublicKey): Promise<{ reserveA: BN; reserveB: BN }> {
     const pool = await this.fetchPool(poolPda);
     const [reserveAPda] = this.deriveReservePDA(poolPda, pool.mintA);
     const [reserveBPda] = this.deriveReservePDA(poolPda, pool.mintB);
 
+    // Both pool mints share one token program (SPL Token, or Token-2022 for conditional mints)
     const connection = this.program.provider.connection;
+    const tokenProgram = await fetchTokenProgram(connection, pool.mintA);
 
     const [reserveAAccount, reserveBAccount] = await Promise.all([
-      getAccount(connection, reserveAPda),
-      getAccount(connection, reserveBPda),
+      getAccount(connection, reserveAPda, undefined, tokenProgram),
+      getAccount(connection, reserveBPda, undefined, tokenProgram),
     ]);
 
     return {
@@ -104,9 +129,10 @@ export class AMMClient {
   async fetchMintDecimals(poolPda: PublicKey): Promise<{ decimalsA: number; decimalsB: number }> {
     const pool = await this.fetchPool(poolPda);
     const connection = this.program.provider.connection;
+    const tokenProgram = await fetchTokenProgram(connection, pool.mintA);
     const [mintA, mintB] = await Promise.all([
-      getMint(connection, pool.mintA),
-      getMint(connection, pool.mintB),
+      getMint(connection, pool.mintA, undefined, tokenProgram),
+      getMint(connection, pool.mintB, undefined, tokenProgram),
     ]);
     return { decimalsA: mintA.decimals, decimalsB: mintB.decimals };
   }
@@ -165,7 +191,8 @@ export class AMMClient {
     startingObservation: BN,
     maxObservationDelta: BN,
     warmupDuration: number,
-    liquidityProvider: PublicKey | null = null
+    liquidityProvider: PublicKey | null = null,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
   ) {
     const [poolPda] = this.derivePoolPDA(admin, mintA, mintB);
     const [reserveA] = this.deriveReservePDA(poolPda, mintA);
@@ -186,7 +213,8 @@ export class AMMClient {
       startingObservation,
       maxObservationDelta,
       warmupDuration,
-      liquidityProvider
+      liquidityProvider,
+      tokenProgram
     );
 
     return {
@@ -208,10 +236,11 @@ export class AMMClient {
     const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits } = options ?? {};
 
     const pool = await this.fetchPool(poolPda);
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, pool.mintA);
     const [reserveA] = this.deriveReservePDA(poolPda, pool.mintA);
     const [reserveB] = this.deriveReservePDA(poolPda, pool.mintB);
-    const depositorTokenAccA = getAssociatedTokenAddressSync(pool.mintA, depositor);
-    const depositorTokenAccB = getAssociatedTokenAddressSync(pool.mintB, depositor);
+    const depositorTokenAccA = getAssociatedTokenAddressSync(pool.mintA, depositor, false, tokenProgram);
+    const depositorTokenAccB = getAssociatedTokenAddressSync(pool.mintB, depositor, false, tokenProgram);
 
     const preIxs: TransactionInstruction[] = [];
 
@@ -230,7 +259,8 @@ export class AMMClient {
           depositor,
           depositorTokenAccA,
           depositor,
-          pool.mintA
+          pool.mintA,
+          tokenProgram
         ),
         SystemProgram.transfer({
           fromPubkey: depositor,
@@ -248,7 +278,8 @@ export class AMMClient {
           depositor,
           depositorTokenAccB,
           depositor,
-          pool.mintB
+          pool.mintB,
+          tokenProgram
         ),
         SystemProgram.transfer({
           fromPubkey: depositor,
@@ -263,6 +294,9 @@ export class AMMClient {
       this.program,
       depositor,
       poolPda,
+      pool.mintA,
+      pool.mintB,
+      tokenProgram,
       reserveA,
       reserveB,
       depositorTokenAccA,
@@ -284,15 +318,19 @@ export class AMMClient {
     const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits } = options ?? {};
 
     const pool = await this.fetchPool(poolPda);
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, pool.mintA);
     const [reserveA] = this.deriveReservePDA(poolPda, pool.mintA);
     const [reserveB] = this.deriveReservePDA(poolPda, pool.mintB);
-    const depositorTokenAccA = getAssociatedTokenAddressSync(pool.mintA, depositor);
-    const depositorTokenAccB = getAssociatedTokenAddressSync(pool.mintB, depositor);
+    const depositorTokenAccA = getAssociatedTokenAddressSync(pool.mintA, depositor, false, tokenProgram);
+    const depositorTokenAccB = getAssociatedTokenAddressSync(pool.mintB, depositor, false, tokenProgram);
 
     let builder = removeLiquidityIx(
       this.program,
       depositor,
       poolPda,
+      pool.mintA,
+      pool.mintB,
+      tokenProgram,
       reserveA,
       reserveB,
       depositorTokenAccA,
@@ -336,8 +374,9 @@ export class AMMClient {
     const [reserveA] = this.deriveReservePDA(poolPda, pool.mintA);
     const [reserveB] = this.deriveReservePDA(poolPda, pool.mintB);
     const [feeVault] = this.deriveFeeVaultPDA(poolPda);
-    const traderAccountA = getAssociatedTokenAddressSync(pool.mintA, trader);
-    const traderAccountB = getAssociatedTokenAddressSync(pool.mintB, trader);
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, pool.mintA);
+    const traderAccountA = getAssociatedTokenAddressSync(pool.mintA, trader, false, tokenProgram);
+    const traderAccountB = getAssociatedTokenAddressSync(pool.mintB, trader, false, tokenProgram);
 
     const preIxs: TransactionInstruction[] = [];
 
@@ -355,13 +394,15 @@ export class AMMClient {
           trader,
           traderAccountA,
           trader,
-          pool.mintA
+          pool.mintA,
+          tokenProgram
         ),
         createAssociatedTokenAccountIdempotentInstruction(
           trader,
           traderAccountB,
           trader,
-          pool.mintB
+          pool.mintB,
+          tokenProgram
         )
       );
     }
@@ -370,6 +411,9 @@ export class AMMClient {
       this.program,
       trader,
       poolPda,
+      pool.mintA,
+      pool.mintB,
+      tokenProgram,
       reserveA,
       reserveB,
       feeVault,
@@ -399,6 +443,35 @@ export class AMMClient {
     return ceaseTradingIx(this.program, admin, poolPda);
   }
 
+  // Permissionless: rewrite a pool stored under an older layout as the current AMM_VERSION
+  migratePool(payer: PublicKey, poolPda: PublicKey) {
+    return migratePoolIx(this.program, payer, poolPda);
+  }
+
+  /* Handovers */
+
+  // Nominates a new admin; null cancels a pending handover. The nominee completes it with acceptAdmin
+  proposeAdmin(admin: PublicKey, poolPda: PublicKey, newAdmin: PublicKey | null) {
+    return proposeAdminIx(this.program, admin, poolPda, newAdmin);
+  }
+
+  acceptAdmin(newAdmin: PublicKey, poolPda: PublicKey) {
+    return acceptAdminIx(this.program, newAdmin, poolPda);
+  }
+
+  // Nominates a new liquidity provider; null cancels a pending handover
+  proposeLiquidityProvider(
+    liquidityProvider: PublicKey,
+    poolPda: PublicKey,
+    newLiquidityProvider: PublicKey | null
+  ) {
+    return proposeLiquidityProviderIx(this.program, liquidityProvider, poolPda, newLiquidityProvider);
+  }
+
+  acceptLiquidityProvider(newLiquidityProvider: PublicKey, poolPda: PublicKey) {
+    return acceptLiquidityProviderIx(this.program, newLiquidityProvider, poolPda);
+  }
+
   /* High-Level Swap with Slippage */
 
   /**
@@ -428,14 +501,18 @@ export class AMMClient {
     const [reserveA] = this.deriveReservePDA(poolPda, pool.mintA);
     const [reserveB] = this.deriveReservePDA(poolPda, pool.mintB);
     const [feeVault] = this.deriveFeeVaultPDA(poolPda);
-    const traderAccountA = getAssociatedTokenAddressSync(pool.mintA, trader);
-    const traderAccountB = getAssociatedTokenAddressSync(pool.mintB, trader);
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, pool.mintA);
+    const traderAccountA = getAssociatedTokenAddressSync(pool.mintA, trader, false, tokenProgram);
+    const traderAccountB = getAssociatedTokenAddressSync(pool.mintB, trader, false, tokenProgram);
 
     // Build base swap instruction
     let builder = swapIx(
       this.program,
       trader,
       poolPda,
+      pool.mintA,
+      pool.mintB,
+      tokenProgram,
       reserveA,
       reserveB,
       feeVault,
@@ -462,13 +539,15 @@ export class AMMClient {
           trader,
           traderAccountA,
           trader,
-          pool.mintA
+          pool.mintA,
+          tokenProgram
         ),
         createAssociatedTokenAccountIdempotentInstruction(
           trader,
           traderAccountB,
           trader,
-          pool.mintB
+          pool.mintB,
+          tokenProgram
         )
       );
     }
@@ -5,6 +5,7 @@
 
 import { Program, BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Amm } from "./types";
 
 /* Instruction Builders */
@@ -23,7 +24,8 @@ export function createPool(
   startingObservation: BN,
   maxObservationDelta: BN,
   warmupDuration: number,
-  liquidityProvider: PublicKey | null = null
+  liquidityProvider: PublicKey | null = null,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ) {
   return program.methods
     .createPool(fee, startingObservation, maxObservationDelta, warmupDuration, liquidityProvider)
@@ -36,6 +38,7 @@ export function createPool(
       reserveA,
       reserveB,
       feeVault,
+      tokenProgram,
     });
 }
 
@@ -43,6 +46,9 @@ export function addLiquidity(
   program: Program<Amm>,
   depositor: PublicKey,
   pool: PublicKey,
+  mintA: PublicKey,
+  mintB: PublicKey,
+  tokenProgram: PublicKey,
   reserveA: PublicKey,
   reserveB: PublicKey,
   depositorTokenAccA: PublicKey,
@@ -56,10 +62,13 @@ export function addLiquidity(
   return program.methods.addLiquidity(amountABN, amountBBN).accountsPartial({
     depositor,
     pool,
+    mintA,
+    mintB,
     reserveA,
     reserveB,
     depositorTokenAccA,
     depositorTokenAccB,
+    tokenProgram,
   });
 }
 
@@ -67,6 +76,9 @@ export function removeLiquidity(
   program: Program<Amm>,
   depositor: PublicKey,
   pool: PublicKey,
+  mintA: PublicKey,
+  mintB: PublicKey,
+  tokenProgram: PublicKey,
   reserveA: PublicKey,
   reserveB: PublicKey,
   depositorTokenAccA: PublicKey,
@@ -80,10 +92,13 @@ export function removeLiquidity(
   return program.methods.removeLiquidity(amountABN, amountBBN).accountsPartial({
     depositor,
     pool,
+    mintA,
+    mintB,
     reserveA,
     reserveB,
     depositorTokenAccA,
     depositorTokenAccB,
+    tokenProgram,
   });
 }
 
@@ -91,6 +106,9 @@ export function swap(
   program: Program<Amm>,
   trader: PublicKey,
   pool: PublicKey,
+  mintA: PublicKey,
+  mintB: PublicKey,
+  tokenProgram: PublicKey,
   reserveA: PublicKey,
   reserveB: PublicKey,
   feeVault: PublicKey,
@@ -106,11 +124,14 @@ export function swap(
   return program.methods.swap(swapAToB, inputAmountBN, minOutputAmountBN).accountsPartial({
     trader,
     pool,
+    mintA,
+    mintB,
     reserveA,
     reserveB,
     feeVault,
     traderAccountA,
     traderAccountB,
+    tokenProgram,
   });
 }
 
@@ -137,3 +158,66 @@ export function ceaseTrading(
     pool,
   });
 }
+
+export function migratePool(
+  program: Program<Amm>,
+  payer: PublicKey,
+  pool: PublicKey
+) {
+  return program.methods.migratePool().accountsPartial({
+    payer,
+    pool,
+  });
+}
+
+export function proposeAdmin(
+  program: Program<Amm>,
+  admin: PublicKey,
+  pool: PublicKey,
+  newAdmin: PublicKey | null
+) {
+  return program.methods.proposeAdmin(newAdmin).accountsPartial({
+    admin,
+    pool,
+  });
+}
+
+export function acceptAdmin(
+  program: Program<Amm>,
+  newAdmin: PublicKey,
+  pool: PublicKey
+) {
+  return program.methods.acceptAdmin().accountsPartial({
+    newAdmin,
+    pool,
+  });
+}
+
+export function proposeLiquidityProvider(
+  program: Program<Amm>,
+  liquidityProvider: PublicKey,
+  pool: PublicKey,
+  newLiquidityProvider: PublicKey | null
+) {
+  return program.methods.proposeLiquidityProvider(newLiquidityProvider).accountsPartial({
+    liquidityProvider,
+    pool,
+  });
+}
+
+export function acceptLiquidityProvider(
+  program: Program<Amm>,
+  newLiquidityProvider: PublicKey,
+  pool: PublicKey
+) {
+  return program.methods.acceptLiquidityProvider().accountsPartial({
+    newLiquidityProvider,
+    pool,
+  });
+}
+
+export function getFinalSnapshot(program: Program<Amm>, pool: PublicKey) {
+  return program.methods.getFinalSnapshot().accountsPartial({
+    pool,
+  });
+}
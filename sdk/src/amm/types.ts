@@ -17,6 +17,7 @@ export type PoolAccount = IdlAccounts<Amm>["poolAccount"];
 export type PoolStateRaw = IdlTypes<Amm>["poolState"];
 export type TwapOracle = IdlTypes<Amm>["twapOracle"];
 export type PoolBumps = IdlTypes<Amm>["poolBumps"];
+export type FinalSnapshot = IdlTypes<Amm>["finalSnapshot"];
 
 /* Event Types */
 
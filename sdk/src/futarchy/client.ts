@@ -18,6 +18,7 @@ import {
 import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   createAssociatedTokenAccountIdempotentInstruction,
 } from "@solana/spl-token";
@@ -28,7 +29,10 @@ import {
   ModeratorAccount,
   ProposalAccount,
   ProposalParams,
+  ProposalState,
   PoolType,
+  InitializeProposalOptions,
+  AddOptionOptions,
 } from "./types";
 import {
   deriveDAOPDA,
@@ -59,6 +63,11 @@ import { TxOptions } from "../utils";
 import { VaultClient, deriveVaultPDA, deriveConditionalMint, VaultType } from "../vault";
 import { AMMClient, derivePoolPDA, deriveReservePDA, deriveFeeVaultPDA, FEE_AUTHORITY } from "../amm";
 
+// Proposal vaults create their conditional mints under Token-2022
+function condAta(condMint: PublicKey, owner: PublicKey): PublicKey {
+  return getAssociatedTokenAddressSync(condMint, owner, false, TOKEN_2022_PROGRAM_ID);
+}
+
 import { FutarchyIDL } from "../generated/idls";
 import * as multisig from "@sqds/multisig";
 
@@ -183,7 +192,7 @@ export class FutarchyClient {
     moderatorPda: PublicKey,
     proposalParams: ProposalParams,
     metadata?: string,
-    options?: TxOptions
+    options?: InitializeProposalOptions
   ) {
     const moderator = await this.fetchModerator(moderatorPda);
     const proposalId = moderator.proposalIdCounter;
@@ -243,6 +252,7 @@ export class FutarchyClient {
       proposalPda,
       proposalParams,
       metadata ?? null,
+      options?.permanentDelegate ?? false,
       remainingAccounts
     ).preInstructions(this.maybeAddComputeBudget(options));
 
@@ -257,8 +267,9 @@ export class FutarchyClient {
     };
   }
 
-  async addOption(creator: PublicKey, proposalPda: PublicKey, options?: TxOptions) {
+  async addOption(creator: PublicKey, proposalPda: PublicKey, options?: AddOptionOptions) {
     const proposal = await this.fetchProposal(proposalPda);
+    const vault = await this.vault.fetchVault(proposal.vault);
     const optionIndex = proposal.numOptions;
 
     // Derive new conditional mints
@@ -273,17 +284,24 @@ export class FutarchyClient {
 
     // Build remaining accounts (see add_option.rs)
     const remainingAccounts = [
-      { pubkey: proposal.vault, isSigner: false, isWritable: true },           // 0: vault
-      { pubkey: condBaseMint, isSigner: false, isWritable: true },             // 1: cond_base_mint
-      { pubkey: condQuoteMint, isSigner: false, isWritable: true },            // 2: cond_quote_mint
-      { pubkey: pool, isSigner: false, isWritable: true },                     // 3: pool
-      { pubkey: reserveA, isSigner: false, isWritable: true },                 // 4: reserve_a
-      { pubkey: reserveB, isSigner: false, isWritable: true },                 // 5: reserve_b
-      { pubkey: FEE_AUTHORITY, isSigner: false, isWritable: false },           // 6: fee_authority
-      { pubkey: feeVault, isSigner: false, isWritable: true },                 // 7: fee_vault
+      { pubkey: condBaseMint, isSigner: false, isWritable: true },             // 0: cond_base_mint
+      { pubkey: condQuoteMint, isSigner: false, isWritable: true },            // 1: cond_quote_mint
+      { pubkey: pool, isSigner: false, isWritable: true },                     // 2: pool
+      { pubkey: reserveA, isSigner: false, isWritable: true },                 // 3: reserve_a
+      { pubkey: reserveB, isSigner: false, isWritable: true },                 // 4: reserve_b
+      { pubkey: FEE_AUTHORITY, isSigner: false, isWritable: false },           // 5: fee_authority
+      { pubkey: feeVault, isSigner: false, isWritable: true },                 // 6: fee_vault
     ];
 
-    const builder = addOption(this.program, creator, proposalPda, remainingAccounts)
+    const builder = addOption(
+      this.program,
+      creator,
+      proposalPda,
+      proposal.vault,
+      vault.conditionalTokenProgram,
+      options?.uri ?? "",
+      remainingAccounts
+    )
       .preInstructions(this.maybeAddComputeBudget(options));
 
     return { builder, optionIndex, pool, condBaseMint, condQuoteMint };
@@ -314,8 +332,8 @@ export class FutarchyClient {
     const pools = proposal.pools.slice(0, numOptions);
 
     // Derive all user conditional token ATAs
-    const userCondBaseATAs = condBaseMints.map((m) => getAssociatedTokenAddressSync(m, creator));
-    const userCondQuoteATAs = condQuoteMints.map((m) => getAssociatedTokenAddressSync(m, creator));
+    const userCondBaseATAs = condBaseMints.map((m) => condAta(m, creator));
+    const userCondQuoteATAs = condQuoteMints.map((m) => condAta(m, creator));
 
     // Derive reserve accounts for each pool
     const reservesA: PublicKey[] = [];
@@ -372,6 +390,7 @@ export class FutarchyClient {
       creator,
       proposalPda,
       proposal.vault,
+      vault.conditionalTokenProgram,
       baseAmount,
       quoteAmount,
       remainingAccounts
@@ -419,21 +438,23 @@ export class FutarchyClient {
     // Build ATA creation instructions (idempotent - won't fail if exists)
     const instructions: TransactionInstruction[] = [];
     for (let i = 0; i < condBaseMints.length; i++) {
-      const userCondBaseAta = getAssociatedTokenAddressSync(condBaseMints[i], creator);
-      const userCondQuoteAta = getAssociatedTokenAddressSync(condQuoteMints[i], creator);
+      const userCondBaseAta = condAta(condBaseMints[i], creator);
+      const userCondQuoteAta = condAta(condQuoteMints[i], creator);
 
       instructions.push(
         createAssociatedTokenAccountIdempotentInstruction(
           creator,
           userCondBaseAta,
           creator,
-          condBaseMints[i]
+          condBaseMints[i],
+          TOKEN_2022_PROGRAM_ID
         ),
         createAssociatedTokenAccountIdempotentInstruction(
           creator,
           userCondQuoteAta,
           creator,
-          condQuoteMints[i]
+          condQuoteMints[i],
+          TOKEN_2022_PROGRAM_ID
         )
       );
     }
@@ -477,10 +498,13 @@ export class FutarchyClient {
     const vault = await this.vault.fetchVault(proposal.vault);
     const numOptions = proposal.numOptions;
 
-    const { winningIdx } = parseProposalState(proposal.state);
-    if (winningIdx === null) {
+    // A voided proposal redeems every option; pool 0 takes the winning pool's place
+    const { state, winningIdx: resolvedIdx } = parseProposalState(proposal.state);
+    const voided = state === ProposalState.Voided;
+    if (resolvedIdx === null && !voided) {
       throw new Error("Proposal not finalized");
     }
+    const winningIdx = resolvedIdx ?? 0;
     const winningPool = proposal.pools[winningIdx];
 
     // Derive winning pool reserves
@@ -488,8 +512,8 @@ export class FutarchyClient {
     const [reserveB] = deriveReservePDA(winningPool, vault.condBaseMints[winningIdx], this.amm.programId);
 
     // User's winning conditional token ATAs
-    const creatorCondQuoteAta = getAssociatedTokenAddressSync(vault.condQuoteMints[winningIdx], creator);
-    const creatorCondBaseAta = getAssociatedTokenAddressSync(vault.condBaseMints[winningIdx], creator);
+    const creatorCondQuoteAta = condAta(vault.condQuoteMints[winningIdx], creator);
+    const creatorCondBaseAta = condAta(vault.condBaseMints[winningIdx], creator);
 
     // Build remaining accounts (see redeem_liquidity.rs)
     const remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] = [
@@ -508,7 +532,7 @@ export class FutarchyClient {
     // redeem_winnings base remaining (7..7+2N): [cond_base_mint_i, user_cond_base_ata_i]
     for (let i = 0; i < numOptions; i++) {
       remainingAccounts.push({ pubkey: vault.condBaseMints[i], isSigner: false, isWritable: true });
-      remainingAccounts.push({ pubkey: getAssociatedTokenAddressSync(vault.condBaseMints[i], creator), isSigner: false, isWritable: true });
+      remainingAccounts.push({ pubkey: condAta(vault.condBaseMints[i], creator), isSigner: false, isWritable: true });
     }
 
     // redeem_winnings quote fixed accounts
@@ -519,7 +543,19 @@ export class FutarchyClient {
     // redeem_winnings quote remaining: [cond_quote_mint_i, user_cond_quote_ata_i]
     for (let i = 0; i < numOptions; i++) {
       remainingAccounts.push({ pubkey: vault.condQuoteMints[i], isSigner: false, isWritable: true });
-      remainingAccounts.push({ pubkey: getAssociatedTokenAddressSync(vault.condQuoteMints[i], creator), isSigner: false, isWritable: true });
+      remainingAccounts.push({ pubkey: condAta(vault.condQuoteMints[i], creator), isSigner: false, isWritable: true });
+    }
+
+    // Voided only: [pool_i, reserve_a_i, reserve_b_i] for every other pool
+    if (voided) {
+      for (let i = 1; i < numOptions; i++) {
+        const pool = proposal.pools[i];
+        const [poolReserveA] = deriveReservePDA(pool, vault.condQuoteMints[i], this.amm.programId);
+        const [poolReserveB] = deriveReservePDA(pool, vault.condBaseMints[i], this.amm.programId);
+        remainingAccounts.push({ pubkey: pool, isSigner: false, isWritable: true });
+        remainingAccounts.push({ pubkey: poolReserveA, isSigner: false, isWritable: true });
+        remainingAccounts.push({ pubkey: poolReserveB, isSigner: false, isWritable: true });
+      }
     }
 
     const builder = redeemLiquidity(
@@ -527,6 +563,7 @@ export class FutarchyClient {
       creator,
       proposalPda,
       proposal.vault,
+      vault.conditionalTokenProgram,
       winningPool,
       remainingAccounts
     ).preInstructions(this.maybeAddComputeBudget(options));
@@ -545,16 +582,18 @@ export class FutarchyClient {
   async createRedemptionALT(
     creator: PublicKey,
     proposalPda: PublicKey,
-  ): Promise<{ altAddress: PublicKey }> {
+  ): Promise<{ altAddress: PublicKey; numAddresses: number }> {
     const provider = this.program.provider as AnchorProvider;
     const proposal = await this.fetchProposal(proposalPda);
     const vault = await this.vault.fetchVault(proposal.vault);
     const numOptions = proposal.numOptions;
 
-    const { winningIdx } = parseProposalState(proposal.state);
-    if (winningIdx === null) {
+    const { state, winningIdx: resolvedIdx } = parseProposalState(proposal.state);
+    const voided = state === ProposalState.Voided;
+    if (resolvedIdx === null && !voided) {
       throw new Error("Proposal not finalized");
     }
+    const winningIdx = resolvedIdx ?? 0;
 
     const addresses: PublicKey[] = [
       // Programs
@@ -563,6 +602,7 @@ export class FutarchyClient {
       this.amm.programId,
       SystemProgram.programId,
       TOKEN_PROGRAM_ID,
+      TOKEN_2022_PROGRAM_ID,
       ASSOCIATED_TOKEN_PROGRAM_ID,
       // Core accounts
       proposalPda,
@@ -585,13 +625,22 @@ export class FutarchyClient {
     const [reserveB] = deriveReservePDA(proposal.pools[winningIdx], vault.condBaseMints[winningIdx], this.amm.programId);
     addresses.push(reserveA, reserveB);
 
+    // Voided: every other pool is unwound too
+    if (voided) {
+      for (let i = 1; i < numOptions; i++) {
+        const [poolReserveA] = deriveReservePDA(proposal.pools[i], vault.condQuoteMints[i], this.amm.programId);
+        const [poolReserveB] = deriveReservePDA(proposal.pools[i], vault.condBaseMints[i], this.amm.programId);
+        addresses.push(proposal.pools[i], poolReserveA, poolReserveB);
+      }
+    }
+
     // Per-option accounts (conditional mints and user ATAs)
     for (let i = 0; i < numOptions; i++) {
       addresses.push(
         vault.condBaseMints[i],
         vault.condQuoteMints[i],
-        getAssociatedTokenAddressSync(vault.condBaseMints[i], creator),
-        getAssociatedTokenAddressSync(vault.condQuoteMints[i], creator),
+        condAta(vault.condBaseMints[i], creator),
+        condAta(vault.condQuoteMints[i], creator),
       );
     }
 
@@ -636,7 +685,7 @@ export class FutarchyClient {
       await provider.connection.confirmTransaction(extendSig, "confirmed");
     }
 
-    return { altAddress };
+    return { altAddress, numAddresses: addresses.length };
   }
 
   /**
@@ -669,7 +718,7 @@ export class FutarchyClient {
       // Wait for ALT to be fully available with all addresses
       // Use longer delays after extending to ensure propagation
       console.log(`  Waiting for ALT propagation...`);
-      const expectedAddresses = 18 + (numOptions * 4); // Base accounts + per-option accounts
+      const expectedAddresses = result.numAddresses;
       let attempts = 0;
 
       // Initial delay after extension
@@ -761,6 +810,7 @@ export class FutarchyClient {
       this.amm.programId,
       SystemProgram.programId,
       TOKEN_PROGRAM_ID,
+      TOKEN_2022_PROGRAM_ID,
       ASSOCIATED_TOKEN_PROGRAM_ID,
       // Core accounts
       moderatorPda,
@@ -794,8 +844,8 @@ export class FutarchyClient {
         reserveB,
         feeVault,
         // Creator's conditional token ATAs
-        getAssociatedTokenAddressSync(condBaseMint, creator),
-        getAssociatedTokenAddressSync(condQuoteMint, creator),
+        condAta(condBaseMint, creator),
+        condAta(condQuoteMint, creator),
       );
     }
 
@@ -21,6 +21,9 @@ export const PROPOSAL_SEED = parseIdlBytes(getIdlConstant(FutarchyIDL, "PROPOSAL
 
 export const MAX_OPTIONS = Number(getIdlConstant(FutarchyIDL, "MAX_OPTIONS"));
 export const MIN_OPTIONS = Number(getIdlConstant(FutarchyIDL, "MIN_OPTIONS"));
+export const VAULT_RESOLUTION_GRACE_PERIOD = Number(
+  getIdlConstant(FutarchyIDL, "VAULT_RESOLUTION_GRACE_PERIOD")
+);
 
 /* Squads Integration */
 
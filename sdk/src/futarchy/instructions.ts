@@ -32,10 +32,11 @@ export function initializeProposal(
   proposal: PublicKey,
   proposalParams: ProposalParams,
   metadata: string | null,
+  permanentDelegate: boolean,
   remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]
 ) {
   return program.methods
-    .initializeProposal(proposalParams, metadata)
+    .initializeProposal(proposalParams, metadata, permanentDelegate)
     .accountsPartial({
       creator,
       moderator,
@@ -48,13 +49,18 @@ export function addOption(
   program: Program<Futarchy>,
   creator: PublicKey,
   proposal: PublicKey,
+  vault: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  uri: string,
   remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]
 ) {
   return program.methods
-    .addOption()
+    .addOption(uri)
     .accountsPartial({
       creator,
       proposal,
+      vault,
+      conditionalTokenProgram,
     })
     .remainingAccounts(remainingAccounts);
 }
@@ -64,6 +70,7 @@ export function launchProposal(
   creator: PublicKey,
   proposal: PublicKey,
   vault: PublicKey,
+  conditionalTokenProgram: PublicKey,
   baseAmount: BN | number,
   quoteAmount: BN | number,
   remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]
@@ -77,6 +84,7 @@ export function launchProposal(
       creator,
       proposal,
       vault,
+      conditionalTokenProgram,
     })
     .remainingAccounts(remainingAccounts);
 }
@@ -103,6 +111,7 @@ export function redeemLiquidity(
   creator: PublicKey,
   proposal: PublicKey,
   vault: PublicKey,
+  conditionalTokenProgram: PublicKey,
   pool: PublicKey,
   remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[]
 ) {
@@ -112,6 +121,7 @@ export function redeemLiquidity(
       creator,
       proposal,
       vault,
+      conditionalTokenProgram,
       pool,
     })
     .remainingAccounts(remainingAccounts);
@@ -4,6 +4,7 @@
  */
 
 import { IdlAccounts, IdlEvents, IdlTypes } from "@coral-xyz/anchor";
+import { TxOptions } from "../utils";
 
 // Re-export the generated IDL type
 export { Futarchy } from "../generated/types";
@@ -31,6 +32,7 @@ export type ProposalInitializedEvent = IdlEvents<Futarchy>["proposalInitialized"
 export type ProposalLaunchedEvent = IdlEvents<Futarchy>["proposalLaunched"];
 export type OptionAddedEvent = IdlEvents<Futarchy>["optionAdded"];
 export type ProposalFinalizedEvent = IdlEvents<Futarchy>["proposalFinalized"];
+export type ProposalVoidedEvent = IdlEvents<Futarchy>["proposalVoided"];
 export type LiquidityRedeemedEvent = IdlEvents<Futarchy>["liquidityRedeemed"];
 
 /* Enums */
@@ -39,6 +41,7 @@ export enum ProposalState {
   Setup = "setup",
   Pending = "pending",
   Resolved = "resolved",
+  Voided = "voided",
 }
 
 /* Event Union Type */
@@ -51,4 +54,15 @@ export type FutarchyEvent =
   | { name: "ProposalLaunched"; data: ProposalLaunchedEvent }
   | { name: "OptionAdded"; data: OptionAddedEvent }
   | { name: "ProposalFinalized"; data: ProposalFinalizedEvent }
+  | { name: "ProposalVoided"; data: ProposalVoidedEvent }
   | { name: "LiquidityRedeemed"; data: LiquidityRedeemedEvent };
+
+/* Client Options */
+
+export interface InitializeProposalOptions extends TxOptions {
+  permanentDelegate?: boolean;  // Proposal vault as permanent delegate, so redeemFor can sweep it (default: false)
+}
+
+export interface AddOptionOptions extends TxOptions {
+  uri?: string;  // Metadata URI of the option's conditional mints (default: none)
+}
@@ -67,6 +67,9 @@ export function parseProposalState(state: any): { state: ProposalState; winningI
     const winningIdx = state.resolved[0] ?? state.resolved;
     return { state: ProposalState.Resolved, winningIdx };
   }
+  if ("voided" in state) {
+    return { state: ProposalState.Voided, winningIdx: null };
+  }
   throw new Error("Unknown proposal state");
 }
 
@@ -13,6 +13,110 @@ export type Amm = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "signer": true
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptLiquidityProvider",
+      "discriminator": [
+        113,
+        125,
+        51,
+        135,
+        57,
+        214,
+        42,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "newLiquidityProvider",
+          "signer": true
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addLiquidity",
       "discriminator": [
@@ -45,7 +149,7 @@ export type Amm = {
               },
               {
                 "kind": "account",
-                "path": "pool.admin",
+                "path": "pool.seed_admin",
                 "account": "poolAccount"
               },
               {
@@ -61,6 +165,12 @@ export type Amm = {
             ]
           }
         },
+        {
+          "name": "mintA"
+        },
+        {
+          "name": "mintB"
+        },
         {
           "name": "reserveA",
           "writable": true,
@@ -128,8 +238,7 @@ export type Amm = {
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         }
       ],
       "args": [
@@ -176,13 +285,69 @@ export type Amm = {
               },
               {
                 "kind": "account",
-                "path": "pool.admin",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
                 "account": "poolAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserveA",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
               },
               {
                 "kind": "account",
                 "path": "pool.mint_a",
                 "account": "poolAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserveB",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool"
               },
               {
                 "kind": "account",
@@ -224,7 +389,7 @@ export type Amm = {
               },
               {
                 "kind": "account",
-                "path": "pool.admin",
+                "path": "pool.seed_admin",
                 "account": "poolAccount"
               },
               {
@@ -444,8 +609,7 @@ export type Amm = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         },
         {
           "name": "systemProgram",
@@ -470,7 +634,261 @@ export type Amm = {
           "type": "u32"
         },
         {
-          "name": "liquidityProvider",
+          "name": "liquidityProvider",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getFinalSnapshot",
+      "discriminator": [
+        116,
+        153,
+        249,
+        112,
+        70,
+        72,
+        113,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "finalSnapshot"
+        }
+      }
+    },
+    {
+      "name": "getOracle",
+      "discriminator": [
+        163,
+        19,
+        138,
+        28,
+        5,
+        7,
+        240,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "oracleState"
+        }
+      }
+    },
+    {
+      "name": "migratePool",
+      "discriminator": [
+        55,
+        170,
+        171,
+        123,
+        210,
+        69,
+        39,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pool",
+          "docs": [
+            "Owner checked here, discriminator and version checked in handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeLiquidityProvider",
+      "discriminator": [
+        116,
+        63,
+        66,
+        147,
+        43,
+        6,
+        209,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "liquidityProvider",
+          "signer": true
+        },
+        {
+          "name": "pool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pool.seed_admin",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_a",
+                "account": "poolAccount"
+              },
+              {
+                "kind": "account",
+                "path": "pool.mint_b",
+                "account": "poolAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newLiquidityProvider",
           "type": {
             "option": "pubkey"
           }
@@ -509,7 +927,7 @@ export type Amm = {
               },
               {
                 "kind": "account",
-                "path": "pool.admin",
+                "path": "pool.seed_admin",
                 "account": "poolAccount"
               },
               {
@@ -525,6 +943,12 @@ export type Amm = {
             ]
           }
         },
+        {
+          "name": "mintA"
+        },
+        {
+          "name": "mintB"
+        },
         {
           "name": "reserveA",
           "writable": true,
@@ -592,8 +1016,7 @@ export type Amm = {
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         }
       ],
       "args": [
@@ -640,7 +1063,7 @@ export type Amm = {
               },
               {
                 "kind": "account",
-                "path": "pool.admin",
+                "path": "pool.seed_admin",
                 "account": "poolAccount"
               },
               {
@@ -656,6 +1079,12 @@ export type Amm = {
             ]
           }
         },
+        {
+          "name": "mintA"
+        },
+        {
+          "name": "mintB"
+        },
         {
           "name": "reserveA",
           "writable": true,
@@ -752,8 +1181,7 @@ export type Amm = {
           "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         }
       ],
       "args": [
@@ -788,6 +1216,32 @@ export type Amm = {
     }
   ],
   "events": [
+    {
+      "name": "adminAccepted",
+      "discriminator": [
+        174,
+        12,
+        76,
+        139,
+        158,
+        99,
+        110,
+        254
+      ]
+    },
+    {
+      "name": "adminProposed",
+      "discriminator": [
+        129,
+        249,
+        226,
+        227,
+        199,
+        82,
+        110,
+        243
+      ]
+    },
     {
       "name": "condSwap",
       "discriminator": [
@@ -814,6 +1268,32 @@ export type Amm = {
         161
       ]
     },
+    {
+      "name": "liquidityProviderAccepted",
+      "discriminator": [
+        181,
+        16,
+        239,
+        39,
+        20,
+        195,
+        164,
+        37
+      ]
+    },
+    {
+      "name": "liquidityProviderProposed",
+      "discriminator": [
+        42,
+        159,
+        73,
+        137,
+        26,
+        129,
+        102,
+        153
+      ]
+    },
     {
       "name": "liquidityRemoved",
       "discriminator": [
@@ -840,6 +1320,19 @@ export type Amm = {
         82
       ]
     },
+    {
+      "name": "poolMigrated",
+      "discriminator": [
+        250,
+        204,
+        24,
+        195,
+        37,
+        253,
+        152,
+        6
+      ]
+    },
     {
       "name": "twapUpdate",
       "discriminator": [
@@ -852,6 +1345,19 @@ export type Amm = {
         144,
         38
       ]
+    },
+    {
+      "name": "tradingCeased",
+      "discriminator": [
+        205,
+        219,
+        66,
+        87,
+        39,
+        200,
+        46,
+        20
+      ]
     }
   ],
   "errors": [
@@ -914,9 +1420,56 @@ export type Amm = {
       "code": 6011,
       "name": "invalidFee",
       "msg": "Fee exceeds maximum"
+    },
+    {
+      "code": 6012,
+      "name": "invalidVersion",
+      "msg": "Pool account version mismatch, migrate_pool required"
     }
   ],
   "types": [
+    {
+      "name": "adminAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "condSwap",
       "type": {
@@ -949,6 +1502,46 @@ export type Amm = {
         ]
       }
     },
+    {
+      "name": "finalSnapshot",
+      "docs": [
+        "Oracle and reserve state captured when trading ceases"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "twap",
+            "docs": [
+              "TWAP at cessation, None if it never became available (e.g. still in warmup)"
+            ],
+            "type": {
+              "option": "u128"
+            }
+          },
+          {
+            "name": "lastPrice",
+            "type": "u128"
+          },
+          {
+            "name": "lastObservation",
+            "type": "u128"
+          },
+          {
+            "name": "reserveA",
+            "type": "u64"
+          },
+          {
+            "name": "reserveB",
+            "type": "u64"
+          },
+          {
+            "name": "unixTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "liquidityAdded",
       "type": {
@@ -969,6 +1562,48 @@ export type Amm = {
         ]
       }
     },
+    {
+      "name": "liquidityProviderAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousLiquidityProvider",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidityProvider",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityProviderProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidityProvider",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingLiquidityProvider",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "liquidityRemoved",
       "type": {
@@ -989,6 +1624,56 @@ export type Amm = {
         ]
       }
     },
+    {
+      "name": "oracleState",
+      "docs": [
+        "Read-only view of a TWAP oracle, returned by `get_oracle`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "twap",
+            "docs": [
+              "None until accumulation has started (during warmup or before the first recording after it)"
+            ],
+            "type": {
+              "option": "u128"
+            }
+          },
+          {
+            "name": "lastPrice",
+            "type": "u128"
+          },
+          {
+            "name": "lastObservation",
+            "type": "u128"
+          },
+          {
+            "name": "cumulativeObservations",
+            "type": "u128"
+          },
+          {
+            "name": "windowStart",
+            "docs": [
+              "TWAP window: from end of warmup to the last recording"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "windowEnd",
+            "type": "i64"
+          },
+          {
+            "name": "staleness",
+            "docs": [
+              "Seconds since the last recording"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "poolAccount",
       "type": {
@@ -1041,6 +1726,32 @@ export type Amm = {
                 "name": "twapOracle"
               }
             }
+          },
+          {
+            "name": "seedAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pendingLiquidityProvider",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "finalSnapshot",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "finalSnapshot"
+                }
+              }
+            }
           }
         ]
       }
@@ -1101,6 +1812,26 @@ export type Amm = {
         ]
       }
     },
+    {
+      "name": "poolMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "poolState",
       "type": {
@@ -1143,6 +1874,26 @@ export type Amm = {
         ]
       }
     },
+    {
+      "name": "tradingCeased",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "snapshot",
+            "type": {
+              "defined": {
+                "name": "finalSnapshot"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "twapOracle",
       "docs": [
@@ -1227,7 +1978,7 @@ export type Amm = {
     {
       "name": "ammVersion",
       "type": "u8",
-      "value": "1"
+      "value": "2"
     },
     {
       "name": "feeAuthority",
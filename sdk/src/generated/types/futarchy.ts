@@ -162,6 +162,13 @@ export type Futarchy = {
             ]
           }
         },
+        {
+          "name": "vault",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -175,15 +182,21 @@ export type Futarchy = {
           "address": "AMMSgtnttAKx5Ad2Y1socKJ3CcQYCB2ctg8U2SAHcVEx"
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "conditionalTokenProgram"
         },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "uri",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
     },
     {
       "name": "finalizeProposal",
@@ -663,6 +676,30 @@ export type Futarchy = {
             ]
           }
         },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ],
+            "program": {
+              "kind": "account",
+              "path": "vaultProgram"
+            }
+          }
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -679,6 +716,10 @@ export type Futarchy = {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
@@ -698,6 +739,10 @@ export type Futarchy = {
           "type": {
             "option": "string"
           }
+        },
+        {
+          "name": "permanentDelegate",
+          "type": "bool"
         }
       ],
       "returns": "u16"
@@ -774,6 +819,9 @@ export type Futarchy = {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "conditionalTokenProgram"
+        },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
@@ -861,6 +909,9 @@ export type Futarchy = {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "conditionalTokenProgram"
+        },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
@@ -1027,6 +1078,19 @@ export type Futarchy = {
         243,
         64
       ]
+    },
+    {
+      "name": "vaultAccount",
+      "discriminator": [
+        230,
+        251,
+        241,
+        83,
+        139,
+        202,
+        93,
+        28
+      ]
     }
   ],
   "events": [
@@ -1133,6 +1197,19 @@ export type Futarchy = {
         187,
         47
       ]
+    },
+    {
+      "name": "proposalVoided",
+      "discriminator": [
+        161,
+        216,
+        7,
+        106,
+        3,
+        53,
+        119,
+        31
+      ]
     }
   ],
   "errors": [
@@ -1230,6 +1307,11 @@ export type Futarchy = {
       "code": 6018,
       "name": "invalidProposalParams",
       "msg": "Invalid proposal parameters"
+    },
+    {
+      "code": 6019,
+      "name": "invalidTokenProgram",
+      "msg": "Invalid token program"
     }
   ],
   "types": [
@@ -1386,6 +1468,42 @@ export type Futarchy = {
         ]
       }
     },
+    {
+      "name": "depositCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "total",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perUser",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRates",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposit",
+            "type": "u16"
+          },
+          {
+            "name": "redeem",
+            "type": "u16"
+          }
+        ]
+      }
+    },
     {
       "name": "liquidityRedeemed",
       "type": {
@@ -1406,6 +1524,10 @@ export type Futarchy = {
           {
             "name": "winningIdx",
             "type": "u8"
+          },
+          {
+            "name": "voided",
+            "type": "bool"
           }
         ]
       }
@@ -1501,6 +1623,30 @@ export type Futarchy = {
         ]
       }
     },
+    {
+      "name": "parentLink",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "option",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "poolType",
       "type": {
@@ -1729,6 +1875,285 @@ export type Futarchy = {
             "fields": [
               "u8"
             ]
+          },
+          {
+            "name": "voided"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposalVoided",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u16"
+          },
+          {
+            "name": "proposal",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenMint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "pubkey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseMint",
+            "type": {
+              "defined": {
+                "name": "tokenMint"
+              }
+            }
+          },
+          {
+            "name": "quoteMint",
+            "type": {
+              "defined": {
+                "name": "tokenMint"
+              }
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u16"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": {
+                "name": "vaultState"
+              }
+            }
+          },
+          {
+            "name": "numOptions",
+            "type": "u8"
+          },
+          {
+            "name": "condBaseMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "condQuoteMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "payoutWeights",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "baseSetCoverage",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "quoteSetCoverage",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "resolutionDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "conditionalTokenProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "permanentDelegate",
+            "type": "bool"
+          },
+          {
+            "name": "baseTotals",
+            "type": {
+              "defined": {
+                "name": "vaultTotals"
+              }
+            }
+          },
+          {
+            "name": "quoteTotals",
+            "type": {
+              "defined": {
+                "name": "vaultTotals"
+              }
+            }
+          },
+          {
+            "name": "baseParent",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "parentLink"
+                }
+              }
+            }
+          },
+          {
+            "name": "quoteParent",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "parentLink"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingChunks",
+            "type": "u32"
+          },
+          {
+            "name": "extraMints",
+            "type": "u32"
+          },
+          {
+            "name": "baseCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "quoteCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "allowList",
+            "type": "bool"
+          },
+          {
+            "name": "oracleResolved",
+            "type": "bool"
+          },
+          {
+            "name": "feeRates",
+            "type": {
+              "defined": {
+                "name": "feeRates"
+              }
+            }
+          },
+          {
+            "name": "baseSymbol",
+            "type": "string"
+          },
+          {
+            "name": "quoteSymbol",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "setup"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "finalized",
+            "fields": [
+              "u8"
+            ]
+          },
+          {
+            "name": "finalizedWeighted"
+          },
+          {
+            "name": "voided"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultTotals",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposited",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "redeemed",
+            "type": "u64"
+          },
+          {
+            "name": "fees",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "base"
+          },
+          {
+            "name": "quote"
           }
         ]
       }
@@ -1764,6 +2189,11 @@ export type Futarchy = {
       "name": "proposalSeed",
       "type": "bytes",
       "value": "[112, 114, 111, 112, 111, 115, 97, 108]"
+    },
+    {
+      "name": "vaultResolutionGracePeriod",
+      "type": "i64",
+      "value": "604800"
     }
   ]
 };
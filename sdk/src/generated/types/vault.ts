@@ -13,6 +13,47 @@ export type Vault = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptProtocolAdmin",
+      "discriminator": [
+        76,
+        35,
+        211,
+        183,
+        82,
+        72,
+        131,
+        36
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "activate",
       "discriminator": [
@@ -141,37 +182,57 @@ export type Vault = {
           "address": "11111111111111111111111111111111"
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         },
         {
           "name": "associatedTokenProgram",
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "uri",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "label",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "deposit",
+      "name": "allowDepositor",
       "discriminator": [
-        242,
-        35,
-        198,
-        137,
-        82,
-        225,
-        242,
-        182
+        184,
+        102,
+        51,
+        206,
+        31,
+        97,
+        174,
+        107
       ],
       "accounts": [
         {
-          "name": "signer",
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
         {
           "name": "vault",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -198,73 +259,186 @@ export type Vault = {
           }
         },
         {
-          "name": "mint"
+          "name": "user"
         },
         {
-          "name": "vaultAta",
+          "name": "depositRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "allowed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "approveOperator",
+      "discriminator": [
+        117,
+        56,
+        29,
+        189,
+        94,
+        229,
+        234,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "operator"
+        },
+        {
+          "name": "vault"
+        },
+        {
+          "name": "operatorApproval",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
               {
                 "kind": "account",
                 "path": "vault"
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "owner"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "operator"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "userAta",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelChunked",
+      "discriminator": [
+        146,
+        9,
+        199,
+        162,
+        0,
+        170,
+        246,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "user",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chunkProgress",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "signer"
+                "path": "vault"
               },
               {
                 "kind": "account",
@@ -315,8 +489,17 @@ export type Vault = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "userAta",
+          "docs": [
+            "Validated in handler via `init_user_token_account`, unused for withdrawals"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
         },
         {
           "name": "associatedTokenProgram",
@@ -337,32 +520,24 @@ export type Vault = {
           }
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "startOption",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "finalize",
+      "name": "closeVault",
       "discriminator": [
-        171,
-        61,
-        218,
-        56,
-        127,
-        115,
-        12,
-        217
+        141,
+        103,
+        17,
+        126,
+        72,
+        75,
+        29,
+        29
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "docs": [
-            "Payer for account rent"
-          ],
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "owner",
           "docs": [
@@ -370,6 +545,10 @@ export type Vault = {
           ],
           "signer": true
         },
+        {
+          "name": "receiver",
+          "writable": true
+        },
         {
           "name": "vault",
           "writable": true,
@@ -397,69 +576,7 @@ export type Vault = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "winningIdx",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "initialize",
-      "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "docs": [
-            "Payer for account rent"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "owner",
-          "docs": [
-            "Owner of the vault"
-          ],
-          "signer": true
-        },
-        {
-          "name": "vault",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "owner"
-              },
-              {
-                "kind": "arg",
-                "path": "nonce"
-              }
-            ]
-          }
-        },
+        },
         {
           "name": "baseMint"
         },
@@ -467,7 +584,7 @@ export type Vault = {
           "name": "quoteMint"
         },
         {
-          "name": "baseTokenAcc",
+          "name": "vaultBaseAta",
           "writable": true,
           "pda": {
             "seeds": [
@@ -477,7 +594,7 @@ export type Vault = {
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "baseTokenProgram"
               },
               {
                 "kind": "account",
@@ -524,7 +641,7 @@ export type Vault = {
           }
         },
         {
-          "name": "quoteTokenAcc",
+          "name": "vaultQuoteAta",
           "writable": true,
           "pda": {
             "seeds": [
@@ -534,7 +651,7 @@ export type Vault = {
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "quoteTokenProgram"
               },
               {
                 "kind": "account",
@@ -581,56 +698,40 @@ export type Vault = {
           }
         },
         {
-          "name": "condBaseMint0",
-          "writable": true
-        },
-        {
-          "name": "condBaseMint1",
-          "writable": true
-        },
-        {
-          "name": "condQuoteMint0",
+          "name": "baseDustAccount",
           "writable": true
         },
         {
-          "name": "condQuoteMint1",
+          "name": "quoteDustAccount",
           "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "baseTokenProgram"
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "quoteTokenProgram"
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "conditionalTokenProgram"
         }
       ],
-      "args": [
-        {
-          "name": "nonce",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "redeemWinnings",
+      "name": "collectFees",
       "discriminator": [
-        209,
-        5,
-        204,
-        87,
-        134,
-        122,
-        239,
-        185
+        164,
+        152,
+        207,
+        99,
+        30,
+        186,
+        19,
+        182
       ],
       "accounts": [
         {
-          "name": "signer",
+          "name": "payer",
           "writable": true,
           "signer": true
         },
@@ -662,6 +763,32 @@ export type Vault = {
             ]
           }
         },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "relations": [
+            "protocolConfig"
+          ]
+        },
         {
           "name": "mint"
         },
@@ -723,13 +850,13 @@ export type Vault = {
           }
         },
         {
-          "name": "userAta",
+          "name": "feeRecipientAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "signer"
+                "path": "feeRecipient"
               },
               {
                 "kind": "account",
@@ -780,8 +907,7 @@ export type Vault = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "tokenProgram"
         },
         {
           "name": "associatedTokenProgram",
@@ -804,23 +930,33 @@ export type Vault = {
       ]
     },
     {
-      "name": "withdraw",
+      "name": "configureOracle",
       "discriminator": [
-        183,
-        18,
-        70,
-        156,
-        148,
-        109,
-        161,
-        34
+        245,
+        58,
+        202,
+        16,
+        204,
+        36,
+        82,
+        199
       ],
       "accounts": [
         {
-          "name": "signer",
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
           "writable": true,
           "signer": true
         },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
         {
           "name": "vault",
           "writable": true,
@@ -850,73 +986,106 @@ export type Vault = {
           }
         },
         {
-          "name": "mint"
+          "name": "pool"
         },
         {
-          "name": "vaultAta",
+          "name": "oracleConfig",
           "writable": true,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
               {
                 "kind": "account",
                 "path": "vault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "resolveAt",
+          "type": "i64"
+        },
+        {
+          "name": "thresholds",
+          "type": {
+            "vec": "u128"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "discriminator": [
+        242,
+        35,
+        198,
+        137,
+        82,
+        225,
+        242,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
               },
               {
                 "kind": "account",
-                "path": "tokenProgram"
+                "path": "vault.owner",
+                "account": "vaultAccount"
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "vault.nonce",
+                "account": "vaultAccount"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "userAta",
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "signer"
+                "path": "vault"
               },
               {
                 "kind": "account",
@@ -967,12 +1136,21 @@ export type Vault = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_ata`"
+          ],
+          "writable": true
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
           "name": "systemProgram",
@@ -993,317 +1171,6032 @@ export type Vault = {
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "vaultAccount",
-      "discriminator": [
-        230,
-        251,
-        241,
-        83,
-        139,
-        202,
-        93,
-        28
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "optionAdded",
-      "discriminator": [
-        136,
-        202,
-        18,
-        117,
-        144,
-        187,
-        122,
-        249
-      ]
-    },
-    {
-      "name": "vaultActivated",
-      "discriminator": [
-        76,
-        220,
-        220,
-        110,
-        216,
-        252,
-        88,
-        84
-      ]
-    },
-    {
-      "name": "vaultDeposit",
-      "discriminator": [
-        4,
-        248,
-        234,
-        163,
-        99,
-        238,
-        140,
-        45
-      ]
     },
     {
-      "name": "vaultFinalized",
+      "name": "depositBoth",
       "discriminator": [
-        200,
-        144,
-        206,
-        248,
-        111,
-        213,
-        163,
-        0
+        35,
+        115,
+        100,
+        51,
+        73,
+        141,
+        186,
+        194
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseMint"
+        },
+        {
+          "name": "quoteMint"
+        },
+        {
+          "name": "vaultBaseAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "baseTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "baseMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultQuoteAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "quoteTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userBaseAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userQuoteAta",
+          "writable": true
+        },
+        {
+          "name": "baseTokenProgram"
+        },
+        {
+          "name": "quoteTokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "baseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "quoteAmount",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "vaultInitialized",
+      "name": "depositFor",
       "discriminator": [
-        180,
-        43,
-        207,
-        2,
-        18,
-        71,
-        3,
-        75
+        193,
+        39,
+        228,
+        88,
+        160,
+        254,
+        92,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "operatorApproval",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "vaultWithdrawal",
+      "name": "expire",
       "discriminator": [
-        168,
-        109,
-        95,
-        252,
-        76,
-        240,
-        237,
-        56
-      ]
+        243,
+        83,
+        205,
+        58,
+        57,
+        201,
+        247,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalize",
+      "discriminator": [
+        171,
+        61,
+        218,
+        56,
+        127,
+        115,
+        12,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "winningIdx",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "finalizeWeighted",
+      "discriminator": [
+        146,
+        55,
+        51,
+        140,
+        84,
+        195,
+        168,
+        182
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "payoutWeights",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "arg",
+                "path": "nonce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseMint"
+        },
+        {
+          "name": "quoteMint"
+        },
+        {
+          "name": "baseTokenAcc",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "baseTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "baseMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "quoteTokenAcc",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "quoteTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "condBaseMint0",
+          "writable": true
+        },
+        {
+          "name": "condBaseMint1",
+          "writable": true
+        },
+        {
+          "name": "condQuoteMint0",
+          "writable": true
+        },
+        {
+          "name": "condQuoteMint1",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "baseTokenProgram"
+        },
+        {
+          "name": "quoteTokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u16"
+        },
+        {
+          "name": "baseSymbol",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "quoteSymbol",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "optionLabels",
+          "type": {
+            "array": [
+              "string",
+              2
+            ]
+          }
+        },
+        {
+          "name": "permanentDelegate",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "initializeProtocolConfig",
+      "discriminator": [
+        28,
+        50,
+        43,
+        233,
+        244,
+        98,
+        123,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer for account rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "program",
+          "address": "VLTEetGyPKtffi1u3Jr8btWATv33NeDyUuRsPENFPTU"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeRecipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeRates",
+          "type": {
+            "defined": {
+              "name": "feeRates"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "mergeOptions",
+      "discriminator": [
+        194,
+        176,
+        55,
+        104,
+        118,
+        218,
+        21,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAta"
+        },
+        {
+          "name": "setMint",
+          "writable": true
+        },
+        {
+          "name": "userSetAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "mask",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrate",
+      "discriminator": [
+        155,
+        234,
+        231,
+        146,
+        236,
+        158,
+        162,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "docs": [
+            "Owner checked here, discriminator and version checked in handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "baseEscrow"
+        },
+        {
+          "name": "quoteEscrow"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "processChunk",
+      "discriminator": [
+        235,
+        79,
+        61,
+        18,
+        72,
+        33,
+        26,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "user",
+          "writable": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chunkProgress",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "Validated in handler via `init_user_token_account`, unused for deposits"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "startOption",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "discriminator": [
+        184,
+        12,
+        86,
+        149,
+        70,
+        196,
+        97,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_ata`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "closeAccounts",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "redeemFor",
+      "discriminator": [
+        98,
+        224,
+        121,
+        78,
+        193,
+        242,
+        79,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemNested",
+      "discriminator": [
+        88,
+        136,
+        196,
+        181,
+        157,
+        18,
+        163,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "parent_vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "parent_vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentCondMint",
+          "writable": true
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "conditionalTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "parentCondMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "parentVaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "parentVault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "for the mint, or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemOptionSet",
+      "discriminator": [
+        21,
+        156,
+        212,
+        67,
+        34,
+        178,
+        202,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "setMint",
+          "writable": true
+        },
+        {
+          "name": "userSetAta",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "mask",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemReceipt",
+      "discriminator": [
+        213,
+        100,
+        93,
+        195,
+        251,
+        161,
+        175,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "receiptMint",
+          "writable": true
+        },
+        {
+          "name": "userReceiptAta",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemWinnings",
+      "discriminator": [
+        209,
+        5,
+        204,
+        87,
+        134,
+        122,
+        239,
+        185
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_ata`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolve",
+      "discriminator": [
+        246,
+        150,
+        236,
+        206,
+        108,
+        63,
+        58,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          },
+          "relations": [
+            "oracleConfig"
+          ]
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "relations": [
+            "oracleConfig"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revokeOperator",
+      "discriminator": [
+        185,
+        25,
+        87,
+        77,
+        88,
+        8,
+        30,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "operatorApproval"
+          ]
+        },
+        {
+          "name": "operatorApproval",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "operator_approval.vault",
+                "account": "operatorApproval"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "operator_approval.operator",
+                "account": "operatorApproval"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setDepositLimits",
+      "discriminator": [
+        167,
+        127,
+        131,
+        202,
+        2,
+        109,
+        0,
+        80
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "baseCaps",
+          "type": {
+            "defined": {
+              "name": "depositCaps"
+            }
+          }
+        },
+        {
+          "name": "quoteCaps",
+          "type": {
+            "defined": {
+              "name": "depositCaps"
+            }
+          }
+        },
+        {
+          "name": "allowList",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setParent",
+      "discriminator": [
+        98,
+        31,
+        51,
+        45,
+        125,
+        67,
+        205,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "parentVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "parent_vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "parent_vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "parentVaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "parentOption",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setResolutionDeadline",
+      "discriminator": [
+        215,
+        226,
+        5,
+        88,
+        173,
+        138,
+        118,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "resolutionDeadline",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splitOptionSet",
+      "discriminator": [
+        32,
+        122,
+        170,
+        184,
+        20,
+        241,
+        249,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAta"
+        },
+        {
+          "name": "setMint",
+          "writable": true
+        },
+        {
+          "name": "userSetAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "mask",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "startChunked",
+      "discriminator": [
+        48,
+        170,
+        60,
+        139,
+        202,
+        212,
+        247,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "chunkProgress",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "Validated in handler via `validate_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "chunkedAction"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "unwrap",
+      "discriminator": [
+        126,
+        175,
+        198,
+        14,
+        212,
+        69,
+        50,
+        44
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAta"
+        },
+        {
+          "name": "receiptMint",
+          "writable": true
+        },
+        {
+          "name": "userReceiptAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateProtocolConfig",
+      "discriminator": [
+        197,
+        97,
+        123,
+        54,
+        221,
+        168,
+        11,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "protocolConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pendingAdmin",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "feeRates",
+          "type": {
+            "defined": {
+              "name": "feeRates"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "void",
+      "discriminator": [
+        55,
+        130,
+        74,
+        24,
+        235,
+        14,
+        16,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner of the vault — needs to sign"
+          ],
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdraw",
+      "discriminator": [
+        183,
+        18,
+        70,
+        156,
+        148,
+        109,
+        161,
+        34
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_ata`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawBoth",
+      "discriminator": [
+        152,
+        222,
+        75,
+        129,
+        29,
+        24,
+        0,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "baseMint"
+        },
+        {
+          "name": "quoteMint"
+        },
+        {
+          "name": "vaultBaseAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "baseTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "baseMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultQuoteAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "quoteTokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "userBaseAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "userQuoteAta",
+          "writable": true
+        },
+        {
+          "name": "baseTokenProgram"
+        },
+        {
+          "name": "quoteTokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "baseAmount",
+          "type": "u64"
+        },
+        {
+          "name": "quoteAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFor",
+      "discriminator": [
+        160,
+        150,
+        243,
+        135,
+        236,
+        125,
+        235,
+        220
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner"
+        },
+        {
+          "name": "operatorApproval",
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  101,
+                  114,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "vaultAta",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "vault"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "conditionalTokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "wrap",
+      "discriminator": [
+        178,
+        40,
+        10,
+        189,
+        228,
+        129,
+        186,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "vault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "vault.owner",
+                "account": "vaultAccount"
+              },
+              {
+                "kind": "account",
+                "path": "vault.nonce",
+                "account": "vaultAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultAta"
+        },
+        {
+          "name": "receiptMint",
+          "writable": true
+        },
+        {
+          "name": "userReceiptAta",
+          "docs": [
+            "or their ATA (created if missing). Validated in handler via `init_user_token_account`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "conditionalTokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "vaultType",
+          "type": {
+            "defined": {
+              "name": "vaultType"
+            }
+          }
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "chunkProgress",
+      "discriminator": [
+        192,
+        75,
+        199,
+        222,
+        238,
+        159,
+        116,
+        151
+      ]
+    },
+    {
+      "name": "depositRecord",
+      "discriminator": [
+        83,
+        232,
+        10,
+        31,
+        251,
+        49,
+        189,
+        167
+      ]
+    },
+    {
+      "name": "operatorApproval",
+      "discriminator": [
+        247,
+        72,
+        26,
+        58,
+        86,
+        185,
+        221,
+        219
+      ]
+    },
+    {
+      "name": "oracleConfig",
+      "discriminator": [
+        133,
+        196,
+        152,
+        50,
+        27,
+        21,
+        145,
+        254
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "vaultAccount",
+      "discriminator": [
+        230,
+        251,
+        241,
+        83,
+        139,
+        202,
+        93,
+        28
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "chunkProcessed",
+      "discriminator": [
+        187,
+        132,
+        201,
+        131,
+        165,
+        248,
+        173,
+        247
+      ]
+    },
+    {
+      "name": "chunkedActionCancelled",
+      "discriminator": [
+        112,
+        173,
+        142,
+        86,
+        36,
+        160,
+        184,
+        245
+      ]
+    },
+    {
+      "name": "chunkedActionStarted",
+      "discriminator": [
+        29,
+        51,
+        12,
+        115,
+        193,
+        242,
+        80,
+        53
+      ]
+    },
+    {
+      "name": "depositLimitsSet",
+      "discriminator": [
+        194,
+        45,
+        235,
+        8,
+        136,
+        140,
+        113,
+        136
+      ]
+    },
+    {
+      "name": "depositorAllowed",
+      "discriminator": [
+        184,
+        127,
+        60,
+        66,
+        75,
+        167,
+        161,
+        179
+      ]
+    },
+    {
+      "name": "feesCollected",
+      "discriminator": [
+        233,
+        23,
+        117,
+        225,
+        107,
+        178,
+        254,
+        8
+      ]
+    },
+    {
+      "name": "nestedWinningsRedeemed",
+      "discriminator": [
+        86,
+        202,
+        167,
+        243,
+        90,
+        128,
+        120,
+        163
+      ]
+    },
+    {
+      "name": "operatorApproved",
+      "discriminator": [
+        40,
+        34,
+        192,
+        156,
+        70,
+        243,
+        202,
+        144
+      ]
+    },
+    {
+      "name": "operatorRevoked",
+      "discriminator": [
+        234,
+        41,
+        78,
+        23,
+        191,
+        224,
+        103,
+        64
+      ]
+    },
+    {
+      "name": "optionAdded",
+      "discriminator": [
+        136,
+        202,
+        18,
+        117,
+        144,
+        187,
+        122,
+        249
+      ]
+    },
+    {
+      "name": "optionSetRedeemed",
+      "discriminator": [
+        236,
+        168,
+        6,
+        64,
+        72,
+        119,
+        38,
+        247
+      ]
+    },
+    {
+      "name": "optionSetSplit",
+      "discriminator": [
+        33,
+        230,
+        60,
+        163,
+        164,
+        35,
+        109,
+        120
+      ]
+    },
+    {
+      "name": "optionsMerged",
+      "discriminator": [
+        167,
+        218,
+        24,
+        84,
+        250,
+        237,
+        151,
+        151
+      ]
+    },
+    {
+      "name": "oracleConfigured",
+      "discriminator": [
+        111,
+        102,
+        175,
+        86,
+        180,
+        219,
+        55,
+        79
+      ]
+    },
+    {
+      "name": "parentVaultSet",
+      "discriminator": [
+        201,
+        30,
+        153,
+        189,
+        90,
+        193,
+        90,
+        51
+      ]
+    },
+    {
+      "name": "protocolAdminAccepted",
+      "discriminator": [
+        203,
+        190,
+        137,
+        225,
+        225,
+        43,
+        76,
+        252
+      ]
+    },
+    {
+      "name": "protocolConfigInitialized",
+      "discriminator": [
+        243,
+        69,
+        27,
+        238,
+        111,
+        169,
+        87,
+        231
+      ]
+    },
+    {
+      "name": "protocolConfigUpdated",
+      "discriminator": [
+        20,
+        99,
+        32,
+        237,
+        111,
+        86,
+        195,
+        199
+      ]
+    },
+    {
+      "name": "receiptRedeemed",
+      "discriminator": [
+        141,
+        39,
+        41,
+        130,
+        225,
+        81,
+        103,
+        187
+      ]
+    },
+    {
+      "name": "receiptUnwrapped",
+      "discriminator": [
+        16,
+        94,
+        92,
+        193,
+        251,
+        112,
+        39,
+        41
+      ]
+    },
+    {
+      "name": "receiptWrapped",
+      "discriminator": [
+        154,
+        6,
+        36,
+        125,
+        145,
+        149,
+        56,
+        9
+      ]
+    },
+    {
+      "name": "resolutionDeadlineSet",
+      "discriminator": [
+        6,
+        175,
+        26,
+        61,
+        115,
+        154,
+        221,
+        84
+      ]
+    },
+    {
+      "name": "tokenAccountClosed",
+      "discriminator": [
+        58,
+        175,
+        118,
+        169,
+        94,
+        175,
+        37,
+        5
+      ]
+    },
+    {
+      "name": "tokenAccountCreated",
+      "discriminator": [
+        254,
+        64,
+        207,
+        221,
+        139,
+        60,
+        97,
+        168
+      ]
+    },
+    {
+      "name": "vaultActivated",
+      "discriminator": [
+        76,
+        220,
+        220,
+        110,
+        216,
+        252,
+        88,
+        84
+      ]
+    },
+    {
+      "name": "vaultClosed",
+      "discriminator": [
+        238,
+        129,
+        38,
+        228,
+        227,
+        118,
+        249,
+        215
+      ]
+    },
+    {
+      "name": "vaultDeposit",
+      "discriminator": [
+        4,
+        248,
+        234,
+        163,
+        99,
+        238,
+        140,
+        45
+      ]
+    },
+    {
+      "name": "vaultDepositFor",
+      "discriminator": [
+        12,
+        182,
+        6,
+        44,
+        86,
+        199,
+        253,
+        133
+      ]
+    },
+    {
+      "name": "vaultExpired",
+      "discriminator": [
+        101,
+        141,
+        174,
+        235,
+        37,
+        229,
+        87,
+        0
+      ]
+    },
+    {
+      "name": "vaultFinalized",
+      "discriminator": [
+        200,
+        144,
+        206,
+        248,
+        111,
+        213,
+        163,
+        0
+      ]
+    },
+    {
+      "name": "vaultFinalizedWeighted",
+      "discriminator": [
+        241,
+        8,
+        237,
+        5,
+        200,
+        161,
+        198,
+        169
+      ]
+    },
+    {
+      "name": "vaultInitialized",
+      "discriminator": [
+        180,
+        43,
+        207,
+        2,
+        18,
+        71,
+        3,
+        75
+      ]
+    },
+    {
+      "name": "vaultMigrated",
+      "discriminator": [
+        184,
+        13,
+        33,
+        52,
+        25,
+        239,
+        189,
+        81
+      ]
+    },
+    {
+      "name": "vaultResolved",
+      "discriminator": [
+        197,
+        224,
+        199,
+        26,
+        139,
+        9,
+        175,
+        20
+      ]
+    },
+    {
+      "name": "vaultVoided",
+      "discriminator": [
+        230,
+        197,
+        9,
+        96,
+        53,
+        69,
+        205,
+        49
+      ]
+    },
+    {
+      "name": "vaultWithdrawal",
+      "discriminator": [
+        168,
+        109,
+        95,
+        252,
+        76,
+        240,
+        237,
+        56
+      ]
+    },
+    {
+      "name": "vaultWithdrawalFor",
+      "discriminator": [
+        103,
+        209,
+        252,
+        193,
+        1,
+        177,
+        194,
+        68
+      ]
+    },
+    {
+      "name": "winningsRedeemed",
+      "discriminator": [
+        165,
+        63,
+        125,
+        179,
+        230,
+        236,
+        63,
+        99
+      ]
+    },
+    {
+      "name": "winningsRedeemedFor",
+      "discriminator": [
+        139,
+        149,
+        121,
+        70,
+        80,
+        176,
+        221,
+        62
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "vaultAlreadyExists",
+      "msg": "Vault already exists"
+    },
+    {
+      "code": 6001,
+      "name": "invalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6002,
+      "name": "invalidState",
+      "msg": "Invalid state"
+    },
+    {
+      "code": 6003,
+      "name": "notEnoughOptions",
+      "msg": "Minimum 2 options required"
+    },
+    {
+      "code": 6004,
+      "name": "tooManyOptions",
+      "msg": "Too many options"
+    },
+    {
+      "code": 6005,
+      "name": "optionLimitReached",
+      "msg": "Option limit reached"
+    },
+    {
+      "code": 6006,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6007,
+      "name": "indexOutOfBounds",
+      "msg": "Index out of bounds"
+    },
+    {
+      "code": 6008,
+      "name": "invalidNumberOfAccounts",
+      "msg": "Invalid number of accounts"
+    },
+    {
+      "code": 6009,
+      "name": "invalidConditionalMint",
+      "msg": "Invalid conditional mint"
+    },
+    {
+      "code": 6010,
+      "name": "invalidUserAta",
+      "msg": "Invalid user ATA"
+    },
+    {
+      "code": 6011,
+      "name": "invalidAccountOwner",
+      "msg": "Invalid account owner"
+    },
+    {
+      "code": 6012,
+      "name": "noConditionalTokens",
+      "msg": "No conditional tokens"
+    },
+    {
+      "code": 6013,
+      "name": "invalidMint",
+      "msg": "Invalid mint for vault type"
+    },
+    {
+      "code": 6014,
+      "name": "invalidPayoutWeights",
+      "msg": "Payout weights must cover every option and sum to 10,000 bps"
+    },
+    {
+      "code": 6015,
+      "name": "mathOverflow",
+      "msg": "Math overflow"
+    },
+    {
+      "code": 6016,
+      "name": "invalidResolutionDeadline",
+      "msg": "Resolution deadline must be in the future"
+    },
+    {
+      "code": 6017,
+      "name": "resolutionDeadlineNotReached",
+      "msg": "Resolution deadline has not passed"
+    },
+    {
+      "code": 6018,
+      "name": "resolutionDeadlinePassed",
+      "msg": "Resolution deadline has passed"
+    },
+    {
+      "code": 6019,
+      "name": "metadataUriTooLong",
+      "msg": "Metadata URI too long"
+    },
+    {
+      "code": 6020,
+      "name": "invalidTokenProgram",
+      "msg": "Invalid token program"
+    },
+    {
+      "code": 6021,
+      "name": "escrowInvariantViolated",
+      "msg": "Escrow balance below outstanding conditional supply"
+    },
+    {
+      "code": 6022,
+      "name": "outstandingSupply",
+      "msg": "Winning conditional supply not fully redeemed"
+    },
+    {
+      "code": 6023,
+      "name": "invalidHolder",
+      "msg": "Holder must be a wallet"
+    },
+    {
+      "code": 6024,
+      "name": "invalidOptionSet",
+      "msg": "Option set must be a proper subset of at least two options"
+    },
+    {
+      "code": 6025,
+      "name": "invalidParentVault",
+      "msg": "Underlying mint is not the parent vault's conditional mint"
+    },
+    {
+      "code": 6026,
+      "name": "noParentVault",
+      "msg": "Vault has no parent vault for this type"
+    },
+    {
+      "code": 6027,
+      "name": "invalidUserTokenAccount",
+      "msg": "Token account must belong to the user and hold the expected mint"
+    },
+    {
+      "code": 6028,
+      "name": "optionAlreadyProcessed",
+      "msg": "Option already processed for this chunked action"
+    },
+    {
+      "code": 6029,
+      "name": "pendingChunkedActions",
+      "msg": "Chunked deposits or withdrawals still in progress"
+    },
+    {
+      "code": 6030,
+      "name": "depositCapExceeded",
+      "msg": "Deposit exceeds the vault's total cap"
+    },
+    {
+      "code": 6031,
+      "name": "userDepositCapExceeded",
+      "msg": "Deposit exceeds the per-user cap"
+    },
+    {
+      "code": 6032,
+      "name": "depositorNotAllowed",
+      "msg": "Depositor is not on the vault's allow-list"
+    },
+    {
+      "code": 6033,
+      "name": "invalidDepositRecord",
+      "msg": "Missing or invalid deposit record"
+    },
+    {
+      "code": 6034,
+      "name": "oracleResolved",
+      "msg": "Vault is resolved by its oracle"
+    },
+    {
+      "code": 6035,
+      "name": "invalidOracleThresholds",
+      "msg": "Thresholds must be strictly ascending, one fewer than options"
+    },
+    {
+      "code": 6036,
+      "name": "oracleNotReady",
+      "msg": "Oracle pool has not ceased trading at or after the resolution time"
+    },
+    {
+      "code": 6037,
+      "name": "invalidVersion",
+      "msg": "Invalid vault version"
+    },
+    {
+      "code": 6038,
+      "name": "invalidEscrow",
+      "msg": "Invalid vault escrow account"
+    },
+    {
+      "code": 6039,
+      "name": "invalidFee",
+      "msg": "Protocol fee exceeds the maximum"
+    },
+    {
+      "code": 6040,
+      "name": "uncollectedFees",
+      "msg": "Protocol fees not yet collected"
+    },
+    {
+      "code": 6041,
+      "name": "metadataLabelTooLong",
+      "msg": "Metadata symbol or option label too long"
+    },
+    {
+      "code": 6042,
+      "name": "noPermanentDelegate",
+      "msg": "Vault did not opt into the permanent delegate"
+    },
+    {
+      "code": 6043,
+      "name": "optionNotProcessed",
+      "msg": "Option not processed for this chunked action"
+    },
+    {
+      "code": 6044,
+      "name": "token2022Required",
+      "msg": "Requires Token-2022 conditional mints, unavailable on vaults migrated from v1"
+    },
+    {
+      "code": 6045,
+      "name": "invalidOraclePool",
+      "msg": "Oracle pool does not trade the vault's base and quote mints"
+    }
+  ],
+  "types": [
+    {
+      "name": "chunkProcessed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "chunkedAction"
+              }
+            }
+          },
+          {
+            "name": "startOption",
+            "type": "u8"
+          },
+          {
+            "name": "numOptions",
+            "type": "u8"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chunkProgress",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "chunkedAction"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "processed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chunkedAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "deposit"
+          },
+          {
+            "name": "withdraw"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chunkedActionCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "chunkedAction"
+              }
+            }
+          },
+          {
+            "name": "startOption",
+            "type": "u8"
+          },
+          {
+            "name": "numOptions",
+            "type": "u8"
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "refunded",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "chunkedActionStarted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "chunkedAction"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "total",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "perUser",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositLimitsSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "quoteCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "allowList",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "allowed",
+            "type": "bool"
+          },
+          {
+            "name": "baseDeposited",
+            "type": "u64"
+          },
+          {
+            "name": "quoteDeposited",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "depositorAllowed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "allowed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRates",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposit",
+            "type": "u16"
+          },
+          {
+            "name": "redeem",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feesCollected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nestedWinningsRedeemed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "parentVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "parentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "childFee",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "parentEscrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "parentCondSupply",
+            "type": "u64"
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "operatorApproval",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "operatorApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "operatorRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "operator",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionAdded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "optionIndex",
+            "type": "u8"
+          },
+          {
+            "name": "condBaseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "condQuoteMint",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionSetRedeemed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "mask",
+            "type": "u64"
+          },
+          {
+            "name": "burned",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionSetSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "mask",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "optionsMerged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "mask",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolveAt",
+            "type": "i64"
+          },
+          {
+            "name": "thresholds",
+            "type": {
+              "vec": "u128"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleConfigured",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolveAt",
+            "type": "i64"
+          },
+          {
+            "name": "thresholds",
+            "type": {
+              "vec": "u128"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "parentLink",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "option",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "parentVaultSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "parentVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "parentVaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "parentOption",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolAdminAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRates",
+            "type": {
+              "defined": {
+                "name": "feeRates"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfigInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRates",
+            "type": {
+              "defined": {
+                "name": "feeRates"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeRates",
+            "type": {
+              "defined": {
+                "name": "feeRates"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "receiptRedeemed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "burned",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "winningsRedeemed",
-      "discriminator": [
-        165,
-        63,
-        125,
-        179,
-        230,
-        236,
-        63,
-        99
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "vaultAlreadyExists",
-      "msg": "Vault already exists"
+      "name": "receiptUnwrapped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "invalidAmount",
-      "msg": "Invalid amount"
+      "name": "receiptWrapped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidState",
-      "msg": "Invalid state"
+      "name": "resolutionDeadlineSet",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolutionDeadline",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "notEnoughOptions",
-      "msg": "Minimum 2 options required"
+      "name": "tokenAccountClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "destination",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "tooManyOptions",
-      "msg": "Too many options"
+      "name": "tokenAccountCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "optionLimitReached",
-      "msg": "Option limit reached"
+      "name": "tokenMint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "pubkey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "name": "vaultAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseMint",
+            "type": {
+              "defined": {
+                "name": "tokenMint"
+              }
+            }
+          },
+          {
+            "name": "quoteMint",
+            "type": {
+              "defined": {
+                "name": "tokenMint"
+              }
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u16"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": {
+                "name": "vaultState"
+              }
+            }
+          },
+          {
+            "name": "numOptions",
+            "type": "u8"
+          },
+          {
+            "name": "condBaseMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "condQuoteMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "payoutWeights",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "baseSetCoverage",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "quoteSetCoverage",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "resolutionDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "conditionalTokenProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "permanentDelegate",
+            "type": "bool"
+          },
+          {
+            "name": "baseTotals",
+            "type": {
+              "defined": {
+                "name": "vaultTotals"
+              }
+            }
+          },
+          {
+            "name": "quoteTotals",
+            "type": {
+              "defined": {
+                "name": "vaultTotals"
+              }
+            }
+          },
+          {
+            "name": "baseParent",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "parentLink"
+                }
+              }
+            }
+          },
+          {
+            "name": "quoteParent",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "parentLink"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingChunks",
+            "type": "u32"
+          },
+          {
+            "name": "extraMints",
+            "type": "u32"
+          },
+          {
+            "name": "baseCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "quoteCaps",
+            "type": {
+              "defined": {
+                "name": "depositCaps"
+              }
+            }
+          },
+          {
+            "name": "allowList",
+            "type": "bool"
+          },
+          {
+            "name": "oracleResolved",
+            "type": "bool"
+          },
+          {
+            "name": "feeRates",
+            "type": {
+              "defined": {
+                "name": "feeRates"
+              }
+            }
+          },
+          {
+            "name": "baseSymbol",
+            "type": "string"
+          },
+          {
+            "name": "quoteSymbol",
+            "type": "string"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "indexOutOfBounds",
-      "msg": "Index out of bounds"
+      "name": "vaultActivated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "numOptions",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "invalidNumberOfAccounts",
-      "msg": "Invalid number of accounts"
+      "name": "vaultClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "receiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseDust",
+            "type": "u64"
+          },
+          {
+            "name": "quoteDust",
+            "type": "u64"
+          },
+          {
+            "name": "closedMints",
+            "type": "u32"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "invalidConditionalMint",
-      "msg": "Invalid conditional mint"
+      "name": "vaultDeposit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidUserAta",
-      "msg": "Invalid user ATA"
+      "name": "vaultDepositFor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "signer",
+            "type": "pubkey"
+          },
+          {
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "invalidAccountOwner",
-      "msg": "Invalid account owner"
+      "name": "vaultExpired",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "resolutionDeadline",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "noConditionalTokens",
-      "msg": "No conditional tokens"
+      "name": "vaultFinalized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "winningIdx",
+            "type": "u8"
+          },
+          {
+            "name": "winningBaseMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "winningQuoteMint",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "invalidMint",
-      "msg": "Invalid mint for vault type"
-    }
-  ],
-  "types": [
+      "name": "vaultFinalizedWeighted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "payoutWeights",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "optionAdded",
+      "name": "vaultInitialized",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
           {
             "name": "vault",
             "type": "pubkey"
           },
           {
-            "name": "optionIndex",
-            "type": "u8"
+            "name": "owner",
+            "type": "pubkey"
           },
           {
-            "name": "condBaseMint",
+            "name": "baseMint",
             "type": "pubkey"
           },
           {
-            "name": "condQuoteMint",
+            "name": "quoteMint",
             "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "tokenMint",
+      "name": "vaultMigrated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "address",
+            "name": "vault",
             "type": "pubkey"
           },
           {
-            "name": "decimals",
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
             "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "vaultAccount",
+      "name": "vaultResolved",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "type": "u8"
+            "name": "vault",
+            "type": "pubkey"
           },
           {
-            "name": "bump",
+            "name": "pool",
+            "type": "pubkey"
+          },
+          {
+            "name": "twap",
+            "type": "u128"
+          },
+          {
+            "name": "winningIdx",
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "setup"
           },
           {
-            "name": "owner",
-            "type": "pubkey"
+            "name": "active"
           },
           {
-            "name": "baseMint",
-            "type": {
-              "defined": {
-                "name": "tokenMint"
-              }
-            }
+            "name": "finalized",
+            "fields": [
+              "u8"
+            ]
           },
           {
-            "name": "quoteMint",
-            "type": {
-              "defined": {
-                "name": "tokenMint"
-              }
-            }
+            "name": "finalizedWeighted"
           },
           {
-            "name": "nonce",
-            "type": "u16"
+            "name": "voided"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultTotals",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposited",
+            "type": "u64"
           },
           {
-            "name": "state",
-            "type": {
-              "defined": {
-                "name": "vaultState"
-              }
-            }
+            "name": "withdrawn",
+            "type": "u64"
           },
           {
-            "name": "numOptions",
-            "type": "u8"
+            "name": "redeemed",
+            "type": "u64"
           },
           {
-            "name": "condBaseMints",
-            "type": {
-              "array": [
-                "pubkey",
-                8
-              ]
-            }
+            "name": "fees",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "vaultType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "base"
           },
           {
-            "name": "condQuoteMints",
-            "type": {
-              "array": [
-                "pubkey",
-                8
-              ]
-            }
+            "name": "quote"
           }
         ]
       }
     },
     {
-      "name": "vaultActivated",
+      "name": "vaultVoided",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "vault",
             "type": "pubkey"
-          },
-          {
-            "name": "numOptions",
-            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "vaultDeposit",
+      "name": "vaultWithdrawal",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1326,43 +7219,29 @@ export type Vault = {
           {
             "name": "amount",
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "vaultFinalized",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "vault",
-            "type": "pubkey"
           },
           {
-            "name": "winningIdx",
-            "type": "u8"
+            "name": "escrowBalance",
+            "type": "u64"
           },
           {
-            "name": "winningBaseMint",
-            "type": "pubkey"
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
           },
           {
-            "name": "winningQuoteMint",
-            "type": "pubkey"
+            "name": "slot",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "vaultInitialized",
+      "name": "vaultWithdrawalFor",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "version",
-            "type": "u8"
-          },
           {
             "name": "vault",
             "type": "pubkey"
@@ -1372,56 +7251,40 @@ export type Vault = {
             "type": "pubkey"
           },
           {
-            "name": "baseMint",
+            "name": "signer",
             "type": "pubkey"
           },
           {
-            "name": "quoteMint",
-            "type": "pubkey"
+            "name": "vaultType",
+            "type": {
+              "defined": {
+                "name": "vaultType"
+              }
+            }
           },
           {
-            "name": "nonce",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "vaultState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "setup"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "active"
+            "name": "escrowBalance",
+            "type": "u64"
           },
           {
-            "name": "finalized",
-            "fields": [
-              "u8"
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "vaultType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "base"
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
           },
           {
-            "name": "quote"
+            "name": "slot",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "vaultWithdrawal",
+      "name": "winningsRedeemed",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1444,12 +7307,30 @@ export type Vault = {
           {
             "name": "amount",
             "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "winningsRedeemed",
+      "name": "winningsRedeemedFor",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1458,7 +7339,11 @@ export type Vault = {
             "type": "pubkey"
           },
           {
-            "name": "user",
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "caller",
             "type": "pubkey"
           },
           {
@@ -1472,27 +7357,105 @@ export type Vault = {
           {
             "name": "amount",
             "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBalance",
+            "type": "u64"
+          },
+          {
+            "name": "condSupply",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "slot",
+            "type": "u64"
           }
         ]
       }
     }
   ],
   "constants": [
+    {
+      "name": "chunkProgressSeed",
+      "type": "bytes",
+      "value": "[99, 104, 117, 110, 107]"
+    },
     {
       "name": "conditionalMintSeed",
       "type": "bytes",
       "value": "[99, 109, 105, 110, 116]"
     },
+    {
+      "name": "conditionalSetMintSeed",
+      "type": "bytes",
+      "value": "[99, 115, 109, 105, 110, 116]"
+    },
+    {
+      "name": "depositRecordSeed",
+      "type": "bytes",
+      "value": "[100, 101, 112, 111, 115, 105, 116, 111, 114]"
+    },
+    {
+      "name": "maxMetadataUriLen",
+      "type": "u16",
+      "value": "200"
+    },
     {
       "name": "maxOptions",
       "type": "u8",
-      "value": "8"
+      "value": "64"
+    },
+    {
+      "name": "maxOptionLabelLen",
+      "type": "u16",
+      "value": "32"
+    },
+    {
+      "name": "maxProtocolFee",
+      "type": "u16",
+      "value": "1000"
+    },
+    {
+      "name": "maxSymbolLen",
+      "type": "u16",
+      "value": "10"
     },
     {
       "name": "minOptions",
       "type": "u8",
       "value": "2"
     },
+    {
+      "name": "operatorApprovalSeed",
+      "type": "bytes",
+      "value": "[111, 112, 101, 114, 97, 116, 111, 114]"
+    },
+    {
+      "name": "oracleConfigSeed",
+      "type": "bytes",
+      "value": "[111, 114, 97, 99, 108, 101]"
+    },
+    {
+      "name": "payoutWeightTotal",
+      "type": "u16",
+      "value": "10000"
+    },
+    {
+      "name": "protocolConfigSeed",
+      "type": "bytes",
+      "value": "[112, 114, 111, 116, 111, 99, 111, 108]"
+    },
+    {
+      "name": "receiptMintSeed",
+      "type": "bytes",
+      "value": "[114, 101, 99, 101, 105, 112, 116]"
+    },
     {
       "name": "vaultSeed",
       "type": "bytes",
@@ -1501,7 +7464,7 @@ export type Vault = {
     {
       "name": "vaultVersion",
       "type": "u8",
-      "value": "1"
+      "value": "2"
     }
   ]
 };
@@ -1,6 +1,6 @@
 /* Shared Types */
 
-export { TxOptions } from "./utils";
+export { TxOptions, fetchTokenProgram } from "./utils";
 
 /* Vault Sub-SDK */
 
@@ -11,26 +11,90 @@ export {
   VaultType,
   VaultState,
   VaultAccount,
+  ChunkedAction,
+  ChunkProgressAccount,
+  DepositRecordAccount,
+  OracleConfigAccount,
+  ProtocolConfigAccount,
+  FeeRates,
   VaultInitializedEvent,
   VaultActivatedEvent,
   VaultDepositEvent,
   VaultWithdrawalEvent,
   VaultFinalizedEvent,
+  VaultFinalizedWeightedEvent,
+  VaultVoidedEvent,
+  VaultExpiredEvent,
+  VaultClosedEvent,
+  VaultMigratedEvent,
+  ReceiptWrappedEvent,
+  ReceiptUnwrappedEvent,
+  ReceiptRedeemedEvent,
+  ResolutionDeadlineSetEvent,
   OptionAddedEvent as VaultOptionAddedEvent,
+  OptionsMergedEvent,
+  OptionSetSplitEvent,
+  OptionSetRedeemedEvent,
   WinningsRedeemedEvent,
+  WinningsRedeemedForEvent,
+  VaultDepositForEvent,
+  VaultWithdrawalForEvent,
+  OperatorApprovedEvent,
+  OperatorRevokedEvent,
+  ChunkedActionStartedEvent,
+  ChunkProcessedEvent,
+  ChunkedActionCancelledEvent,
+  DepositLimitsSetEvent,
+  DepositorAllowedEvent,
+  OracleConfiguredEvent,
+  VaultResolvedEvent,
+  ParentVaultSetEvent,
+  NestedWinningsRedeemedEvent,
+  TokenAccountCreatedEvent,
+  TokenAccountClosedEvent,
+  ProtocolConfigInitializedEvent,
+  ProtocolConfigUpdatedEvent,
+  ProtocolAdminAcceptedEvent,
+  FeesCollectedEvent,
   VaultEvent,
   VaultActionOptions,
+  RedeemOptions,
+  UserTokenAccounts,
+  OperatorActionOptions,
+  DepositCaps,
+  DepositLimits,
+  InitializeVaultOptions,
   // Utils
   deriveVaultPDA,
   deriveConditionalMint,
+  deriveSetMint,
+  deriveDepositRecordPDA,
+  deriveOperatorApprovalPDA,
+  deriveChunkProgressPDA,
+  deriveOracleConfigPDA,
+  deriveReceiptMint,
+  deriveProtocolConfigPDA,
+  deriveProgramDataPDA,
   parseVaultState,
   fetchVaultAccount,
   // Constants
   PROGRAM_ID as VAULT_PROGRAM_ID,
   VAULT_SEED,
   CONDITIONAL_MINT_SEED,
+  CONDITIONAL_SET_MINT_SEED,
+  RECEIPT_MINT_SEED,
+  DEPOSIT_RECORD_SEED,
+  OPERATOR_APPROVAL_SEED,
+  CHUNK_PROGRESS_SEED,
+  ORACLE_CONFIG_SEED,
+  PROTOCOL_CONFIG_SEED,
   MAX_OPTIONS as VAULT_MAX_OPTIONS,
   MIN_OPTIONS as VAULT_MIN_OPTIONS,
+  PAYOUT_WEIGHT_TOTAL,
+  MAX_SYMBOL_LEN as VAULT_MAX_SYMBOL_LEN,
+  MAX_OPTION_LABEL_LEN as VAULT_MAX_OPTION_LABEL_LEN,
+  MAX_METADATA_URI_LEN as VAULT_MAX_METADATA_URI_LEN,
+  MAX_PROTOCOL_FEE,
 } from "./vault";
 
 /* AMM Sub-SDK */
@@ -91,8 +155,11 @@ export {
   ProposalLaunchedEvent,
   OptionAddedEvent as FutarchyOptionAddedEvent,
   ProposalFinalizedEvent,
+  ProposalVoidedEvent,
   LiquidityRedeemedEvent,
   FutarchyEvent,
+  InitializeProposalOptions,
+  AddOptionOptions,
   // Utils
   deriveDAOPDA,
   deriveModeratorPDA,
@@ -110,6 +177,7 @@ export {
   PROPOSAL_SEED,
   MAX_OPTIONS as FUTARCHY_MAX_OPTIONS,
   MIN_OPTIONS as FUTARCHY_MIN_OPTIONS,
+  VAULT_RESOLUTION_GRACE_PERIOD,
 } from "./futarchy";
 
 /* Sub-SDK Namespaces */
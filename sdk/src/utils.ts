@@ -3,6 +3,8 @@
  * Used across program SDKs.
  */
 
+import { Connection, PublicKey } from "@solana/web3.js";
+
 /* Transaction Options */
 
 export interface TxOptions {
@@ -39,3 +41,18 @@ export function getIdlConstant(
   }
   return constant.value;
 }
+
+/*
+ * Get the token program owning a mint (SPL Token or Token-2022).
+ * Token accounts and ATAs for the mint must be derived under this program.
+ */
+export async function fetchTokenProgram(
+  connection: Connection,
+  mint: PublicKey
+): Promise<PublicKey> {
+  const info = await connection.getAccountInfo(mint);
+  if (!info) {
+    throw new Error(`Mint not found: ${mint.toBase58()}`);
+  }
+  return info.owner;
+}
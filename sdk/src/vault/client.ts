@@ -4,7 +4,7 @@
  * native SOL wrapping/unwrapping, and compute budget management.
  */
 
-import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
+import { Program, AnchorProvider, BN, EventParser } from "@coral-xyz/anchor";
 import { PublicKey, ComputeBudgetProgram, SystemProgram } from "@solana/web3.js";
 import {
   getAccount,
@@ -14,25 +14,84 @@ import {
   createSyncNativeInstruction,
   createCloseAccountInstruction,
   createAssociatedTokenAccountIdempotentInstruction,
+  TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { PROGRAM_ID } from "./constants";
-import { Vault, VaultType, VaultAccount, VaultActionOptions } from "./types";
+import {
+  Vault,
+  VaultType,
+  VaultAccount,
+  ChunkProgressAccount,
+  DepositRecordAccount,
+  OracleConfigAccount,
+  ProtocolConfigAccount,
+  FeeRates,
+  DepositLimits,
+  ChunkedAction,
+  VaultActionOptions,
+  RedeemOptions,
+  VaultEvent,
+  OperatorActionOptions,
+  InitializeVaultOptions,
+} from "./types";
 import {
   deriveVaultPDA,
   deriveConditionalMint,
+  deriveSetMint,
+  deriveDepositRecordPDA,
+  deriveOperatorApprovalPDA,
+  deriveChunkProgressPDA,
+  deriveOracleConfigPDA,
+  deriveReceiptMint,
+  deriveProtocolConfigPDA,
+  deriveProgramDataPDA,
   fetchVaultAccount,
 } from "./utils";
 import {
   initialize,
   addOption,
+  setResolutionDeadline,
+  setDepositLimits,
+  allowDepositor,
+  configureOracle,
   activate,
   deposit,
   withdraw,
   finalize,
+  finalizeWeighted,
+  voidVault,
+  closeVault,
+  migrate,
+  expire,
+  resolve,
   redeemWinnings,
+  redeem,
+  redeemFor,
+  mergeOptions,
+  splitOptionSet,
+  redeemOptionSet,
+  wrap,
+  unwrap,
+  redeemReceipt,
+  setParent,
+  redeemNested,
+  depositBoth,
+  withdrawBoth,
+  approveOperator,
+  revokeOperator,
+  depositFor,
+  withdrawFor,
+  initializeProtocolConfig,
+  updateProtocolConfig,
+  acceptProtocolAdmin,
+  collectFees,
+  startChunked,
+  processChunk,
+  cancelChunked,
 } from "./instructions";
 
 import { VaultIDL } from "../generated/idls";
+import { TxOptions, fetchTokenProgram } from "../utils";
 
 const DEFAULT_COMPUTE_UNITS = 450_000;
 
@@ -68,36 +127,188 @@ export class VaultClient {
     return deriveConditionalMint(vaultPda, vaultType, index, this.programId);
   }
 
+  deriveSetMint(vaultPda: PublicKey, vaultType: VaultType, mask: number): [PublicKey, number] {
+    return deriveSetMint(vaultPda, vaultType, mask, this.programId);
+  }
+
+  deriveDepositRecordPDA(vaultPda: PublicKey, user: PublicKey): [PublicKey, number] {
+    return deriveDepositRecordPDA(vaultPda, user, this.programId);
+  }
+
+  deriveOperatorApprovalPDA(
+    vaultPda: PublicKey,
+    owner: PublicKey,
+    operator: PublicKey
+  ): [PublicKey, number] {
+    return deriveOperatorApprovalPDA(vaultPda, owner, operator, this.programId);
+  }
+
+  deriveChunkProgressPDA(
+    vaultPda: PublicKey,
+    user: PublicKey,
+    vaultType: VaultType
+  ): [PublicKey, number] {
+    return deriveChunkProgressPDA(vaultPda, user, vaultType, this.programId);
+  }
+
+  deriveReceiptMint(vaultPda: PublicKey, vaultType: VaultType): [PublicKey, number] {
+    return deriveReceiptMint(vaultPda, vaultType, this.programId);
+  }
+
+  deriveProtocolConfigPDA(): [PublicKey, number] {
+    return deriveProtocolConfigPDA(this.programId);
+  }
+
+  deriveOracleConfigPDA(vaultPda: PublicKey): [PublicKey, number] {
+    return deriveOracleConfigPDA(vaultPda, this.programId);
+  }
+
   /* State Fetching */
 
   async fetchVault(vaultPda: PublicKey): Promise<VaultAccount> {
     return fetchVaultAccount(this.program, vaultPda);
   }
 
-  async fetchUserATAs(vaultPda: PublicKey, user: PublicKey, vaultType: VaultType) {
+  // Allow-list status and cumulative deposits of `user`, null before their first deposit or approval
+  async fetchDepositRecord(vaultPda: PublicKey, user: PublicKey): Promise<DepositRecordAccount | null> {
+    const [depositRecord] = this.deriveDepositRecordPDA(vaultPda, user);
+    return this.program.account.depositRecord.fetchNullable(depositRecord);
+  }
+
+  // Layout version the vault is stored under; fetchVault only decodes VAULT_VERSION
+  async fetchVaultVersion(vaultPda: PublicKey): Promise<number> {
+    const info = await this.program.provider.connection.getAccountInfo(vaultPda);
+    if (!info) {
+      throw new Error("Vault not found");
+    }
+    return info.data[8];
+  }
+
+  // Program-wide fee configuration, null until initialized
+  async fetchProtocolConfig(): Promise<ProtocolConfigAccount | null> {
+    const [protocolConfig] = this.deriveProtocolConfigPDA();
+    return this.program.account.protocolConfig.fetchNullable(protocolConfig);
+  }
+
+  // Vault events emitted by a confirmed transaction, in log order
+  async fetchEvents(signature: string): Promise<VaultEvent[]> {
+    const tx = await this.program.provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    if (!tx) {
+      throw new Error(`Transaction ${signature} not found`);
+    }
+    const parser = new EventParser(this.programId, this.program.coder);
+    return [...parser.parseLogs(tx.meta?.logMessages ?? [])] as VaultEvent[];
+  }
+
+  // Resolution rule of an oracle-resolved vault, null for owner-finalized vaults
+  async fetchOracleConfig(vaultPda: PublicKey): Promise<OracleConfigAccount | null> {
+    const [oracleConfig] = this.deriveOracleConfigPDA(vaultPda);
+    return this.program.account.oracleConfig.fetchNullable(oracleConfig);
+  }
+
+  // In-flight chunked deposit/withdrawal of `user`, null if none
+  async fetchChunkProgress(
+    vaultPda: PublicKey,
+    user: PublicKey,
+    vaultType: VaultType
+  ): Promise<ChunkProgressAccount | null> {
+    const [chunkProgress] = this.deriveChunkProgressPDA(vaultPda, user, vaultType);
+    return this.program.account.chunkProgress.fetchNullable(chunkProgress);
+  }
+
+  /*
+   * Mint, token program and conditional mints of one side of a vault.
+   * Conditional mints live under `vault.conditionalTokenProgram` (Token-2022 for new vaults).
+   */
+  async fetchVaultSide(vaultPda: PublicKey, vaultType: VaultType) {
     const vault = await this.fetchVault(vaultPda);
     const mint = vaultType === VaultType.Base ? vault.baseMint.address : vault.quoteMint.address;
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, mint);
     const condMints = (vaultType === VaultType.Base ? vault.condBaseMints : vault.condQuoteMints)
       .slice(0, vault.numOptions);
     return {
-      userAta: getAssociatedTokenAddressSync(mint, user),
-      userCondATAs: condMints.map((m) => getAssociatedTokenAddressSync(m, user)),
+      vault,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram: vault.conditionalTokenProgram,
+      condMints,
     };
   }
 
-  async fetchVaultATA(vaultPda: PublicKey, vaultType: VaultType) {
+  /*
+   * Mints, token programs and conditional mints of both sides of a vault,
+   * for instructions acting on base and quote at once.
+   */
+  async fetchVaultSides(vaultPda: PublicKey) {
     const vault = await this.fetchVault(vaultPda);
-    const mint = vaultType === VaultType.Base ? vault.baseMint.address : vault.quoteMint.address;
-    return getAssociatedTokenAddressSync(mint, vaultPda, true);
+    const connection = this.program.provider.connection;
+    const baseMint = vault.baseMint.address;
+    const quoteMint = vault.quoteMint.address;
+    const [baseTokenProgram, quoteTokenProgram] = await Promise.all([
+      fetchTokenProgram(connection, baseMint),
+      fetchTokenProgram(connection, quoteMint),
+    ]);
+    return {
+      vault,
+      baseMint,
+      quoteMint,
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram: vault.conditionalTokenProgram,
+      condBaseMints: vault.condBaseMints.slice(0, vault.numOptions),
+      condQuoteMints: vault.condQuoteMints.slice(0, vault.numOptions),
+    };
+  }
+
+  /*
+   * OR-position and receipt mints created by merge / wrap (VaultAccount.extraMints of them):
+   * the vault's Token-2022 mints other than its conditional mints.
+   */
+  async fetchExtraMints(vaultPda: PublicKey): Promise<PublicKey[]> {
+    const { vault, conditionalTokenProgram, condBaseMints, condQuoteMints } =
+      await this.fetchVaultSides(vaultPda);
+    if (vault.extraMints === 0) {
+      return [];
+    }
+    // Mint layout: mint_authority option tag (4) | mint_authority (32)
+    const accounts = await this.program.provider.connection.getProgramAccounts(
+      conditionalTokenProgram,
+      {
+        dataSlice: { offset: 0, length: 0 },
+        filters: [{ memcmp: { offset: 4, bytes: vaultPda.toBase58() } }],
+      }
+    );
+    const condMints = new Set([...condBaseMints, ...condQuoteMints].map((m) => m.toBase58()));
+    return accounts.map((a) => a.pubkey).filter((m) => !condMints.has(m.toBase58()));
+  }
+
+  async fetchUserATAs(vaultPda: PublicKey, user: PublicKey, vaultType: VaultType) {
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    return {
+      userAta: getAssociatedTokenAddressSync(mint, user, false, tokenProgram),
+      userCondATAs: condMints.map((m) =>
+        getAssociatedTokenAddressSync(m, user, false, conditionalTokenProgram)
+      ),
+    };
+  }
+
+  async fetchVaultATA(vaultPda: PublicKey, vaultType: VaultType) {
+    const { mint, tokenProgram } = await this.fetchVaultSide(vaultPda, vaultType);
+    return getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram);
   }
 
   async fetchUserBalances(vaultPda: PublicKey, user: PublicKey, vaultType: VaultType) {
-    const { userAta, userCondATAs } = await this.fetchUserATAs(vaultPda, user, vaultType);
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
     const connection = this.program.provider.connection;
 
-    const getBalanceSafe = async (ata: PublicKey) => {
+    const getBalanceSafe = async (ata: PublicKey, programId: PublicKey) => {
       try {
-        const acc = await getAccount(connection, ata);
+        const acc = await getAccount(connection, ata, undefined, programId);
         return new BN(acc.amount.toString());
       } catch (e) {
         if (e instanceof TokenAccountNotFoundError) {
@@ -108,17 +319,23 @@ export class VaultClient {
     };
 
     const [userBalance, ...condBalances] = await Promise.all([
-      getBalanceSafe(userAta),
-      ...userCondATAs.map(getBalanceSafe),
+      getBalanceSafe(getAssociatedTokenAddressSync(mint, user, false, tokenProgram), tokenProgram),
+      ...condMints.map((m) =>
+        getBalanceSafe(
+          getAssociatedTokenAddressSync(m, user, false, conditionalTokenProgram),
+          conditionalTokenProgram
+        )
+      ),
     ]);
 
     return { userBalance, condBalances };
   }
 
   async fetchVaultBalance(vaultPda: PublicKey, vaultType: VaultType): Promise<BN> {
-    const vaultAta = await this.fetchVaultATA(vaultPda, vaultType);
+    const { mint, tokenProgram } = await this.fetchVaultSide(vaultPda, vaultType);
+    const vaultAta = getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram);
     try {
-      const acc = await getAccount(this.program.provider.connection, vaultAta);
+      const acc = await getAccount(this.program.provider.connection, vaultAta, undefined, tokenProgram);
       return new BN(acc.amount.toString());
     } catch (e) {
       if (e instanceof TokenAccountNotFoundError) {
@@ -135,7 +352,8 @@ export class VaultClient {
     baseMint: PublicKey,
     quoteMint: PublicKey,
     nonce: number,
-    owner?: PublicKey
+    owner?: PublicKey,
+    options: InitializeVaultOptions = {}
   ) {
     const vaultOwner = owner ?? payer;
     const [vaultPda] = this.deriveVaultPDA(vaultOwner, nonce);
@@ -155,7 +373,13 @@ export class VaultClient {
       condBaseMint1,
       condQuoteMint0,
       condQuoteMint1,
-      nonce
+      nonce,
+      options.baseSymbol ?? null,
+      options.quoteSymbol ?? null,
+      options.optionLabels ?? ["", ""],
+      options.permanentDelegate ?? false,
+      options.baseTokenProgram ?? TOKEN_PROGRAM_ID,
+      options.quoteTokenProgram ?? TOKEN_PROGRAM_ID
     );
 
     return {
@@ -168,7 +392,13 @@ export class VaultClient {
     };
   }
 
-  async addOption(payer: PublicKey, owner: PublicKey, vaultPda: PublicKey) {
+  async addOption(
+    payer: PublicKey,
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    uri: string = "",
+    label: string = ""
+  ) {
     const vault = await this.fetchVault(vaultPda);
     const [condBaseMint] = this.deriveConditionalMint(vaultPda, VaultType.Base, vault.numOptions);
     const [condQuoteMint] = this.deriveConditionalMint(vaultPda, VaultType.Quote, vault.numOptions);
@@ -179,12 +409,72 @@ export class VaultClient {
       owner,
       vaultPda,
       condBaseMint,
-      condQuoteMint
+      condQuoteMint,
+      uri,
+      label
     );
 
     return { builder, condBaseMint, condQuoteMint };
   }
 
+  // Unix timestamp after which anyone can void the vault (null to clear), set during Setup
+  setResolutionDeadline(
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    resolutionDeadline: BN | number | null
+  ) {
+    return setResolutionDeadline(this.program, owner, vaultPda, resolutionDeadline);
+  }
+
+  // Deposit caps and allow-list, set during Setup; omitted fields are cleared
+  setDepositLimits(owner: PublicKey, vaultPda: PublicKey, limits: DepositLimits) {
+    return setDepositLimits(
+      this.program,
+      owner,
+      vaultPda,
+      limits.baseCaps ?? {},
+      limits.quoteCaps ?? {},
+      limits.allowList ?? false
+    );
+  }
+
+  // Add `user` to (or remove from) an allow-listed vault; allowed during Setup and Active
+  allowDepositor(
+    payer: PublicKey,
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    user: PublicKey,
+    allowed: boolean = true
+  ) {
+    const [depositRecord] = this.deriveDepositRecordPDA(vaultPda, user);
+    return allowDepositor(this.program, payer, owner, vaultPda, user, depositRecord, allowed);
+  }
+
+  /*
+   * Resolve from an amm pool's TWAP instead of the owner, set during Setup.
+   * Option i wins when thresholds[i - 1] <= TWAP < thresholds[i] (N - 1 ascending thresholds).
+   */
+  configureOracle(
+    payer: PublicKey,
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    pool: PublicKey,
+    resolveAt: BN | number,
+    thresholds: (BN | bigint | number)[]
+  ) {
+    const [oracleConfig] = this.deriveOracleConfigPDA(vaultPda);
+    return configureOracle(
+      this.program,
+      payer,
+      owner,
+      vaultPda,
+      pool,
+      oracleConfig,
+      resolveAt,
+      thresholds.map((threshold) => (BN.isBN(threshold) ? threshold : new BN(threshold.toString())))
+    );
+  }
+
   activate(payer: PublicKey, owner: PublicKey, vaultPda: PublicKey) {
     return activate(this.program, payer, owner, vaultPda);
   }
@@ -196,21 +486,31 @@ export class VaultClient {
     amount: BN | number,
     options?: VaultActionOptions
   ) {
-    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits } = options ?? {};
+    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits, tokenAccounts } =
+      options ?? {};
 
-    const vault = await this.fetchVault(vaultPda);
-    const mint = vaultType === VaultType.Base ? vault.baseMint.address : vault.quoteMint.address;
-    const condMints = (vaultType === VaultType.Base ? vault.condBaseMints : vault.condQuoteMints)
-      .slice(0, vault.numOptions);
+    const { vault, mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+
+    // Vaults with an allow-list or per-user caps track depositors in a DepositRecord
+    const tracksDepositors =
+      vault.allowList || vault.baseCaps.perUser !== null || vault.quoteCaps.perUser !== null;
+    const depositRecord = tracksDepositors
+      ? this.deriveDepositRecordPDA(vaultPda, signer)[0]
+      : undefined;
 
     const builder = deposit(
       this.program,
       signer,
       vaultPda,
       mint,
+      tokenProgram,
+      conditionalTokenProgram,
       condMints,
       vaultType,
-      amount
+      amount,
+      depositRecord,
+      tokenAccounts
     );
 
     const preIxs: ReturnType<typeof ComputeBudgetProgram.setComputeUnitLimit>[] = [];
@@ -252,21 +552,23 @@ export class VaultClient {
     amount: BN | number,
     options?: VaultActionOptions
   ) {
-    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits } = options ?? {};
+    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits, tokenAccounts } =
+      options ?? {};
 
-    const vault = await this.fetchVault(vaultPda);
-    const mint = vaultType === VaultType.Base ? vault.baseMint.address : vault.quoteMint.address;
-    const condMints = (vaultType === VaultType.Base ? vault.condBaseMints : vault.condQuoteMints)
-      .slice(0, vault.numOptions);
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
 
     let builder = withdraw(
       this.program,
       signer,
       vaultPda,
       mint,
+      tokenProgram,
+      conditionalTokenProgram,
       condMints,
       vaultType,
-      amount
+      amount,
+      tokenAccounts
     );
 
     if (includeCuBudget) {
@@ -291,26 +593,177 @@ export class VaultClient {
     return finalize(this.program, payer, owner, vaultPda, winningIdx);
   }
 
+  /*
+   * Resolve with a payout weight per option (bps, summing to PAYOUT_WEIGHT_TOTAL).
+   * Conditional tokens then redeem pro-rata to their option's weight.
+   */
+  finalizeWeighted(owner: PublicKey, vaultPda: PublicKey, payoutWeights: number[]) {
+    return finalizeWeighted(this.program, owner, vaultPda, payoutWeights);
+  }
+
+  // Resolve as invalid: every conditional token redeems at 1/N of the underlying
+  voidVault(owner: PublicKey, vaultPda: PublicKey) {
+    return voidVault(this.program, owner, vaultPda);
+  }
+
+  /*
+   * Close a resolved, fully redeemed vault: conditional, OR-position and receipt mints,
+   * escrow ATAs and the vault account.
+   * Rent goes to `receiver`; leftover escrow is swept to the owner's ATAs unless dust accounts are given.
+   */
+  async closeVault(
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    receiver?: PublicKey,
+    dustAccounts?: { base: PublicKey; quote: PublicKey }
+  ) {
+    const {
+      baseMint,
+      quoteMint,
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram,
+      condBaseMints,
+      condQuoteMints,
+    } = await this.fetchVaultSides(vaultPda);
+    const extraMints = await this.fetchExtraMints(vaultPda);
+
+    return closeVault(
+      this.program,
+      owner,
+      receiver ?? owner,
+      vaultPda,
+      baseMint,
+      quoteMint,
+      dustAccounts?.base ?? getAssociatedTokenAddressSync(baseMint, owner, true, baseTokenProgram),
+      dustAccounts?.quote ?? getAssociatedTokenAddressSync(quoteMint, owner, true, quoteTokenProgram),
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram,
+      condBaseMints,
+      condQuoteMints,
+      extraMints
+    );
+  }
+
+  /*
+   * Permissionless: rewrite a vault stored under an older layout as the current VAULT_VERSION.
+   * Reads the mints from raw data, since older layouts don't decode as VaultAccount.
+   */
+  async migrate(payer: PublicKey, vaultPda: PublicKey) {
+    const info = await this.program.provider.connection.getAccountInfo(vaultPda);
+    if (!info) {
+      throw new Error("Vault not found");
+    }
+    // discriminator (8) | version (1) | bump (1) | owner (32) | base_mint (32 + 1) | quote_mint
+    const baseMint = new PublicKey(info.data.subarray(42, 74));
+    const quoteMint = new PublicKey(info.data.subarray(75, 107));
+
+    // Escrows are only read for v1 vaults, which only took SPL Token mints
+    return migrate(
+      this.program,
+      payer,
+      vaultPda,
+      getAssociatedTokenAddressSync(baseMint, vaultPda, true, TOKEN_PROGRAM_ID),
+      getAssociatedTokenAddressSync(quoteMint, vaultPda, true, TOKEN_PROGRAM_ID)
+    );
+  }
+
+  // Permissionless: finalizes an oracle-resolved vault from the pool's final snapshot, once it ceased at or after resolve_at
+  async resolve(vaultPda: PublicKey) {
+    const oracleConfig = await this.fetchOracleConfig(vaultPda);
+    if (!oracleConfig) {
+      throw new Error("Vault is not oracle-resolved");
+    }
+    return resolve(
+      this.program,
+      vaultPda,
+      this.deriveOracleConfigPDA(vaultPda)[0],
+      oracleConfig.pool
+    );
+  }
+
+  // Permissionless: voids an Active vault past its resolution deadline
+  expire(vaultPda: PublicKey) {
+    return expire(this.program, vaultPda);
+  }
+
   async redeemWinnings(
     signer: PublicKey,
     vaultPda: PublicKey,
     vaultType: VaultType,
     options?: VaultActionOptions
   ) {
-    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits } = options ?? {};
+    const { autoWrapUnwrap = true, includeCuBudget = true, computeUnits, tokenAccounts } =
+      options ?? {};
 
-    const vault = await this.fetchVault(vaultPda);
-    const mint = vaultType === VaultType.Base ? vault.baseMint.address : vault.quoteMint.address;
-    const condMints = (vaultType === VaultType.Base ? vault.condBaseMints : vault.condQuoteMints)
-      .slice(0, vault.numOptions);
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
 
     let builder = redeemWinnings(
       this.program,
       signer,
       vaultPda,
       mint,
+      tokenProgram,
+      conditionalTokenProgram,
       condMints,
-      vaultType
+      vaultType,
+      tokenAccounts
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    if (autoWrapUnwrap && mint.equals(NATIVE_MINT)) {
+      const wsolAta = getAssociatedTokenAddressSync(NATIVE_MINT, signer);
+      builder = builder.postInstructions([
+        createCloseAccountInstruction(wsolAta, signer, signer),
+      ]);
+    }
+
+    return builder;
+  }
+
+  /*
+   * Partial redemption: burns up to `amount` of each paying option (losing tokens in full)
+   * and keeps the conditional accounts open unless `closeAccounts` is set.
+   */
+  async redeem(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    options?: RedeemOptions
+  ) {
+    const {
+      autoWrapUnwrap = true,
+      includeCuBudget = true,
+      computeUnits,
+      tokenAccounts,
+      amount,
+      closeAccounts = false,
+    } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+
+    let builder = redeem(
+      this.program,
+      signer,
+      vaultPda,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      amount ?? null,
+      closeAccounts,
+      tokenAccounts
     );
 
     if (includeCuBudget) {
@@ -330,4 +783,657 @@ export class VaultClient {
 
     return builder;
   }
+
+  /*
+   * Permissionless: redeem every conditional token of several holders, paying their canonical ATAs.
+   * Needs a vault initialized with `permanentDelegate`.
+   */
+  async redeemFor(
+    caller: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    holders: PublicKey[],
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+
+    let builder = redeemFor(
+      this.program,
+      caller,
+      vaultPda,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      holders
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  /*
+   * Burn `amount` of every option in `mask` for `amount` of the set's OR-position token,
+   * which pays out if any of those options wins.
+   */
+  async mergeOptions(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    mask: number,
+    amount: BN | number
+  ) {
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [setMint] = this.deriveSetMint(vaultPda, vaultType, mask);
+
+    const builder = mergeOptions(
+      this.program,
+      signer,
+      vaultPda,
+      getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram),
+      setMint,
+      conditionalTokenProgram,
+      condMints.filter((_, i) => (mask >> i) & 1),
+      vaultType,
+      mask,
+      amount
+    );
+
+    return { builder, setMint };
+  }
+
+  // Inverse of mergeOptions: burn the OR-position, mint back each option in the set
+  async splitOptionSet(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    mask: number,
+    amount: BN | number
+  ) {
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [setMint] = this.deriveSetMint(vaultPda, vaultType, mask);
+
+    return splitOptionSet(
+      this.program,
+      signer,
+      vaultPda,
+      getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram),
+      setMint,
+      conditionalTokenProgram,
+      condMints.filter((_, i) => (mask >> i) & 1),
+      vaultType,
+      mask,
+      amount
+    );
+  }
+
+  // Redeem the whole OR-position of a resolved vault and close the user's set account
+  async redeemOptionSet(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    mask: number
+  ) {
+    const { mint, tokenProgram, conditionalTokenProgram } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [setMint] = this.deriveSetMint(vaultPda, vaultType, mask);
+
+    return redeemOptionSet(
+      this.program,
+      signer,
+      vaultPda,
+      mint,
+      setMint,
+      tokenProgram,
+      conditionalTokenProgram,
+      vaultType,
+      mask
+    );
+  }
+
+  // Escrow `amount` of every option as one transferable receipt token
+  async wrap(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    amount: BN | number,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [receiptMint] = this.deriveReceiptMint(vaultPda, vaultType);
+
+    let builder = wrap(
+      this.program,
+      signer,
+      vaultPda,
+      getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram),
+      receiptMint,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      amount
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return { builder, receiptMint };
+  }
+
+  // Inverse of wrap: burn receipts, mint back every option
+  async unwrap(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    amount: BN | number,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [receiptMint] = this.deriveReceiptMint(vaultPda, vaultType);
+
+    let builder = unwrap(
+      this.program,
+      signer,
+      vaultPda,
+      getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram),
+      receiptMint,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      amount
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  // Redeem receipts of a resolved vault for the underlying a complete set pays
+  async redeemReceipt(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    amount: BN | number
+  ) {
+    const { mint, tokenProgram, conditionalTokenProgram } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [receiptMint] = this.deriveReceiptMint(vaultPda, vaultType);
+
+    return redeemReceipt(
+      this.program,
+      signer,
+      vaultPda,
+      mint,
+      receiptMint,
+      tokenProgram,
+      conditionalTokenProgram,
+      vaultType,
+      amount
+    );
+  }
+
+  /*
+   * Link a Setup vault whose underlying is `parentOption` of the parent's `parentVaultType`
+   * conditional mints, enabling redeemNested once both vaults are resolved.
+   */
+  setParent(
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    parentVaultPda: PublicKey,
+    vaultType: VaultType,
+    parentVaultType: VaultType,
+    parentOption: number
+  ) {
+    return setParent(
+      this.program,
+      owner,
+      vaultPda,
+      parentVaultPda,
+      vaultType,
+      parentVaultType,
+      parentOption
+    );
+  }
+
+  // Redeem child winnings straight into the parent's underlying
+  async redeemNested(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { vault, mint: parentCondMint, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const link = vaultType === VaultType.Base ? vault.baseParent : vault.quoteParent;
+    if (!link) {
+      throw new Error("Vault has no parent for this vault type");
+    }
+
+    const parentVault = await this.fetchVault(link.vault);
+    const mint = "base" in link.vaultType
+      ? parentVault.baseMint.address
+      : parentVault.quoteMint.address;
+    const tokenProgram = await fetchTokenProgram(this.program.provider.connection, mint);
+
+    let builder = redeemNested(
+      this.program,
+      signer,
+      vaultPda,
+      link.vault,
+      parentCondMint,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints,
+      vaultType
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  // Deposit base and quote in one instruction; either amount may be zero
+  async depositBoth(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    baseAmount: BN | number,
+    quoteAmount: BN | number,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const sides = await this.fetchVaultSides(vaultPda);
+    const { vault } = sides;
+
+    const tracksDepositors =
+      vault.allowList || vault.baseCaps.perUser !== null || vault.quoteCaps.perUser !== null;
+    const depositRecord = tracksDepositors
+      ? this.deriveDepositRecordPDA(vaultPda, signer)[0]
+      : undefined;
+
+    let builder = depositBoth(
+      this.program,
+      signer,
+      vaultPda,
+      sides.baseMint,
+      sides.quoteMint,
+      sides.baseTokenProgram,
+      sides.quoteTokenProgram,
+      sides.conditionalTokenProgram,
+      sides.condBaseMints,
+      sides.condQuoteMints,
+      baseAmount,
+      quoteAmount,
+      depositRecord
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  // Withdraw base and quote in one instruction; either amount may be zero
+  async withdrawBoth(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    baseAmount: BN | number,
+    quoteAmount: BN | number,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const sides = await this.fetchVaultSides(vaultPda);
+
+    let builder = withdrawBoth(
+      this.program,
+      signer,
+      vaultPda,
+      sides.baseMint,
+      sides.quoteMint,
+      sides.baseTokenProgram,
+      sides.quoteTokenProgram,
+      sides.conditionalTokenProgram,
+      sides.condBaseMints,
+      sides.condQuoteMints,
+      baseAmount,
+      quoteAmount
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  /*
+   * Let `operator` deposit/withdraw for `owner` in this vault. The owner still grants token
+   * allowances to the returned approval PDA (SPL approve), which moves the funds.
+   */
+  approveOperator(payer: PublicKey, owner: PublicKey, vaultPda: PublicKey, operator: PublicKey) {
+    const [operatorApproval] = this.deriveOperatorApprovalPDA(vaultPda, owner, operator);
+    const builder = approveOperator(
+      this.program,
+      payer,
+      owner,
+      operator,
+      vaultPda,
+      operatorApproval
+    );
+    return { builder, operatorApproval };
+  }
+
+  // Close the approval, refunding its rent to the owner
+  revokeOperator(owner: PublicKey, vaultPda: PublicKey, operator: PublicKey) {
+    const [operatorApproval] = this.deriveOperatorApprovalPDA(vaultPda, owner, operator);
+    return revokeOperator(this.program, owner, operatorApproval);
+  }
+
+  // Deposit from the owner's account, minting conditional tokens to the owner
+  async depositFor(
+    signer: PublicKey,
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    amount: BN | number,
+    options?: OperatorActionOptions
+  ) {
+    const { includeCuBudget = true, computeUnits, asDelegate = false } = options ?? {};
+
+    const { vault, mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+
+    const tracksDepositors =
+      vault.allowList || vault.baseCaps.perUser !== null || vault.quoteCaps.perUser !== null;
+    const depositRecord = tracksDepositors
+      ? this.deriveDepositRecordPDA(vaultPda, owner)[0]
+      : undefined;
+
+    let builder = depositFor(
+      this.program,
+      signer,
+      owner,
+      vaultPda,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      amount,
+      asDelegate ? null : this.deriveOperatorApprovalPDA(vaultPda, owner, signer)[0],
+      depositRecord
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  // Burn the owner's conditional tokens, returning the underlying to the owner
+  async withdrawFor(
+    signer: PublicKey,
+    owner: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    amount: BN | number,
+    options?: OperatorActionOptions
+  ) {
+    const { includeCuBudget = true, computeUnits, asDelegate = false } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+
+    let builder = withdrawFor(
+      this.program,
+      signer,
+      owner,
+      vaultPda,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints,
+      vaultType,
+      amount,
+      asDelegate ? null : this.deriveOperatorApprovalPDA(vaultPda, owner, signer)[0]
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  /*
+   * Start a deposit or withdrawal of `amount` too wide for one transaction.
+   * Deposits pay in here; conditional tokens are then minted (or burned) over processChunk calls.
+   */
+  async startChunked(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    action: ChunkedAction,
+    amount: BN | number
+  ) {
+    const { vault, mint, tokenProgram } = await this.fetchVaultSide(vaultPda, vaultType);
+    const [chunkProgress] = this.deriveChunkProgressPDA(vaultPda, signer, vaultType);
+
+    const tracksDepositors =
+      vault.allowList || vault.baseCaps.perUser !== null || vault.quoteCaps.perUser !== null;
+    const depositRecord =
+      action === ChunkedAction.Deposit && tracksDepositors
+        ? this.deriveDepositRecordPDA(vaultPda, signer)[0]
+        : undefined;
+
+    const builder = startChunked(
+      this.program,
+      signer,
+      vaultPda,
+      chunkProgress,
+      mint,
+      tokenProgram,
+      vaultType,
+      action,
+      amount,
+      depositRecord
+    );
+
+    return { builder, chunkProgress };
+  }
+
+  // Mint or burn options startOption..startOption + numOptions of `user`'s chunked action
+  async processChunk(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    startOption: number,
+    numOptions: number,
+    user: PublicKey = signer,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [chunkProgress] = this.deriveChunkProgressPDA(vaultPda, user, vaultType);
+
+    let builder = processChunk(
+      this.program,
+      signer,
+      user,
+      vaultPda,
+      chunkProgress,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints.slice(startOption, startOption + numOptions),
+      vaultType,
+      startOption
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  // Roll back processed options of `user`'s chunked action; refunds once none remain
+  async cancelChunked(
+    signer: PublicKey,
+    vaultPda: PublicKey,
+    vaultType: VaultType,
+    startOption: number,
+    numOptions: number,
+    user: PublicKey = signer,
+    options?: TxOptions
+  ) {
+    const { includeCuBudget = true, computeUnits } = options ?? {};
+
+    const { mint, tokenProgram, conditionalTokenProgram, condMints } =
+      await this.fetchVaultSide(vaultPda, vaultType);
+    const [chunkProgress] = this.deriveChunkProgressPDA(vaultPda, user, vaultType);
+
+    let builder = cancelChunked(
+      this.program,
+      signer,
+      user,
+      vaultPda,
+      chunkProgress,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+      condMints.slice(startOption, startOption + numOptions),
+      vaultType,
+      startOption
+    );
+
+    if (includeCuBudget) {
+      builder = builder.preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({
+          units: computeUnits ?? this.computeUnits,
+        }),
+      ]);
+    }
+
+    return builder;
+  }
+
+  /* Protocol Fees */
+
+  // One-time setup, signed by the program's upgrade authority. Vaults copy the fee rates at initialize
+  initializeProtocolConfig(
+    payer: PublicKey,
+    authority: PublicKey,
+    admin: PublicKey,
+    feeRecipient: PublicKey,
+    feeRates: FeeRates
+  ) {
+    const [programData] = deriveProgramDataPDA(this.programId);
+    return initializeProtocolConfig(
+      this.program,
+      payer,
+      authority,
+      programData,
+      admin,
+      feeRecipient,
+      feeRates
+    );
+  }
+
+  // Rates only apply to vaults initialized afterwards; `pendingAdmin` starts a two-step handover
+  updateProtocolConfig(
+    admin: PublicKey,
+    feeRecipient: PublicKey,
+    feeRates: FeeRates,
+    pendingAdmin: PublicKey | null = null
+  ) {
+    return updateProtocolConfig(this.program, admin, pendingAdmin, feeRecipient, feeRates);
+  }
+
+  acceptProtocolAdmin(newAdmin: PublicKey) {
+    return acceptProtocolAdmin(this.program, newAdmin);
+  }
+
+  // Permissionless: sweep a vault's accrued fees to the protocol fee recipient
+  async collectFees(payer: PublicKey, vaultPda: PublicKey, vaultType: VaultType) {
+    const protocolConfig = await this.fetchProtocolConfig();
+    if (!protocolConfig) {
+      throw new Error("Protocol config not initialized");
+    }
+    const { mint, tokenProgram } = await this.fetchVaultSide(vaultPda, vaultType);
+
+    return collectFees(
+      this.program,
+      payer,
+      vaultPda,
+      protocolConfig.feeRecipient,
+      mint,
+      tokenProgram,
+      vaultType
+    );
+  }
 }
@@ -15,9 +15,21 @@ export const PROGRAM_ID = new PublicKey(VaultIDL.address);
 
 export const VAULT_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "VAULT_SEED"));
 export const CONDITIONAL_MINT_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "CONDITIONAL_MINT_SEED"));
+export const CONDITIONAL_SET_MINT_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "CONDITIONAL_SET_MINT_SEED"));
+export const RECEIPT_MINT_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "RECEIPT_MINT_SEED"));
+export const DEPOSIT_RECORD_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "DEPOSIT_RECORD_SEED"));
+export const OPERATOR_APPROVAL_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "OPERATOR_APPROVAL_SEED"));
+export const CHUNK_PROGRESS_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "CHUNK_PROGRESS_SEED"));
+export const ORACLE_CONFIG_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "ORACLE_CONFIG_SEED"));
+export const PROTOCOL_CONFIG_SEED = parseIdlBytes(getIdlConstant(VaultIDL, "PROTOCOL_CONFIG_SEED"));
 
 /* Numeric Constants */
 
 export const MAX_OPTIONS = Number(getIdlConstant(VaultIDL, "MAX_OPTIONS"));
 export const MIN_OPTIONS = Number(getIdlConstant(VaultIDL, "MIN_OPTIONS"));
 export const VAULT_VERSION = Number(getIdlConstant(VaultIDL, "VAULT_VERSION"));
+export const PAYOUT_WEIGHT_TOTAL = Number(getIdlConstant(VaultIDL, "PAYOUT_WEIGHT_TOTAL"));
+export const MAX_SYMBOL_LEN = Number(getIdlConstant(VaultIDL, "MAX_SYMBOL_LEN"));
+export const MAX_OPTION_LABEL_LEN = Number(getIdlConstant(VaultIDL, "MAX_OPTION_LABEL_LEN"));
+export const MAX_METADATA_URI_LEN = Number(getIdlConstant(VaultIDL, "MAX_METADATA_URI_LEN"));
+export const MAX_PROTOCOL_FEE = Number(getIdlConstant(VaultIDL, "MAX_PROTOCOL_FEE"));
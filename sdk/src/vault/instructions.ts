@@ -6,7 +6,14 @@
 import { Program, BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
-import { Vault, VaultType } from "./types";
+import {
+  Vault,
+  VaultType,
+  ChunkedAction,
+  DepositCaps,
+  UserTokenAccounts,
+  FeeRates,
+} from "./types";
 
 export function initialize(
   program: Program<Vault>,
@@ -19,19 +26,29 @@ export function initialize(
   condBaseMint1: PublicKey,
   condQuoteMint0: PublicKey,
   condQuoteMint1: PublicKey,
-  nonce: number
+  nonce: number,
+  baseSymbol: string | null,
+  quoteSymbol: string | null,
+  optionLabels: [string, string],
+  permanentDelegate: boolean,
+  baseTokenProgram: PublicKey,
+  quoteTokenProgram: PublicKey
 ) {
-  return program.methods.initialize(nonce).accountsPartial({
-    payer,
-    owner,
-    vault: vaultPda,
-    baseMint,
-    quoteMint,
-    condBaseMint0,
-    condBaseMint1,
-    condQuoteMint0,
-    condQuoteMint1,
-  });
+  return program.methods
+    .initialize(nonce, baseSymbol, quoteSymbol, optionLabels, permanentDelegate)
+    .accountsPartial({
+      payer,
+      owner,
+      vault: vaultPda,
+      baseMint,
+      quoteMint,
+      condBaseMint0,
+      condBaseMint1,
+      condQuoteMint0,
+      condQuoteMint1,
+      baseTokenProgram,
+      quoteTokenProgram,
+    });
 }
 
 export function addOption(
@@ -40,9 +57,11 @@ export function addOption(
   owner: PublicKey,
   vaultPda: PublicKey,
   condBaseMint: PublicKey,
-  condQuoteMint: PublicKey
+  condQuoteMint: PublicKey,
+  uri: string,
+  label: string
 ) {
-  return program.methods.addOption().accountsPartial({
+  return program.methods.addOption(uri, label).accountsPartial({
     payer,
     owner,
     vault: vaultPda,
@@ -51,6 +70,83 @@ export function addOption(
   });
 }
 
+export function setResolutionDeadline(
+  program: Program<Vault>,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  resolutionDeadline: BN | number | null
+) {
+  const deadlineBN =
+    typeof resolutionDeadline === "number" ? new BN(resolutionDeadline) : resolutionDeadline;
+
+  return program.methods.setResolutionDeadline(deadlineBN).accountsPartial({
+    owner,
+    vault: vaultPda,
+  });
+}
+
+export function setDepositLimits(
+  program: Program<Vault>,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  baseCaps: DepositCaps,
+  quoteCaps: DepositCaps,
+  allowList: boolean
+) {
+  const capsArg = (caps: DepositCaps) => ({
+    total: caps.total === undefined ? null : new BN(caps.total),
+    perUser: caps.perUser === undefined ? null : new BN(caps.perUser),
+  });
+
+  return program.methods
+    .setDepositLimits(capsArg(baseCaps), capsArg(quoteCaps), allowList)
+    .accountsPartial({
+      owner,
+      vault: vaultPda,
+    });
+}
+
+export function allowDepositor(
+  program: Program<Vault>,
+  payer: PublicKey,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  user: PublicKey,
+  depositRecord: PublicKey,
+  allowed: boolean
+) {
+  return program.methods.allowDepositor(allowed).accountsPartial({
+    payer,
+    owner,
+    vault: vaultPda,
+    user,
+    depositRecord,
+  });
+}
+
+export function configureOracle(
+  program: Program<Vault>,
+  payer: PublicKey,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  pool: PublicKey,
+  oracleConfig: PublicKey,
+  resolveAt: BN | number,
+  thresholds: BN[]
+) {
+  const resolveAtBN = typeof resolveAt === "number" ? new BN(resolveAt) : resolveAt;
+
+  return program.methods
+    .configureOracle(resolveAtBN, thresholds)
+    .accountsPartial({
+      payer,
+      owner,
+      vault: vaultPda,
+      pool,
+      oracleConfig,
+    });
+}
+
 export function activate(
   program: Program<Vault>,
   payer: PublicKey,
@@ -69,30 +165,39 @@ export function deposit(
   signer: PublicKey,
   vaultPda: PublicKey,
   mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
   condMints: PublicKey[],
   vaultType: VaultType,
-  amount: BN | number
+  amount: BN | number,
+  depositRecord?: PublicKey,
+  tokenAccounts: UserTokenAccounts = {}
 ) {
   const amountBN = typeof amount === "number" ? new BN(amount) : amount;
   const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
 
+  const remainingAccounts = condAccounts(
+    signer,
+    condMints,
+    conditionalTokenProgram,
+    tokenAccounts.userCondAtas
+  );
+  if (depositRecord) {
+    remainingAccounts.push({ pubkey: depositRecord, isSigner: false, isWritable: true });
+  }
+
   return program.methods
     .deposit(vaultTypeArg, amountBN)
     .accountsPartial({
       signer,
       vault: vaultPda,
       mint,
+      userAta:
+        tokenAccounts.userAta ?? getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
     })
-    .remainingAccounts(
-      condMints.flatMap((condMint) => [
-        { pubkey: condMint, isSigner: false, isWritable: true },
-        {
-          pubkey: getAssociatedTokenAddressSync(condMint, signer),
-          isSigner: false,
-          isWritable: true,
-        },
-      ])
-    );
+    .remainingAccounts(remainingAccounts);
 }
 
 export function withdraw(
@@ -100,9 +205,12 @@ export function withdraw(
   signer: PublicKey,
   vaultPda: PublicKey,
   mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
   condMints: PublicKey[],
   vaultType: VaultType,
-  amount: BN | number
+  amount: BN | number,
+  tokenAccounts: UserTokenAccounts = {}
 ) {
   const amountBN = typeof amount === "number" ? new BN(amount) : amount;
   const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
@@ -113,16 +221,13 @@ export function withdraw(
       signer,
       vault: vaultPda,
       mint,
+      userAta:
+        tokenAccounts.userAta ?? getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
     })
     .remainingAccounts(
-      condMints.flatMap((condMint) => [
-        { pubkey: condMint, isSigner: false, isWritable: true },
-        {
-          pubkey: getAssociatedTokenAddressSync(condMint, signer),
-          isSigner: false,
-          isWritable: true,
-        },
-      ])
+      condAccounts(signer, condMints, conditionalTokenProgram, tokenAccounts.userCondAtas)
     );
 }
 
@@ -140,13 +245,115 @@ export function finalize(
   });
 }
 
+export function finalizeWeighted(
+  program: Program<Vault>,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  payoutWeights: number[]
+) {
+  return program.methods.finalizeWeighted(payoutWeights).accountsPartial({
+    owner,
+    vault: vaultPda,
+  });
+}
+
+export function voidVault(
+  program: Program<Vault>,
+  owner: PublicKey,
+  vaultPda: PublicKey
+) {
+  return program.methods.void().accountsPartial({
+    owner,
+    vault: vaultPda,
+  });
+}
+
+export function migrate(
+  program: Program<Vault>,
+  payer: PublicKey,
+  vaultPda: PublicKey,
+  baseEscrow: PublicKey,
+  quoteEscrow: PublicKey
+) {
+  return program.methods.migrate().accountsPartial({
+    payer,
+    vault: vaultPda,
+    baseEscrow,
+    quoteEscrow,
+  });
+}
+
+export function closeVault(
+  program: Program<Vault>,
+  owner: PublicKey,
+  receiver: PublicKey,
+  vaultPda: PublicKey,
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  baseDustAccount: PublicKey,
+  quoteDustAccount: PublicKey,
+  baseTokenProgram: PublicKey,
+  quoteTokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condBaseMints: PublicKey[],
+  condQuoteMints: PublicKey[],
+  extraMints: PublicKey[] = []
+) {
+  // Remaining accounts: every conditional base mint, every conditional quote mint,
+  // then every OR-position and receipt mint ascending by key
+  const sortedExtraMints = [...extraMints].sort((a, b) => a.toBuffer().compare(b.toBuffer()));
+  const remainingAccounts = [...condBaseMints, ...condQuoteMints, ...sortedExtraMints].map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: true,
+  }));
+
+  return program.methods
+    .closeVault()
+    .accountsPartial({
+      owner,
+      receiver,
+      vault: vaultPda,
+      baseMint,
+      quoteMint,
+      baseDustAccount,
+      quoteDustAccount,
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(remainingAccounts);
+}
+
+export function resolve(
+  program: Program<Vault>,
+  vaultPda: PublicKey,
+  oracleConfig: PublicKey,
+  pool: PublicKey
+) {
+  return program.methods.resolve().accountsPartial({
+    vault: vaultPda,
+    oracleConfig,
+    pool,
+  });
+}
+
+export function expire(program: Program<Vault>, vaultPda: PublicKey) {
+  return program.methods.expire().accountsPartial({
+    vault: vaultPda,
+  });
+}
+
 export function redeemWinnings(
   program: Program<Vault>,
   signer: PublicKey,
   vaultPda: PublicKey,
   mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
   condMints: PublicKey[],
-  vaultType: VaultType
+  vaultType: VaultType,
+  tokenAccounts: UserTokenAccounts = {}
 ) {
   const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
 
@@ -156,15 +363,672 @@ export function redeemWinnings(
       signer,
       vault: vaultPda,
       mint,
+      userAta:
+        tokenAccounts.userAta ?? getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(
+      condAccounts(signer, condMints, conditionalTokenProgram, tokenAccounts.userCondAtas)
+    );
+}
+
+export function redeem(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  amount: BN | number | null,
+  closeAccounts: boolean,
+  tokenAccounts: UserTokenAccounts = {}
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+
+  return program.methods
+    .redeem(vaultTypeArg, amountBN, closeAccounts)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      mint,
+      userAta:
+        tokenAccounts.userAta ?? getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
     })
     .remainingAccounts(
-      condMints.flatMap((condMint) => [
-        { pubkey: condMint, isSigner: false, isWritable: true },
-        {
-          pubkey: getAssociatedTokenAddressSync(condMint, signer),
-          isSigner: false,
-          isWritable: true,
-        },
-      ])
+      condAccounts(signer, condMints, conditionalTokenProgram, tokenAccounts.userCondAtas)
     );
 }
+
+export function redeemFor(
+  program: Program<Vault>,
+  caller: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  holders: PublicKey[]
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  // Remaining accounts: every conditional mint, then per holder [holder, holder_ata, holder_cond_ata_i...]
+  const remainingAccounts = [
+    ...condMints.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ...holders.flatMap((holder) => [
+      { pubkey: holder, isSigner: false, isWritable: false },
+      {
+        pubkey: getAssociatedTokenAddressSync(mint, holder, false, tokenProgram),
+        isSigner: false,
+        isWritable: true,
+      },
+      ...condMints.map((condMint) => ({
+        pubkey: getAssociatedTokenAddressSync(condMint, holder, false, conditionalTokenProgram),
+        isSigner: false,
+        isWritable: true,
+      })),
+    ]),
+  ];
+
+  return program.methods
+    .redeemFor(vaultTypeArg)
+    .accountsPartial({
+      caller,
+      vault: vaultPda,
+      mint,
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(remainingAccounts);
+}
+
+export function mergeOptions(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  vaultAta: PublicKey,
+  setMint: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  setCondMints: PublicKey[],
+  vaultType: VaultType,
+  mask: number,
+  amount: BN | number
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .mergeOptions(vaultTypeArg, new BN(mask), amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      vaultAta,
+      setMint,
+      userSetAta: getAssociatedTokenAddressSync(setMint, signer, false, conditionalTokenProgram),
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(signer, setCondMints, conditionalTokenProgram));
+}
+
+export function splitOptionSet(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  vaultAta: PublicKey,
+  setMint: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  setCondMints: PublicKey[],
+  vaultType: VaultType,
+  mask: number,
+  amount: BN | number
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .splitOptionSet(vaultTypeArg, new BN(mask), amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      vaultAta,
+      setMint,
+      userSetAta: getAssociatedTokenAddressSync(setMint, signer, false, conditionalTokenProgram),
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(signer, setCondMints, conditionalTokenProgram));
+}
+
+export function redeemOptionSet(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  setMint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  vaultType: VaultType,
+  mask: number
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .redeemOptionSet(vaultTypeArg, new BN(mask))
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      mint,
+      userAta: getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      setMint,
+      userSetAta: getAssociatedTokenAddressSync(setMint, signer, false, conditionalTokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    });
+}
+
+export function wrap(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  vaultAta: PublicKey,
+  receiptMint: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  amount: BN | number
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .wrap(vaultTypeArg, amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      vaultAta,
+      receiptMint,
+      userReceiptAta: getAssociatedTokenAddressSync(receiptMint, signer, false, conditionalTokenProgram),
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(signer, condMints, conditionalTokenProgram));
+}
+
+export function unwrap(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  vaultAta: PublicKey,
+  receiptMint: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  amount: BN | number
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .unwrap(vaultTypeArg, amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      vaultAta,
+      receiptMint,
+      userReceiptAta: getAssociatedTokenAddressSync(receiptMint, signer, false, conditionalTokenProgram),
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(signer, condMints, conditionalTokenProgram));
+}
+
+export function redeemReceipt(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  receiptMint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  vaultType: VaultType,
+  amount: BN | number
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .redeemReceipt(vaultTypeArg, amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      mint,
+      userAta: getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      receiptMint,
+      userReceiptAta: getAssociatedTokenAddressSync(receiptMint, signer, false, conditionalTokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    });
+}
+
+export function setParent(
+  program: Program<Vault>,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  parentVaultPda: PublicKey,
+  vaultType: VaultType,
+  parentVaultType: VaultType,
+  parentOption: number
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+  const parentVaultTypeArg = parentVaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .setParent(vaultTypeArg, parentVaultTypeArg, parentOption)
+    .accountsPartial({
+      owner,
+      vault: vaultPda,
+      parentVault: parentVaultPda,
+    });
+}
+
+export function redeemNested(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  parentVaultPda: PublicKey,
+  parentCondMint: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .redeemNested(vaultTypeArg)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      parentVault: parentVaultPda,
+      parentCondMint,
+      vaultAta: getAssociatedTokenAddressSync(parentCondMint, vaultPda, true, conditionalTokenProgram),
+      mint,
+      parentVaultAta: getAssociatedTokenAddressSync(mint, parentVaultPda, true, tokenProgram),
+      userAta: getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(signer, condMints, conditionalTokenProgram));
+}
+
+export function depositBoth(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  baseTokenProgram: PublicKey,
+  quoteTokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condBaseMints: PublicKey[],
+  condQuoteMints: PublicKey[],
+  baseAmount: BN | number,
+  quoteAmount: BN | number,
+  depositRecord?: PublicKey
+) {
+  const baseAmountBN = typeof baseAmount === "number" ? new BN(baseAmount) : baseAmount;
+  const quoteAmountBN = typeof quoteAmount === "number" ? new BN(quoteAmount) : quoteAmount;
+
+  const remainingAccounts = dualCondAccounts(
+    signer,
+    condBaseMints,
+    condQuoteMints,
+    conditionalTokenProgram
+  );
+  if (depositRecord) {
+    remainingAccounts.push({ pubkey: depositRecord, isSigner: false, isWritable: true });
+  }
+
+  return program.methods
+    .depositBoth(baseAmountBN, quoteAmountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      baseMint,
+      quoteMint,
+      userBaseAta: getAssociatedTokenAddressSync(baseMint, signer, false, baseTokenProgram),
+      userQuoteAta: getAssociatedTokenAddressSync(quoteMint, signer, false, quoteTokenProgram),
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(remainingAccounts);
+}
+
+export function withdrawBoth(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  baseTokenProgram: PublicKey,
+  quoteTokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condBaseMints: PublicKey[],
+  condQuoteMints: PublicKey[],
+  baseAmount: BN | number,
+  quoteAmount: BN | number
+) {
+  const baseAmountBN = typeof baseAmount === "number" ? new BN(baseAmount) : baseAmount;
+  const quoteAmountBN = typeof quoteAmount === "number" ? new BN(quoteAmount) : quoteAmount;
+
+  return program.methods
+    .withdrawBoth(baseAmountBN, quoteAmountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      baseMint,
+      quoteMint,
+      userBaseAta: getAssociatedTokenAddressSync(baseMint, signer, false, baseTokenProgram),
+      userQuoteAta: getAssociatedTokenAddressSync(quoteMint, signer, false, quoteTokenProgram),
+      baseTokenProgram,
+      quoteTokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(
+      dualCondAccounts(signer, condBaseMints, condQuoteMints, conditionalTokenProgram)
+    );
+}
+
+export function approveOperator(
+  program: Program<Vault>,
+  payer: PublicKey,
+  owner: PublicKey,
+  operator: PublicKey,
+  vaultPda: PublicKey,
+  operatorApproval: PublicKey
+) {
+  return program.methods.approveOperator().accountsPartial({
+    payer,
+    owner,
+    operator,
+    vault: vaultPda,
+    operatorApproval,
+  });
+}
+
+export function revokeOperator(
+  program: Program<Vault>,
+  owner: PublicKey,
+  operatorApproval: PublicKey
+) {
+  return program.methods.revokeOperator().accountsPartial({
+    owner,
+    operatorApproval,
+  });
+}
+
+export function depositFor(
+  program: Program<Vault>,
+  signer: PublicKey,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  amount: BN | number,
+  operatorApproval: PublicKey | null,
+  depositRecord?: PublicKey
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  const remainingAccounts = condAccounts(owner, condMints, conditionalTokenProgram);
+  if (depositRecord) {
+    remainingAccounts.push({ pubkey: depositRecord, isSigner: false, isWritable: true });
+  }
+
+  return program.methods
+    .depositFor(vaultTypeArg, amountBN)
+    .accountsPartial({
+      signer,
+      owner,
+      operatorApproval,
+      vault: vaultPda,
+      mint,
+      ownerAta: getAssociatedTokenAddressSync(mint, owner, true, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(remainingAccounts);
+}
+
+export function withdrawFor(
+  program: Program<Vault>,
+  signer: PublicKey,
+  owner: PublicKey,
+  vaultPda: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  condMints: PublicKey[],
+  vaultType: VaultType,
+  amount: BN | number,
+  operatorApproval: PublicKey | null
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .withdrawFor(vaultTypeArg, amountBN)
+    .accountsPartial({
+      signer,
+      owner,
+      operatorApproval,
+      vault: vaultPda,
+      mint,
+      ownerAta: getAssociatedTokenAddressSync(mint, owner, true, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(owner, condMints, conditionalTokenProgram));
+}
+
+export function startChunked(
+  program: Program<Vault>,
+  signer: PublicKey,
+  vaultPda: PublicKey,
+  chunkProgress: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  vaultType: VaultType,
+  action: ChunkedAction,
+  amount: BN | number,
+  depositRecord?: PublicKey
+) {
+  const amountBN = typeof amount === "number" ? new BN(amount) : amount;
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+  const actionArg = action === ChunkedAction.Deposit ? { deposit: {} } : { withdraw: {} };
+
+  return program.methods
+    .startChunked(vaultTypeArg, actionArg, amountBN)
+    .accountsPartial({
+      signer,
+      vault: vaultPda,
+      chunkProgress,
+      mint,
+      userAta: getAssociatedTokenAddressSync(mint, signer, false, tokenProgram),
+      tokenProgram,
+    })
+    .remainingAccounts(
+      depositRecord ? [{ pubkey: depositRecord, isSigner: false, isWritable: true }] : []
+    );
+}
+
+export function processChunk(
+  program: Program<Vault>,
+  signer: PublicKey,
+  user: PublicKey,
+  vaultPda: PublicKey,
+  chunkProgress: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  chunkCondMints: PublicKey[],
+  vaultType: VaultType,
+  startOption: number
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .processChunk(vaultTypeArg, startOption)
+    .accountsPartial({
+      signer,
+      user,
+      vault: vaultPda,
+      chunkProgress,
+      mint,
+      userAta: getAssociatedTokenAddressSync(mint, user, true, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(user, chunkCondMints, conditionalTokenProgram));
+}
+
+export function cancelChunked(
+  program: Program<Vault>,
+  signer: PublicKey,
+  user: PublicKey,
+  vaultPda: PublicKey,
+  chunkProgress: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  conditionalTokenProgram: PublicKey,
+  chunkCondMints: PublicKey[],
+  vaultType: VaultType,
+  startOption: number
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .cancelChunked(vaultTypeArg, startOption)
+    .accountsPartial({
+      signer,
+      user,
+      vault: vaultPda,
+      chunkProgress,
+      mint,
+      userAta: getAssociatedTokenAddressSync(mint, user, true, tokenProgram),
+      tokenProgram,
+      conditionalTokenProgram,
+    })
+    .remainingAccounts(condAccounts(user, chunkCondMints, conditionalTokenProgram));
+}
+
+export function initializeProtocolConfig(
+  program: Program<Vault>,
+  payer: PublicKey,
+  authority: PublicKey,
+  programData: PublicKey,
+  admin: PublicKey,
+  feeRecipient: PublicKey,
+  feeRates: FeeRates
+) {
+  return program.methods
+    .initializeProtocolConfig(admin, feeRecipient, feeRates)
+    .accountsPartial({
+      payer,
+      authority,
+      programData,
+    });
+}
+
+export function updateProtocolConfig(
+  program: Program<Vault>,
+  admin: PublicKey,
+  pendingAdmin: PublicKey | null,
+  feeRecipient: PublicKey,
+  feeRates: FeeRates
+) {
+  return program.methods
+    .updateProtocolConfig(pendingAdmin, feeRecipient, feeRates)
+    .accountsPartial({
+      admin,
+    });
+}
+
+export function acceptProtocolAdmin(program: Program<Vault>, newAdmin: PublicKey) {
+  return program.methods.acceptProtocolAdmin().accountsPartial({
+    newAdmin,
+  });
+}
+
+export function collectFees(
+  program: Program<Vault>,
+  payer: PublicKey,
+  vaultPda: PublicKey,
+  feeRecipient: PublicKey,
+  mint: PublicKey,
+  tokenProgram: PublicKey,
+  vaultType: VaultType
+) {
+  const vaultTypeArg = vaultType === VaultType.Base ? { base: {} } : { quote: {} };
+
+  return program.methods
+    .collectFees(vaultTypeArg)
+    .accountsPartial({
+      payer,
+      vault: vaultPda,
+      feeRecipient,
+      mint,
+      vaultAta: getAssociatedTokenAddressSync(mint, vaultPda, true, tokenProgram),
+      feeRecipientAta: getAssociatedTokenAddressSync(mint, feeRecipient, true, tokenProgram),
+      tokenProgram,
+    });
+}
+
+// [cond_mint_i, user_cond_ata_i] pairs, as user actions expect in remaining accounts
+// (`userCondAtas` overrides the user's ATAs with any of their conditional token accounts).
+// The user may be off-curve: operators act for PDA owners such as multisig vaults
+export function condAccounts(
+  user: PublicKey,
+  condMints: PublicKey[],
+  conditionalTokenProgram: PublicKey,
+  userCondAtas?: PublicKey[]
+) {
+  return condMints.flatMap((condMint, i) => [
+    { pubkey: condMint, isSigner: false, isWritable: true },
+    {
+      pubkey:
+        userCondAtas?.[i] ??
+        getAssociatedTokenAddressSync(condMint, user, true, conditionalTokenProgram),
+      isSigner: false,
+      isWritable: true,
+    },
+  ]);
+}
+
+// Every conditional mint (base then quote), then the user's conditional ATAs in the same order,
+// as deposit_both / withdraw_both expect in remaining accounts
+export function dualCondAccounts(
+  user: PublicKey,
+  condBaseMints: PublicKey[],
+  condQuoteMints: PublicKey[],
+  conditionalTokenProgram: PublicKey
+) {
+  const condMints = [...condBaseMints, ...condQuoteMints];
+  return [
+    ...condMints.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ...condMints.map((condMint) => ({
+      pubkey: getAssociatedTokenAddressSync(condMint, user, false, conditionalTokenProgram),
+      isSigner: false,
+      isWritable: true,
+    })),
+  ];
+}
@@ -3,7 +3,8 @@
  * Exports IDL-derived types and SDK-friendly enums.
  */
 
-import { IdlAccounts, IdlEvents, IdlTypes } from "@coral-xyz/anchor";
+import { BN, IdlAccounts, IdlEvents, IdlTypes } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
 import { TxOptions } from "../utils";
 
 export { Vault } from "../generated/types";
@@ -12,16 +13,53 @@ import type { Vault } from "../generated/types";
 /* IDL-derived Types */
 
 export type VaultAccount = IdlAccounts<Vault>["vaultAccount"];
+export type ChunkProgressAccount = IdlAccounts<Vault>["chunkProgress"];
+export type DepositRecordAccount = IdlAccounts<Vault>["depositRecord"];
+export type OracleConfigAccount = IdlAccounts<Vault>["oracleConfig"];
+export type ProtocolConfigAccount = IdlAccounts<Vault>["protocolConfig"];
 export type VaultStateRaw = IdlTypes<Vault>["vaultState"];
 export type VaultTypeRaw = IdlTypes<Vault>["vaultType"];
+export type FeeRates = IdlTypes<Vault>["feeRates"]; // Basis points, up to MAX_PROTOCOL_FEE
 
 export type VaultInitializedEvent = IdlEvents<Vault>["vaultInitialized"];
 export type VaultActivatedEvent = IdlEvents<Vault>["vaultActivated"];
 export type VaultDepositEvent = IdlEvents<Vault>["vaultDeposit"];
 export type VaultWithdrawalEvent = IdlEvents<Vault>["vaultWithdrawal"];
 export type VaultFinalizedEvent = IdlEvents<Vault>["vaultFinalized"];
+export type VaultFinalizedWeightedEvent = IdlEvents<Vault>["vaultFinalizedWeighted"];
+export type VaultVoidedEvent = IdlEvents<Vault>["vaultVoided"];
+export type VaultExpiredEvent = IdlEvents<Vault>["vaultExpired"];
+export type VaultClosedEvent = IdlEvents<Vault>["vaultClosed"];
+export type VaultMigratedEvent = IdlEvents<Vault>["vaultMigrated"];
+export type ReceiptWrappedEvent = IdlEvents<Vault>["receiptWrapped"];
+export type ReceiptUnwrappedEvent = IdlEvents<Vault>["receiptUnwrapped"];
+export type ReceiptRedeemedEvent = IdlEvents<Vault>["receiptRedeemed"];
+export type ResolutionDeadlineSetEvent = IdlEvents<Vault>["resolutionDeadlineSet"];
 export type OptionAddedEvent = IdlEvents<Vault>["optionAdded"];
+export type OptionsMergedEvent = IdlEvents<Vault>["optionsMerged"];
+export type OptionSetSplitEvent = IdlEvents<Vault>["optionSetSplit"];
+export type OptionSetRedeemedEvent = IdlEvents<Vault>["optionSetRedeemed"];
 export type WinningsRedeemedEvent = IdlEvents<Vault>["winningsRedeemed"];
+export type WinningsRedeemedForEvent = IdlEvents<Vault>["winningsRedeemedFor"];
+export type VaultDepositForEvent = IdlEvents<Vault>["vaultDepositFor"];
+export type VaultWithdrawalForEvent = IdlEvents<Vault>["vaultWithdrawalFor"];
+export type OperatorApprovedEvent = IdlEvents<Vault>["operatorApproved"];
+export type OperatorRevokedEvent = IdlEvents<Vault>["operatorRevoked"];
+export type ChunkedActionStartedEvent = IdlEvents<Vault>["chunkedActionStarted"];
+export type ChunkProcessedEvent = IdlEvents<Vault>["chunkProcessed"];
+export type ChunkedActionCancelledEvent = IdlEvents<Vault>["chunkedActionCancelled"];
+export type DepositLimitsSetEvent = IdlEvents<Vault>["depositLimitsSet"];
+export type DepositorAllowedEvent = IdlEvents<Vault>["depositorAllowed"];
+export type OracleConfiguredEvent = IdlEvents<Vault>["oracleConfigured"];
+export type VaultResolvedEvent = IdlEvents<Vault>["vaultResolved"];
+export type ParentVaultSetEvent = IdlEvents<Vault>["parentVaultSet"];
+export type NestedWinningsRedeemedEvent = IdlEvents<Vault>["nestedWinningsRedeemed"];
+export type TokenAccountCreatedEvent = IdlEvents<Vault>["tokenAccountCreated"];
+export type TokenAccountClosedEvent = IdlEvents<Vault>["tokenAccountClosed"];
+export type ProtocolConfigInitializedEvent = IdlEvents<Vault>["protocolConfigInitialized"];
+export type ProtocolConfigUpdatedEvent = IdlEvents<Vault>["protocolConfigUpdated"];
+export type ProtocolAdminAcceptedEvent = IdlEvents<Vault>["protocolAdminAccepted"];
+export type FeesCollectedEvent = IdlEvents<Vault>["feesCollected"];
 
 /* SDK Enums */
 
@@ -34,6 +72,13 @@ export enum VaultState {
   Setup = "setup",
   Active = "active",
   Finalized = "finalized",
+  FinalizedWeighted = "finalizedWeighted",
+  Voided = "voided",
+}
+
+export enum ChunkedAction {
+  Deposit = "deposit",
+  Withdraw = "withdraw",
 }
 
 /* Event Union Type */
@@ -44,11 +89,80 @@ export type VaultEvent =
   | { name: "VaultDeposit"; data: VaultDepositEvent }
   | { name: "VaultWithdrawal"; data: VaultWithdrawalEvent }
   | { name: "VaultFinalized"; data: VaultFinalizedEvent }
+  | { name: "VaultFinalizedWeighted"; data: VaultFinalizedWeightedEvent }
+  | { name: "VaultVoided"; data: VaultVoidedEvent }
+  | { name: "VaultExpired"; data: VaultExpiredEvent }
+  | { name: "VaultClosed"; data: VaultClosedEvent }
+  | { name: "VaultMigrated"; data: VaultMigratedEvent }
+  | { name: "ResolutionDeadlineSet"; data: ResolutionDeadlineSetEvent }
   | { name: "OptionAdded"; data: OptionAddedEvent }
-  | { name: "WinningsRedeemed"; data: WinningsRedeemedEvent };
+  | { name: "OptionsMerged"; data: OptionsMergedEvent }
+  | { name: "OptionSetSplit"; data: OptionSetSplitEvent }
+  | { name: "OptionSetRedeemed"; data: OptionSetRedeemedEvent }
+  | { name: "ReceiptWrapped"; data: ReceiptWrappedEvent }
+  | { name: "ReceiptUnwrapped"; data: ReceiptUnwrappedEvent }
+  | { name: "ReceiptRedeemed"; data: ReceiptRedeemedEvent }
+  | { name: "WinningsRedeemed"; data: WinningsRedeemedEvent }
+  | { name: "WinningsRedeemedFor"; data: WinningsRedeemedForEvent }
+  | { name: "VaultDepositFor"; data: VaultDepositForEvent }
+  | { name: "VaultWithdrawalFor"; data: VaultWithdrawalForEvent }
+  | { name: "OperatorApproved"; data: OperatorApprovedEvent }
+  | { name: "OperatorRevoked"; data: OperatorRevokedEvent }
+  | { name: "ChunkedActionStarted"; data: ChunkedActionStartedEvent }
+  | { name: "ChunkProcessed"; data: ChunkProcessedEvent }
+  | { name: "ChunkedActionCancelled"; data: ChunkedActionCancelledEvent }
+  | { name: "DepositLimitsSet"; data: DepositLimitsSetEvent }
+  | { name: "DepositorAllowed"; data: DepositorAllowedEvent }
+  | { name: "OracleConfigured"; data: OracleConfiguredEvent }
+  | { name: "VaultResolved"; data: VaultResolvedEvent }
+  | { name: "ParentVaultSet"; data: ParentVaultSetEvent }
+  | { name: "NestedWinningsRedeemed"; data: NestedWinningsRedeemedEvent }
+  | { name: "TokenAccountCreated"; data: TokenAccountCreatedEvent }
+  | { name: "TokenAccountClosed"; data: TokenAccountClosedEvent }
+  | { name: "ProtocolConfigInitialized"; data: ProtocolConfigInitializedEvent }
+  | { name: "ProtocolConfigUpdated"; data: ProtocolConfigUpdatedEvent }
+  | { name: "ProtocolAdminAccepted"; data: ProtocolAdminAcceptedEvent }
+  | { name: "FeesCollected"; data: FeesCollectedEvent };
 
 /* Client Options */
 
 export interface VaultActionOptions extends TxOptions {
   autoWrapUnwrap?: boolean;  // Auto wrap/unwrap native SOL (default: true)
+  tokenAccounts?: UserTokenAccounts;
+}
+
+export interface RedeemOptions extends VaultActionOptions {
+  amount?: BN | number;      // Winning tokens to burn per paying option (default: all)
+  closeAccounts?: boolean;   // Close conditional accounts left empty (default: false)
+}
+
+export interface OperatorActionOptions extends TxOptions {
+  asDelegate?: boolean;  // Signer is the SPL delegate of the owner's accounts, not an approved operator (default: false)
+}
+
+// Any of the user's token accounts for the mints, instead of their ATAs (default: ATAs)
+export interface UserTokenAccounts {
+  userAta?: PublicKey;        // Underlying token account
+  userCondAtas?: PublicKey[]; // Conditional token accounts, by option index
+}
+
+export interface InitializeVaultOptions {
+  baseSymbol?: string;                // Conditional mint name symbols (default: from token metadata)
+  quoteSymbol?: string;
+  optionLabels?: [string, string];    // Labels of the first two options (default: indices)
+  permanentDelegate?: boolean;        // Vault as permanent delegate of its conditional mints (default: false)
+  baseTokenProgram?: PublicKey;       // Token programs of the underlying mints (default: SPL Token)
+  quoteTokenProgram?: PublicKey;
+}
+
+// Deposit caps of one side of a vault, in underlying units (default: uncapped)
+export interface DepositCaps {
+  total?: BN | number;    // Max outstanding escrow
+  perUser?: BN | number;  // Max cumulative deposits per user
+}
+
+export interface DepositLimits {
+  baseCaps?: DepositCaps;
+  quoteCaps?: DepositCaps;
+  allowList?: boolean;    // Only depositors approved via allowDepositor (default: false)
 }
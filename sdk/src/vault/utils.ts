@@ -5,7 +5,18 @@
 
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
-import { VAULT_SEED, CONDITIONAL_MINT_SEED, PROGRAM_ID } from "./constants";
+import {
+  VAULT_SEED,
+  CONDITIONAL_MINT_SEED,
+  CONDITIONAL_SET_MINT_SEED,
+  RECEIPT_MINT_SEED,
+  DEPOSIT_RECORD_SEED,
+  OPERATOR_APPROVAL_SEED,
+  CHUNK_PROGRESS_SEED,
+  ORACLE_CONFIG_SEED,
+  PROTOCOL_CONFIG_SEED,
+  PROGRAM_ID,
+} from "./constants";
 import { Vault, VaultType, VaultState, VaultAccount } from "./types";
 
 /* PDA Derivation */
@@ -44,6 +55,119 @@ export function deriveConditionalMint(
   );
 }
 
+// OR-position mint of an option set; bit i of `mask` selects option i
+export function deriveSetMint(
+  vaultPda: PublicKey,
+  vaultType: VaultType,
+  mask: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  const maskBuffer = Buffer.alloc(8);
+  maskBuffer.writeBigUInt64LE(BigInt(mask));
+  return PublicKey.findProgramAddressSync(
+    [
+      CONDITIONAL_SET_MINT_SEED,
+      vaultPda.toBuffer(),
+      Buffer.from([vaultType]),
+      maskBuffer,
+    ],
+    programId
+  );
+}
+
+// Receipt mint for a complete set of one side's options
+export function deriveReceiptMint(
+  vaultPda: PublicKey,
+  vaultType: VaultType,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      RECEIPT_MINT_SEED,
+      vaultPda.toBuffer(),
+      Buffer.from([vaultType]),
+    ],
+    programId
+  );
+}
+
+export function deriveDepositRecordPDA(
+  vaultPda: PublicKey,
+  user: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      DEPOSIT_RECORD_SEED,
+      vaultPda.toBuffer(),
+      user.toBuffer(),
+    ],
+    programId
+  );
+}
+
+export function deriveOperatorApprovalPDA(
+  vaultPda: PublicKey,
+  owner: PublicKey,
+  operator: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      OPERATOR_APPROVAL_SEED,
+      vaultPda.toBuffer(),
+      owner.toBuffer(),
+      operator.toBuffer(),
+    ],
+    programId
+  );
+}
+
+export function deriveChunkProgressPDA(
+  vaultPda: PublicKey,
+  user: PublicKey,
+  vaultType: VaultType,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      CHUNK_PROGRESS_SEED,
+      vaultPda.toBuffer(),
+      user.toBuffer(),
+      Buffer.from([vaultType]),
+    ],
+    programId
+  );
+}
+
+export function deriveOracleConfigPDA(
+  vaultPda: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      ORACLE_CONFIG_SEED,
+      vaultPda.toBuffer(),
+    ],
+    programId
+  );
+}
+
+// Program-wide fee configuration, a singleton
+export function deriveProtocolConfigPDA(
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROTOCOL_CONFIG_SEED], programId);
+}
+
+// Upgradeable-loader ProgramData account, whose upgrade authority may initialize the protocol config
+export function deriveProgramDataPDA(programId: PublicKey = PROGRAM_ID): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+}
+
 /* Parsers */
 
 export function parseVaultState(state: any): { state: VaultState; winningIdx: number | null } {
@@ -57,6 +181,12 @@ export function parseVaultState(state: any): { state: VaultState; winningIdx: nu
     const winningIdx = state.finalized[0] ?? state.finalized;
     return { state: VaultState.Finalized, winningIdx };
   }
+  if ("finalizedWeighted" in state) {
+    return { state: VaultState.FinalizedWeighted, winningIdx: null };
+  }
+  if ("voided" in state) {
+    return { state: VaultState.Voided, winningIdx: null };
+  }
   throw new Error("Unknown vault state");
 }
 
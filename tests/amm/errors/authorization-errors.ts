@@ -9,7 +9,7 @@ import {
   createUserClient,
   ensureWalletFunded,
 } from "../helpers/setup";
-import { createPool, createPoolWithLiquidity } from "../helpers/factories";
+import { createPool, createPoolWithLiquidity, PoolTestContext } from "../helpers/factories";
 import { expectAnchorError } from "../helpers/assertions";
 import { INITIAL_LIQUIDITY, FUNDING_AMOUNT } from "../helpers/constants";
 
@@ -139,4 +139,66 @@ describe("AMM - Errors - Authorization Errors", () => {
       await builder.rpc();
     });
   });
+
+  describe("Handovers", () => {
+    let ctx: PoolTestContext;
+    let nominee: Keypair;
+    let outsider: Keypair;
+
+    beforeEach(async () => {
+      const mintA = await createTestMint(provider, wallet);
+      const mintB = await createTestMint(provider, wallet);
+      await fundOwnerWallet(provider, wallet, mintA);
+      await fundOwnerWallet(provider, wallet, mintB);
+
+      ctx = await createPool(client, wallet, mintA, mintB);
+      nominee = Keypair.generate();
+      outsider = (await createFundedUser(provider, wallet, mintA, mintB, FUNDING_AMOUNT)).keypair;
+    });
+
+    it("non-admin proposing an admin fails with InvalidAdmin", async () => {
+      const outsiderClient = createUserClient(provider, outsider);
+      await expectAnchorError(
+        outsiderClient.proposeAdmin(outsider.publicKey, ctx.poolPda, outsider.publicKey).rpc(),
+        "InvalidAdmin"
+      );
+    });
+
+    it("accepting an admin handover as someone other than the nominee fails with InvalidAdmin", async () => {
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, nominee.publicKey).rpc();
+
+      const outsiderClient = createUserClient(provider, outsider);
+      await expectAnchorError(
+        outsiderClient.acceptAdmin(outsider.publicKey, ctx.poolPda).rpc(),
+        "InvalidAdmin"
+      );
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.admin.equals(wallet.publicKey)).to.be.true;
+      expect(pool.pendingAdmin!.equals(nominee.publicKey)).to.be.true;
+    });
+
+    it("non-provider proposing a liquidity provider fails with InvalidDepositor", async () => {
+      const outsiderClient = createUserClient(provider, outsider);
+      await expectAnchorError(
+        outsiderClient
+          .proposeLiquidityProvider(outsider.publicKey, ctx.poolPda, outsider.publicKey)
+          .rpc(),
+        "InvalidDepositor"
+      );
+    });
+
+    it("accepting an LP handover as someone other than the nominee fails with InvalidDepositor", async () => {
+      await client.proposeLiquidityProvider(wallet.publicKey, ctx.poolPda, nominee.publicKey).rpc();
+
+      const outsiderClient = createUserClient(provider, outsider);
+      await expectAnchorError(
+        outsiderClient.acceptLiquidityProvider(outsider.publicKey, ctx.poolPda).rpc(),
+        "InvalidDepositor"
+      );
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.liquidityProvider.equals(wallet.publicKey)).to.be.true;
+    });
+  });
 });
@@ -0,0 +1,151 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import {
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  ensureWalletFunded,
+  FundedUser,
+} from "../helpers/setup";
+import { createPoolWithLiquidity, PoolTestContext } from "../helpers/factories";
+import {
+  expectAnchorError,
+  expectLiquidityProvider,
+  expectPoolAdmin,
+} from "../helpers/assertions";
+import { INITIAL_LIQUIDITY, FUNDING_AMOUNT } from "../helpers/constants";
+
+describe("AMM - Handovers", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let mintA: PublicKey;
+  let mintB: PublicKey;
+  let ctx: PoolTestContext;
+  let nominee: FundedUser;
+
+  beforeEach(async () => {
+    await ensureWalletFunded(provider, wallet);
+
+    mintA = await createTestMint(provider, wallet);
+    mintB = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, mintA);
+    await fundOwnerWallet(provider, wallet, mintB);
+
+    ctx = await createPoolWithLiquidity(client, wallet, mintA, mintB);
+    nominee = await createFundedUser(provider, wallet, mintA, mintB, FUNDING_AMOUNT);
+  });
+
+  describe("Admin", () => {
+    it("hands the pool to the proposed admin once they accept", async () => {
+      const newAdmin = nominee.keypair.publicKey;
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, newAdmin).rpc();
+
+      // Nothing changes until the nominee accepts
+      let pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingAdmin!.equals(newAdmin)).to.be.true;
+      await expectPoolAdmin(client, ctx.poolPda, wallet.publicKey);
+
+      const nomineeClient = createUserClient(provider, nominee.keypair);
+      await nomineeClient.acceptAdmin(newAdmin, ctx.poolPda).rpc();
+
+      await expectPoolAdmin(client, ctx.poolPda, newAdmin);
+      pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingAdmin).to.be.null;
+      // The PDA stays derived from the admin the pool was created with
+      expect(pool.seedAdmin.equals(wallet.publicKey)).to.be.true;
+
+      // Only the new admin can cease trading
+      await expectAnchorError(
+        client.ceaseTrading(wallet.publicKey, ctx.poolPda).rpc(),
+        "InvalidAdmin"
+      );
+      await nomineeClient.ceaseTrading(newAdmin, ctx.poolPda).rpc();
+    });
+
+    it("replaces a pending proposal with a newer one", async () => {
+      const replacement = Keypair.generate();
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, nominee.keypair.publicKey).rpc();
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, replacement.publicKey).rpc();
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingAdmin!.equals(replacement.publicKey)).to.be.true;
+
+      // The overwritten nominee can no longer accept
+      const nomineeClient = createUserClient(provider, nominee.keypair);
+      await expectAnchorError(
+        nomineeClient.acceptAdmin(nominee.keypair.publicKey, ctx.poolPda).rpc(),
+        "InvalidAdmin"
+      );
+      await expectPoolAdmin(client, ctx.poolPda, wallet.publicKey);
+    });
+
+    it("cancels a pending proposal with null", async () => {
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, nominee.keypair.publicKey).rpc();
+      await client.proposeAdmin(wallet.publicKey, ctx.poolPda, null).rpc();
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingAdmin).to.be.null;
+
+      const nomineeClient = createUserClient(provider, nominee.keypair);
+      await expectAnchorError(
+        nomineeClient.acceptAdmin(nominee.keypair.publicKey, ctx.poolPda).rpc(),
+        "InvalidAdmin"
+      );
+    });
+  });
+
+  describe("Liquidity Provider", () => {
+    it("hands the LP position to the proposed provider once they accept", async () => {
+      const newProvider = nominee.keypair.publicKey;
+      await client.proposeLiquidityProvider(wallet.publicKey, ctx.poolPda, newProvider).rpc();
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingLiquidityProvider!.equals(newProvider)).to.be.true;
+      await expectLiquidityProvider(client, ctx.poolPda, wallet.publicKey);
+
+      const nomineeClient = createUserClient(provider, nominee.keypair);
+      await nomineeClient.acceptLiquidityProvider(newProvider, ctx.poolPda).rpc();
+      await expectLiquidityProvider(client, ctx.poolPda, newProvider);
+
+      // The new provider manages liquidity; the previous one no longer can
+      const add = await nomineeClient.addLiquidity(
+        newProvider,
+        ctx.poolPda,
+        INITIAL_LIQUIDITY / 10,
+        INITIAL_LIQUIDITY / 10
+      );
+      await add.rpc();
+
+      const remove = await client.removeLiquidity(
+        wallet.publicKey,
+        ctx.poolPda,
+        INITIAL_LIQUIDITY / 10,
+        INITIAL_LIQUIDITY / 10
+      );
+      await expectAnchorError(remove.rpc(), "InvalidDepositor");
+    });
+
+    it("replaces a pending proposal with a newer one", async () => {
+      const replacement = Keypair.generate();
+      await client
+        .proposeLiquidityProvider(wallet.publicKey, ctx.poolPda, nominee.keypair.publicKey)
+        .rpc();
+      await client
+        .proposeLiquidityProvider(wallet.publicKey, ctx.poolPda, replacement.publicKey)
+        .rpc();
+
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.pendingLiquidityProvider!.equals(replacement.publicKey)).to.be.true;
+
+      const nomineeClient = createUserClient(provider, nominee.keypair);
+      await expectAnchorError(
+        nomineeClient.acceptLiquidityProvider(nominee.keypair.publicKey, ctx.poolPda).rpc(),
+        "InvalidDepositor"
+      );
+      await expectLiquidityProvider(client, ctx.poolPda, wallet.publicKey);
+    });
+  });
+});
@@ -0,0 +1,71 @@
+import { expect } from "chai";
+import { PublicKey } from "@solana/web3.js";
+
+import { AMM_VERSION, PoolState, parsePoolState } from "../../../sdk/src";
+import { crankTwap } from "../../../sdk/src/amm/instructions";
+import { getTestContext, ensureWalletFunded } from "../helpers/setup";
+import { expectAnchorError } from "../helpers/assertions";
+
+// v1 pool loaded into the validator from tests/fixtures/amm-pool-v1.json (see Anchor.toml):
+// Trading, fee 30, admin [11; 32], liquidity provider [12; 32], mints [13; 32] / [14; 32]
+const V1_POOL = new PublicKey("6wrhzB9nuJycZe5vFBVKyrZm74tcVHpjBqn4BwkKHojr");
+const V1_ADMIN = new PublicKey(new Uint8Array(32).fill(11));
+const V1_LIQUIDITY_PROVIDER = new PublicKey(new Uint8Array(32).fill(12));
+const V1_LEN = 252;
+
+describe("AMM - Migration", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  before(async () => {
+    await ensureWalletFunded(provider, wallet);
+  });
+
+  it("rejects an unmigrated pool with InvalidVersion", async () => {
+    expect(await client.fetchPoolVersion(V1_POOL)).to.equal(1);
+
+    // Built directly, since the client can't decode the v1 layout to derive accounts
+    const mintA = new PublicKey(new Uint8Array(32).fill(13));
+    const mintB = new PublicKey(new Uint8Array(32).fill(14));
+    const [reserveA] = client.deriveReservePDA(V1_POOL, mintA);
+    const [reserveB] = client.deriveReservePDA(V1_POOL, mintB);
+
+    await expectAnchorError(
+      crankTwap(client.program, V1_POOL, reserveA, reserveB).rpc(),
+      "InvalidVersion"
+    );
+  });
+
+  it("migrates a v1 pool to the current layout, topping up rent", async () => {
+    const connection = provider.connection;
+    const before = await connection.getAccountInfo(V1_POOL);
+    expect(before!.data.length).to.equal(V1_LEN);
+
+    await client.migratePool(wallet.publicKey, V1_POOL).rpc();
+
+    const after = await connection.getAccountInfo(V1_POOL);
+    expect(after!.data.length).to.be.greaterThan(V1_LEN);
+    expect(after!.lamports).to.be.greaterThan(before!.lamports);
+    expect(after!.lamports).to.equal(
+      await connection.getMinimumBalanceForRentExemption(after!.data.length)
+    );
+
+    const pool = await client.fetchPool(V1_POOL);
+    expect(pool.version).to.equal(AMM_VERSION);
+    expect(parsePoolState(pool.state)).to.equal(PoolState.Trading);
+    expect(pool.fee).to.equal(30);
+    expect(pool.admin.equals(V1_ADMIN)).to.be.true;
+    expect(pool.liquidityProvider.equals(V1_LIQUIDITY_PROVIDER)).to.be.true;
+    // PDA seeds keep the admin the pool was created with
+    expect(pool.seedAdmin.equals(V1_ADMIN)).to.be.true;
+    expect(pool.pendingAdmin).to.be.null;
+    expect(pool.pendingLiquidityProvider).to.be.null;
+    expect(pool.finalSnapshot).to.be.null;
+  });
+
+  it("rejects migrating a pool that is already current", async () => {
+    await expectAnchorError(
+      client.migratePool(wallet.publicKey, V1_POOL).rpc(),
+      "InvalidVersion"
+    );
+  });
+});
@@ -9,6 +9,7 @@ import {
   ensureWalletFunded,
 } from "../helpers/setup";
 import { createPool, createPoolWithLiquidity } from "../helpers/factories";
+import { expectAnchorError } from "../helpers/assertions";
 import {
   INITIAL_LIQUIDITY,
   SWAP_AMOUNT,
@@ -174,4 +175,66 @@ describe("AMM - Happy Path - TWAP Oracle", () => {
       );
     });
   });
+
+  describe("Final Snapshot", () => {
+    it("is unavailable while the pool is trading", async () => {
+      const mintA = await createTestMint(provider, wallet);
+      const mintB = await createTestMint(provider, wallet);
+      await fundOwnerWallet(provider, wallet, mintA);
+      await fundOwnerWallet(provider, wallet, mintB);
+
+      const ctx = await createPoolWithLiquidity(client, wallet, mintA, mintB);
+
+      await expectAnchorError(client.fetchFinalSnapshot(ctx.poolPda), "InvalidState");
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(pool.finalSnapshot).to.be.null;
+    });
+
+    it("records the closing reserves and oracle on cease_trading", async () => {
+      const mintA = await createTestMint(provider, wallet);
+      const mintB = await createTestMint(provider, wallet);
+      await fundOwnerWallet(provider, wallet, mintA);
+      await fundOwnerWallet(provider, wallet, mintB);
+
+      const ctx = await createPoolWithLiquidity(client, wallet, mintA, mintB);
+      const swap = await client.swap(wallet.publicKey, ctx.poolPda, true, SWAP_AMOUNT, 1);
+      await swap.rpc();
+      const { reserveA, reserveB } = await client.fetchReserves(ctx.poolPda);
+
+      await client.ceaseTrading(wallet.publicKey, ctx.poolPda).rpc();
+
+      const snapshot = await client.fetchFinalSnapshot(ctx.poolPda);
+      expect(snapshot.reserveA.eq(reserveA)).to.be.true;
+      expect(snapshot.reserveB.eq(reserveB)).to.be.true;
+
+      // Taken after the final crank, so it matches the pool's oracle
+      const pool = await client.fetchPool(ctx.poolPda);
+      expect(snapshot.lastPrice.eq(pool.oracle.lastPrice)).to.be.true;
+      expect(snapshot.lastObservation.eq(pool.oracle.lastObservation)).to.be.true;
+      expect(snapshot.unixTime.gte(pool.oracle.lastUpdateUnixTime)).to.be.true;
+
+      // The stored snapshot is what get_final_snapshot returns
+      expect(pool.finalSnapshot!.reserveA.eq(snapshot.reserveA)).to.be.true;
+      expect(pool.finalSnapshot!.unixTime.eq(snapshot.unixTime)).to.be.true;
+    });
+
+    it("rejects trading and cranking after cease_trading", async () => {
+      const mintA = await createTestMint(provider, wallet);
+      const mintB = await createTestMint(provider, wallet);
+      await fundOwnerWallet(provider, wallet, mintA);
+      await fundOwnerWallet(provider, wallet, mintB);
+
+      const ctx = await createPoolWithLiquidity(client, wallet, mintA, mintB);
+      await client.ceaseTrading(wallet.publicKey, ctx.poolPda).rpc();
+      const before = await client.fetchFinalSnapshot(ctx.poolPda);
+
+      const swap = await client.swap(wallet.publicKey, ctx.poolPda, true, SWAP_AMOUNT, 1);
+      await expectAnchorError(swap.rpc(), "InvalidState");
+      await expectAnchorError((await client.crankTwap(ctx.poolPda)).rpc(), "InvalidState");
+
+      const after = await client.fetchFinalSnapshot(ctx.poolPda);
+      expect(after.reserveA.eq(before.reserveA)).to.be.true;
+      expect(after.unixTime.eq(before.unixTime)).to.be.true;
+    });
+  });
 });
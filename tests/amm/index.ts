@@ -6,6 +6,8 @@
  * - Swap operations (both directions, fee handling, slippage protection)
  * - Liquidity management (add, remove, partial, asymmetric)
  * - TWAP oracle functionality (non-time-dependent)
+ * - Admin and liquidity provider handovers
+ * - Migrating pools written under an older layout
  * - All error conditions (state, authorization, validation, math)
  * - Multi-user scenarios (concurrent swaps, arbitrage)
  * - Stress tests (sequential operations)
@@ -19,6 +21,8 @@ import "./happy-path/lifecycle";
 import "./happy-path/swaps";
 import "./happy-path/liquidity";
 import "./happy-path/twap";
+import "./happy-path/handover";
+import "./happy-path/migration";
 
 // Error Tests
 import "./errors/state-errors";
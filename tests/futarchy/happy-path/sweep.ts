@@ -0,0 +1,106 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  ONE_TOKEN,
+  INITIAL_LIQUIDITY,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createModerator,
+  createProposalInSetupState,
+  createProposalInPendingState,
+  warmupTwap,
+  waitForProposalExpiration,
+  FundedUser,
+  ModeratorTestContext,
+  ProposalTestContext,
+} from "../helpers";
+
+describe("Futarchy - Sweeping Finished Proposals", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const aliceDeposit = 3 * ONE_TOKEN;
+
+  let moderatorCtx: ModeratorTestContext;
+  let ctx: ProposalTestContext;
+  let alice: FundedUser;
+
+  before(async () => {
+    const baseMint = await createTestMint(provider, wallet);
+    const quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    moderatorCtx = await createModerator(client, wallet, {
+      baseMint,
+      quoteMint,
+    });
+    ctx = await createProposalInPendingState(
+      client,
+      wallet,
+      moderatorCtx,
+      INITIAL_LIQUIDITY,
+      INITIAL_LIQUIDITY,
+      { permanentDelegate: true }
+    );
+
+    // Alice takes a position straight through the proposal's vault
+    alice = await createFundedUser(provider, wallet, baseMint, quoteMint);
+    const aliceClient = createUserClient(provider, alice.keypair);
+    const depositBuilder = await aliceClient.vault.deposit(
+      alice.keypair.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      aliceDeposit
+    );
+    await depositBuilder.rpc();
+
+    await warmupTwap(client, ctx.proposalPda);
+    await waitForProposalExpiration(client, ctx.proposalPda);
+    const { builder: finalizeBuilder } = await client.finalizeProposal(
+      wallet.publicKey,
+      ctx.proposalPda
+    );
+    await finalizeBuilder.rpc();
+  });
+
+  it("opts the proposal vault into the permanent delegate when asked", async () => {
+    const vault = await client.vault.fetchVault(ctx.vaultPda);
+    expect(vault.permanentDelegate).to.be.true;
+  });
+
+  it("leaves proposal vaults without the permanent delegate by default", async () => {
+    const defaultCtx = await createProposalInSetupState(client, wallet, moderatorCtx);
+    const vault = await client.vault.fetchVault(defaultCtx.vaultPda);
+    expect(vault.permanentDelegate).to.be.false;
+  });
+
+  it("lets anyone redeem a holder's winnings once the proposal resolves", async () => {
+    const { userBalance: before } = await client.vault.fetchUserBalances(
+      ctx.vaultPda,
+      alice.keypair.publicKey,
+      VaultType.Base
+    );
+
+    // The moderator admin sweeps on Alice's behalf
+    const builder = await client.vault.redeemFor(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      [alice.keypair.publicKey]
+    );
+    await builder.rpc();
+
+    const { userBalance: after, condBalances } = await client.vault.fetchUserBalances(
+      ctx.vaultPda,
+      alice.keypair.publicKey,
+      VaultType.Base
+    );
+    expect(after.sub(before).toNumber()).to.equal(aliceDeposit);
+    expect(condBalances.map((b) => b.toNumber())).to.deep.equal([0, 0]);
+  });
+});
@@ -0,0 +1,50 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VAULT_RESOLUTION_GRACE_PERIOD } from "../../../sdk/src";
+import {
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createModerator,
+  createProposalInPendingState,
+  expectAnchorError,
+  ModeratorTestContext,
+  ProposalTestContext,
+} from "../helpers";
+
+describe("Futarchy - Vault Resolution Deadline", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let moderatorCtx: ModeratorTestContext;
+  let ctx: ProposalTestContext;
+
+  before(async () => {
+    const baseMint = await createTestMint(provider, wallet);
+    const quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    moderatorCtx = await createModerator(client, wallet, {
+      baseMint,
+      quoteMint,
+    });
+    ctx = await createProposalInPendingState(client, wallet, moderatorCtx);
+  });
+
+  it("sets the vault deadline to proposal end plus the grace period on launch", async () => {
+    const proposal = await client.fetchProposal(ctx.proposalPda);
+    const vault = await client.vault.fetchVault(ctx.vaultPda);
+
+    const expected =
+      proposal.createdAt.toNumber() + proposal.config.length + VAULT_RESOLUTION_GRACE_PERIOD;
+    expect(vault.resolutionDeadline?.toNumber()).to.equal(expected);
+  });
+
+  it("keeps the vault from being expired while the proposal can still finalize", async () => {
+    await expectAnchorError(
+      client.vault.expire(ctx.vaultPda).rpc(),
+      "ResolutionDeadlineNotReached"
+    );
+  });
+});
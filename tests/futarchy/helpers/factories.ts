@@ -4,6 +4,7 @@ import { BN } from "@coral-xyz/anchor";
 import {
   createAssociatedTokenAccountInstruction,
   getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
 
 import { FutarchyClient, TWAPConfig } from "../../../sdk/src";
@@ -46,6 +47,7 @@ export interface CreateProposalOptions {
   length?: number; // default: PROPOSAL_LENGTH
   fee?: number; // default: DEFAULT_FEE
   twapConfig?: TWAPConfig; // default: DEFAULT_TWAP_CONFIG
+  permanentDelegate?: boolean; // default: false, needed to sweep with redeemFor
 }
 
 /**
@@ -116,9 +118,16 @@ export async function createProposalInSetupState(
   } = await client.initializeProposal(
     wallet.publicKey,
     moderatorCtx.moderatorPda,
-    length,
-    fee,
-    twapConfig
+    {
+      length,
+      startingObservation: twapConfig.startingObservation,
+      maxObservationDelta: twapConfig.maxObservationDelta,
+      warmupDuration: twapConfig.warmupDuration,
+      marketBias: 0,
+      fee,
+    },
+    undefined,
+    { permanentDelegate: options.permanentDelegate }
   );
   await builder.rpc();
 
@@ -350,12 +359,12 @@ export async function preCreateConditionalATAs(
 ): Promise<void> {
   const provider = client.program.provider as anchor.AnchorProvider;
 
-  // Build instructions to create all conditional token ATAs
+  // Build instructions to create all conditional token ATAs (conditional mints are Token-2022)
   const instructions: TransactionInstruction[] = [];
   const allMints = [...proposalCtx.condBaseMints, ...proposalCtx.condQuoteMints];
 
   for (const mint of allMints) {
-    const ata = getAssociatedTokenAddressSync(mint, wallet.publicKey);
+    const ata = getAssociatedTokenAddressSync(mint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
 
     // Check if ATA already exists
     const accountInfo = await provider.connection.getAccountInfo(ata);
@@ -365,7 +374,8 @@ export async function preCreateConditionalATAs(
           wallet.publicKey, // payer
           ata,              // ata
           wallet.publicKey, // owner
-          mint              // mint
+          mint,             // mint
+          TOKEN_2022_PROGRAM_ID
         )
       );
     }
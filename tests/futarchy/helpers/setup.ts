@@ -8,7 +8,7 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 
-import { FutarchyClient } from "../../../sdk/src";
+import { FutarchyClient, fetchTokenProgram } from "../../../sdk/src";
 import { FUNDING_AMOUNT } from "./constants";
 
 export interface TestContext {
@@ -156,22 +156,22 @@ export function createUserClient(
 }
 
 /**
- * Get token balance for an ATA (returns 0 if account doesn't exist)
+ * Get token balance for an ATA of either token program (returns 0 if account doesn't exist)
  */
 export async function getTokenBalance(
   provider: anchor.AnchorProvider,
   ata: PublicKey
 ): Promise<number> {
-  try {
-    const account = await getAccount(provider.connection, ata);
-    return Number(account.amount);
-  } catch {
+  const info = await provider.connection.getAccountInfo(ata);
+  if (!info) {
     return 0; // Account doesn't exist
   }
+  const account = await getAccount(provider.connection, ata, undefined, info.owner);
+  return Number(account.amount);
 }
 
 /**
- * Get token balance for a mint and owner (derives ATA automatically)
+ * Get token balance for a mint and owner (derives ATA under the mint's token program)
  */
 export async function getTokenBalanceFor(
   provider: anchor.AnchorProvider,
@@ -179,6 +179,7 @@ export async function getTokenBalanceFor(
   owner: PublicKey,
   allowOwnerOffCurve: boolean = false
 ): Promise<number> {
-  const ata = getAssociatedTokenAddressSync(mint, owner, allowOwnerOffCurve);
+  const tokenProgram = await fetchTokenProgram(provider.connection, mint);
+  const ata = getAssociatedTokenAddressSync(mint, owner, allowOwnerOffCurve, tokenProgram);
   return getTokenBalance(provider, ata);
 }
@@ -3,6 +3,8 @@
 // Happy path tests
 import "./happy-path/moderator";
 import "./happy-path/lifecycle";
+import "./happy-path/vault-deadline";
+import "./happy-path/sweep";
 
 // Error tests
 import "./errors/state-errors";
@@ -3,13 +3,16 @@ import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 
 import { VaultClient, VaultType } from "../../../sdk/src";
 import {
+  DEPOSIT_AMOUNT,
   getTestContext,
   createTestMint,
   fundOwnerWallet,
   createVaultInSetupState,
   createVaultInActiveState,
   createUserClient,
+  ensureProtocolConfig,
   expectAnchorError,
+  expectError,
 } from "../helpers";
 
 describe("Authorization Errors", () => {
@@ -44,6 +47,7 @@ describe("Authorization Errors", () => {
 
       // Attacker tries to add option
       const { builder } = await attackerClient.addOption(
+        attackerKeypair.publicKey,
         attackerKeypair.publicKey,
         ctx.vaultPda
       );
@@ -58,7 +62,7 @@ describe("Authorization Errors", () => {
 
       // Attacker tries to activate
       await expectAnchorError(
-        attackerClient.activate(attackerKeypair.publicKey, ctx.vaultPda).rpc(),
+        attackerClient.activate(attackerKeypair.publicKey, attackerKeypair.publicKey, ctx.vaultPda).rpc(),
         "Unauthorized"
       );
     });
@@ -71,7 +75,88 @@ describe("Authorization Errors", () => {
 
       // Attacker tries to finalize
       await expectAnchorError(
-        attackerClient.finalize(attackerKeypair.publicKey, ctx.vaultPda, 0).rpc(),
+        attackerClient.finalize(attackerKeypair.publicKey, attackerKeypair.publicKey, ctx.vaultPda, 0).rpc(),
+        "Unauthorized"
+      );
+    });
+  });
+
+  describe("Unauthorized - void", () => {
+    it("rejects void from non-owner", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        attackerClient.voidVault(attackerKeypair.publicKey, ctx.vaultPda).rpc(),
+        "Unauthorized"
+      );
+    });
+  });
+
+  describe("Unauthorized - deposit_for", () => {
+    it("rejects an operator the owner never approved", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await attackerClient.depositFor(
+        attackerKeypair.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(builder.rpc(), "AccountNotInitialized");
+    });
+
+    it("rejects a signer without a token allowance", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await attackerClient.depositFor(
+        attackerKeypair.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT,
+        { asDelegate: true }
+      );
+      // Token program rejects the transfer: OwnerMismatch
+      await expectError(builder.rpc(), "custom program error: 0x4");
+    });
+  });
+
+  describe("Unauthorized - protocol config", () => {
+    before(async () => {
+      await ensureProtocolConfig(client, wallet);
+    });
+
+    it("rejects update_protocol_config from non-admin", async () => {
+      await expectAnchorError(
+        attackerClient
+          .updateProtocolConfig(attackerKeypair.publicKey, attackerKeypair.publicKey, {
+            deposit: 0,
+            redeem: 0,
+          })
+          .rpc(),
+        "Unauthorized"
+      );
+    });
+
+    it("rejects accept_protocol_admin without a pending handover to the signer", async () => {
+      await expectAnchorError(
+        attackerClient.acceptProtocolAdmin(attackerKeypair.publicKey).rpc(),
+        "Unauthorized"
+      );
+    });
+
+    it("rejects initialize_protocol_config from a non-upgrade-authority", async () => {
+      await expectAnchorError(
+        attackerClient
+          .initializeProtocolConfig(
+            attackerKeypair.publicKey,
+            attackerKeypair.publicKey,
+            attackerKeypair.publicKey,
+            attackerKeypair.publicKey,
+            { deposit: 0, redeem: 0 }
+          )
+          .rpc(),
         "Unauthorized"
       );
     });
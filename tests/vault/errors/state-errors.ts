@@ -1,16 +1,19 @@
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 
-import { VaultClient, VaultType } from "../../../sdk/src";
+import { PRICE_SCALE, VaultClient, VaultType } from "../../../sdk/src";
 import {
   DEPOSIT_AMOUNT,
   getTestContext,
+  getChainTime,
   createTestMint,
   fundOwnerWallet,
   createVaultInSetupState,
   createVaultInActiveState,
   createVaultInFinalizedState,
   createVaultWithDeposit,
+  createTwapPool,
   sendAndLog,
   expectAnchorError,
 } from "../helpers";
@@ -32,14 +35,14 @@ describe("State Errors", () => {
     it("rejects add_option when vault is Active", async () => {
       const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
 
-      const { builder } = await client.addOption(wallet.publicKey, ctx.vaultPda);
+      const { builder } = await client.addOption(wallet.publicKey, wallet.publicKey, ctx.vaultPda);
       await expectAnchorError(builder.rpc(), "InvalidState");
     });
 
     it("rejects add_option when vault is Finalized", async () => {
       const ctx = await createVaultInFinalizedState(client, wallet, baseMint, quoteMint, 0);
 
-      const { builder } = await client.addOption(wallet.publicKey, ctx.vaultPda);
+      const { builder } = await client.addOption(wallet.publicKey, wallet.publicKey, ctx.vaultPda);
       await expectAnchorError(builder.rpc(), "InvalidState");
     });
   });
@@ -49,7 +52,7 @@ describe("State Errors", () => {
       const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
 
       await expectAnchorError(
-        client.activate(wallet.publicKey, ctx.vaultPda).rpc(),
+        client.activate(wallet.publicKey, wallet.publicKey, ctx.vaultPda).rpc(),
         "InvalidState"
       );
     });
@@ -58,7 +61,7 @@ describe("State Errors", () => {
       const ctx = await createVaultInFinalizedState(client, wallet, baseMint, quoteMint, 0);
 
       await expectAnchorError(
-        client.activate(wallet.publicKey, ctx.vaultPda).rpc(),
+        client.activate(wallet.publicKey, wallet.publicKey, ctx.vaultPda).rpc(),
         "InvalidState"
       );
     });
@@ -122,7 +125,7 @@ describe("State Errors", () => {
         DEPOSIT_AMOUNT,
         VaultType.Base
       );
-      await client.finalize(wallet.publicKey, ctx.vaultPda, 0).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
 
       const builder = await client.withdraw(
         wallet.publicKey,
@@ -142,7 +145,7 @@ describe("State Errors", () => {
       const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
 
       await expectAnchorError(
-        client.finalize(wallet.publicKey, ctx.vaultPda, 0).rpc(),
+        client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc(),
         "InvalidState"
       );
     });
@@ -151,12 +154,144 @@ describe("State Errors", () => {
       const ctx = await createVaultInFinalizedState(client, wallet, baseMint, quoteMint, 0);
 
       await expectAnchorError(
-        client.finalize(wallet.publicKey, ctx.vaultPda, 1).rpc(),
+        client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 1).rpc(),
         "InvalidState"
       );
     });
   });
 
+  describe("InvalidState - void", () => {
+    it("rejects void when vault is in Setup state", async () => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        client.voidVault(wallet.publicKey, ctx.vaultPda).rpc(),
+        "InvalidState"
+      );
+    });
+
+    it("rejects void when vault is Finalized", async () => {
+      const ctx = await createVaultInFinalizedState(client, wallet, baseMint, quoteMint, 0);
+
+      await expectAnchorError(
+        client.voidVault(wallet.publicKey, ctx.vaultPda).rpc(),
+        "InvalidState"
+      );
+    });
+
+    it("rejects finalize once the vault is Voided", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      await client.voidVault(wallet.publicKey, ctx.vaultPda).rpc();
+
+      await expectAnchorError(
+        client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc(),
+        "InvalidState"
+      );
+    });
+  });
+
+  describe("Resolution deadline", () => {
+    it("rejects expire when no deadline is set", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        client.expire(ctx.vaultPda).rpc(),
+        "ResolutionDeadlineNotReached"
+      );
+    });
+
+    it("rejects set_resolution_deadline once the vault is Active", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        client.setResolutionDeadline(wallet.publicKey, ctx.vaultPda, null).rpc(),
+        "InvalidState"
+      );
+    });
+  });
+
+  describe("InvalidState - set_deposit_limits", () => {
+    it("rejects set_deposit_limits once the vault is Active", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        client
+          .setDepositLimits(wallet.publicKey, ctx.vaultPda, { baseCaps: { total: DEPOSIT_AMOUNT } })
+          .rpc(),
+        "InvalidState"
+      );
+    });
+  });
+
+  describe("InvalidState - configure_oracle", () => {
+    it("rejects configure_oracle once the vault is Active", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      const pool = await createTwapPool(provider, wallet, baseMint, quoteMint);
+      const resolveAt = (await getChainTime(provider)) + 3600;
+
+      await expectAnchorError(
+        client
+          .configureOracle(wallet.publicKey, wallet.publicKey, ctx.vaultPda, pool, resolveAt, [
+            PRICE_SCALE,
+          ])
+          .rpc(),
+        "InvalidState"
+      );
+    });
+  });
+
+  describe("close_vault", () => {
+    it("rejects close_vault when vault is Active", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await client.closeVault(wallet.publicKey, ctx.vaultPda);
+      await expectAnchorError(builder.rpc(), "InvalidState");
+    });
+
+    it("rejects close_vault while winning supply is outstanding", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Base
+      );
+      await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
+
+      const builder = await client.closeVault(wallet.publicKey, ctx.vaultPda);
+      await expectAnchorError(builder.rpc(), "OutstandingSupply");
+    });
+  });
+
+  describe("migrate", () => {
+    it("rejects migrating a vault already at the current version", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await client.migrate(wallet.publicKey, ctx.vaultPda);
+      await expectAnchorError(builder.rpc(), "InvalidVersion");
+    });
+
+    it("rejects migrating a vault-program account that isn't a vault", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      const { builder: approve, operatorApproval } = client.approveOperator(
+        wallet.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        Keypair.generate().publicKey
+      );
+      await approve.rpc();
+
+      const builder = client.program.methods.migrate().accountsPartial({
+        payer: wallet.publicKey,
+        vault: operatorApproval,
+        baseEscrow: getAssociatedTokenAddressSync(baseMint, ctx.vaultPda, true),
+        quoteEscrow: getAssociatedTokenAddressSync(quoteMint, ctx.vaultPda, true),
+      });
+      await expectAnchorError(builder.rpc(), "AccountDiscriminatorMismatch");
+    });
+  });
+
   describe("InvalidState - redeem_winnings", () => {
     it("rejects redeem_winnings when vault is in Setup state", async () => {
       const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
@@ -193,4 +328,56 @@ describe("State Errors", () => {
       );
     });
   });
+
+  describe("InvalidState - redeem_receipt", () => {
+    it("rejects redeem_receipt before the vault is resolved", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Base
+      );
+      const { builder: wrap } = await client.wrap(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await sendAndLog(wrap, client, wallet);
+
+      const builder = await client.redeemReceipt(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidState"
+      );
+    });
+  });
+
+  describe("InvalidState - redeem", () => {
+    it("rejects a partial redeem when vault is Active", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Base
+      );
+
+      const builder = await client.redeem(wallet.publicKey, ctx.vaultPda, VaultType.Base, {
+        amount: DEPOSIT_AMOUNT / 2,
+      });
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidState"
+      );
+    });
+  });
 });
@@ -1,18 +1,38 @@
 import * as anchor from "@coral-xyz/anchor";
 import { BN } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
 import { expect } from "chai";
 
-import { VaultClient, VaultType, parseVaultState } from "../../../sdk/src";
+import {
+  PRICE_SCALE,
+  VaultClient,
+  VaultType,
+  DepositLimits,
+  ChunkedAction,
+  parseVaultState,
+  VAULT_MAX_OPTIONS,
+  VAULT_MAX_SYMBOL_LEN,
+  VAULT_MAX_OPTION_LABEL_LEN,
+  VAULT_MAX_METADATA_URI_LEN,
+  MAX_PROTOCOL_FEE,
+} from "../../../sdk/src";
 import {
   DEPOSIT_AMOUNT,
   getTestContext,
+  getChainTime,
   createTestMint,
+  createTokenAccount,
   fundOwnerWallet,
   createVaultInSetupState,
   createVaultInActiveState,
   createVaultWithDeposit,
+  createTwapPool,
+  ensureProtocolConfig,
   sendAndLog,
   expectAnchorError,
   expectError,
@@ -72,17 +92,37 @@ describe("Validation Errors", () => {
         "InvalidAmount"
       );
     });
+
+    it("rejects deposit_both with both amounts zero", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await client.depositBoth(wallet.publicKey, ctx.vaultPda, 0, 0);
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidAmount"
+      );
+    });
+
+    it("rejects wrap with zero amount", async () => {
+      const ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+
+      const { builder } = await client.wrap(wallet.publicKey, ctx.vaultPda, VaultType.Base, 0);
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidAmount"
+      );
+    });
   });
 
   describe("OptionLimitReached", () => {
-    it("rejects adding 9th option (exceeds MAX_OPTIONS of 8)", async () => {
-      // Create vault with max options (8)
+    it("rejects adding an option beyond MAX_OPTIONS", async () => {
+      // Create vault with max options
       const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint, {
-        numOptions: 8,
+        numOptions: VAULT_MAX_OPTIONS,
       });
 
       // Try to add one more
-      const { builder } = await client.addOption(wallet.publicKey, ctx.vaultPda);
+      const { builder } = await client.addOption(wallet.publicKey, wallet.publicKey, ctx.vaultPda);
       await expectAnchorError(builder.rpc(), "OptionLimitReached");
     });
   });
@@ -95,7 +135,7 @@ describe("Validation Errors", () => {
 
       // Try to finalize with index 3 (only 0, 1, 2 are valid)
       await expectAnchorError(
-        client.finalize(wallet.publicKey, ctx.vaultPda, 3).rpc(),
+        client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 3).rpc(),
         "IndexOutOfBounds"
       );
     });
@@ -104,7 +144,7 @@ describe("Validation Errors", () => {
       const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
 
       await expectAnchorError(
-        client.finalize(wallet.publicKey, ctx.vaultPda, 255).rpc(),
+        client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 255).rpc(),
         "IndexOutOfBounds"
       );
     });
@@ -115,7 +155,7 @@ describe("Validation Errors", () => {
       });
 
       // Index 4 should be valid for 5 options
-      await client.finalize(wallet.publicKey, ctx.vaultPda, 4).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 4).rpc();
 
       const vault = await client.fetchVault(ctx.vaultPda);
       const { winningIdx } = parseVaultState(vault.state);
@@ -123,6 +163,348 @@ describe("Validation Errors", () => {
     });
   });
 
+  describe("InvalidPayoutWeights", () => {
+    it("rejects weights that don't sum to PAYOUT_WEIGHT_TOTAL", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      await expectAnchorError(
+        client.finalizeWeighted(wallet.publicKey, ctx.vaultPda, [5000, 4999]).rpc(),
+        "InvalidPayoutWeights"
+      );
+    });
+
+    it("rejects a weight vector not matching num_options", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+        numOptions: 3,
+      });
+
+      await expectAnchorError(
+        client.finalizeWeighted(wallet.publicKey, ctx.vaultPda, [5000, 5000]).rpc(),
+        "InvalidPayoutWeights"
+      );
+    });
+  });
+
+  describe("InvalidResolutionDeadline", () => {
+    it("rejects a deadline that isn't in the future", async () => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+      const now = await getChainTime(provider);
+
+      await expectAnchorError(
+        client.setResolutionDeadline(wallet.publicKey, ctx.vaultPda, now - 1).rpc(),
+        "InvalidResolutionDeadline"
+      );
+    });
+  });
+
+  describe("Metadata limits", () => {
+    it("rejects an option label over MAX_OPTION_LABEL_LEN", async () => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+
+      const { builder } = await client.addOption(
+        wallet.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        "",
+        "x".repeat(VAULT_MAX_OPTION_LABEL_LEN + 1)
+      );
+      await expectAnchorError(builder.rpc(), "MetadataLabelTooLong");
+    });
+
+    it("rejects an option URI over MAX_METADATA_URI_LEN", async () => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+
+      const { builder } = await client.addOption(
+        wallet.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        "x".repeat(VAULT_MAX_METADATA_URI_LEN + 1)
+      );
+      await expectAnchorError(builder.rpc(), "MetadataUriTooLong");
+    });
+
+    it("rejects an underlying symbol over MAX_SYMBOL_LEN", async () => {
+      await expectAnchorError(
+        createVaultInSetupState(client, wallet, baseMint, quoteMint, {
+          initOptions: { baseSymbol: "x".repeat(VAULT_MAX_SYMBOL_LEN + 1) },
+        }),
+        "MetadataLabelTooLong"
+      );
+    });
+  });
+
+  describe("InvalidOptionSet", () => {
+    it("rejects a set naming an option the vault doesn't have", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Base,
+        { numOptions: 3 }
+      );
+
+      const { builder } = await client.mergeOptions(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        0b1001,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(builder.rpc(), "InvalidOptionSet");
+    });
+
+    it("rejects a set covering every option", async () => {
+      const ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+
+      const { builder } = await client.mergeOptions(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        0b11,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(builder.rpc(), "InvalidOptionSet");
+    });
+  });
+
+  describe("InvalidParentVault", () => {
+    it("rejects a parent option whose conditional mint isn't the underlying", async () => {
+      const parent = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      const child = await createVaultInSetupState(
+        client,
+        wallet,
+        parent.condBaseMints[0],
+        quoteMint,
+        { initOptions: { baseTokenProgram: TOKEN_2022_PROGRAM_ID } }
+      );
+
+      await expectAnchorError(
+        client
+          .setParent(
+            wallet.publicKey,
+            child.vaultPda,
+            parent.vaultPda,
+            VaultType.Base,
+            VaultType.Base,
+            1
+          )
+          .rpc(),
+        "InvalidParentVault"
+      );
+    });
+  });
+
+  describe("InvalidUserTokenAccount", () => {
+    it("rejects an underlying token account owned by someone else", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      const foreignAccount = await createTokenAccount(
+        provider,
+        wallet,
+        baseMint,
+        Keypair.generate().publicKey
+      );
+
+      const builder = await client.deposit(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT,
+        { tokenAccounts: { userAta: foreignAccount } }
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidUserTokenAccount"
+      );
+    });
+
+    it("rejects a conditional token account owned by someone else", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      const stranger = Keypair.generate().publicKey;
+      const userCondAtas = await Promise.all(
+        ctx.condBaseMints.map((condMint) =>
+          createTokenAccount(provider, wallet, condMint, stranger, TOKEN_2022_PROGRAM_ID)
+        )
+      );
+
+      const builder = await client.deposit(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT,
+        { tokenAccounts: { userCondAtas } }
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "InvalidUserTokenAccount"
+      );
+    });
+  });
+
+  describe("OptionAlreadyProcessed", () => {
+    it("rejects processing a chunk twice", async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const { builder } = await client.startChunked(
+        wallet.publicKey,
+        ctx.vaultPda,
+        VaultType.Base,
+        ChunkedAction.Deposit,
+        DEPOSIT_AMOUNT
+      );
+      await builder.rpc();
+      const first = await client.processChunk(wallet.publicKey, ctx.vaultPda, VaultType.Base, 0, 1);
+      await sendAndLog(first, client, wallet);
+
+      const again = await client.processChunk(wallet.publicKey, ctx.vaultPda, VaultType.Base, 0, 1);
+      await expectAnchorError(
+        sendAndLog(again, client, wallet),
+        "OptionAlreadyProcessed"
+      );
+    });
+  });
+
+  describe("Deposit limits", () => {
+    const createLimitedVault = async (limits: DepositLimits) => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+      await client.setDepositLimits(wallet.publicKey, ctx.vaultPda, limits).rpc();
+      await client.activate(wallet.publicKey, wallet.publicKey, ctx.vaultPda).rpc();
+      return ctx.vaultPda;
+    };
+
+    it("rejects a deposit past the total cap", async () => {
+      const vaultPda = await createLimitedVault({ baseCaps: { total: DEPOSIT_AMOUNT } });
+
+      const builder = await client.deposit(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT + 1
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "DepositCapExceeded"
+      );
+    });
+
+    it("rejects a deposit past the per-user cap, even after withdrawing", async () => {
+      const vaultPda = await createLimitedVault({ baseCaps: { perUser: DEPOSIT_AMOUNT } });
+
+      const deposit = await client.deposit(wallet.publicKey, vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+      await sendAndLog(deposit, client, wallet);
+      const withdraw = await client.withdraw(wallet.publicKey, vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+      await sendAndLog(withdraw, client, wallet);
+
+      const again = await client.deposit(wallet.publicKey, vaultPda, VaultType.Base, 1);
+      await expectAnchorError(
+        sendAndLog(again, client, wallet),
+        "UserDepositCapExceeded"
+      );
+    });
+
+    it("rejects a depositor missing from the allow-list", async () => {
+      const vaultPda = await createLimitedVault({ allowList: true });
+
+      const builder = await client.deposit(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "DepositorNotAllowed"
+      );
+    });
+
+    it("rejects a depositor removed from the allow-list", async () => {
+      const vaultPda = await createLimitedVault({ allowList: true });
+      await client.allowDepositor(wallet.publicKey, wallet.publicKey, vaultPda, wallet.publicKey).rpc();
+      await client
+        .allowDepositor(wallet.publicKey, wallet.publicKey, vaultPda, wallet.publicKey, false)
+        .rpc();
+
+      const builder = await client.deposit(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(
+        sendAndLog(builder, client, wallet),
+        "DepositorNotAllowed"
+      );
+    });
+  });
+
+  describe("InvalidOracleThresholds", () => {
+    let pool: PublicKey;
+
+    before(async () => {
+      pool = await createTwapPool(provider, wallet, baseMint, quoteMint);
+    });
+
+    const configure = async (thresholds: bigint[]) => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint, {
+        numOptions: 3,
+      });
+      const resolveAt = (await getChainTime(provider)) + 3600;
+      return client
+        .configureOracle(wallet.publicKey, wallet.publicKey, ctx.vaultPda, pool, resolveAt, thresholds)
+        .rpc();
+    };
+
+    it("rejects a threshold count that does not match the options", async () => {
+      await expectAnchorError(configure([PRICE_SCALE]), "InvalidOracleThresholds");
+    });
+
+    it("rejects thresholds that are not strictly ascending", async () => {
+      await expectAnchorError(
+        configure([PRICE_SCALE, PRICE_SCALE]),
+        "InvalidOracleThresholds"
+      );
+    });
+  });
+
+  describe("InvalidOraclePool", () => {
+    it("rejects a pool that does not trade the vault's base and quote mints", async () => {
+      const otherMint = await createTestMint(provider, wallet);
+      await fundOwnerWallet(provider, wallet, otherMint);
+      const pool = await createTwapPool(provider, wallet, baseMint, otherMint);
+
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+      const resolveAt = (await getChainTime(provider)) + 3600;
+
+      await expectAnchorError(
+        client
+          .configureOracle(wallet.publicKey, wallet.publicKey, ctx.vaultPda, pool, resolveAt, [
+            PRICE_SCALE,
+          ])
+          .rpc(),
+        "InvalidOraclePool"
+      );
+    });
+  });
+
+  describe("InvalidFee", () => {
+    before(async () => {
+      await ensureProtocolConfig(client, wallet);
+    });
+
+    it("rejects protocol fee rates above MAX_PROTOCOL_FEE", async () => {
+      await expectAnchorError(
+        client
+          .updateProtocolConfig(wallet.publicKey, wallet.publicKey, {
+            deposit: MAX_PROTOCOL_FEE + 1,
+            redeem: 0,
+          })
+          .rpc(),
+        "InvalidFee"
+      );
+    });
+  });
+
   // ==========================================================================
   // Low-Level Tests (crafted transactions for edge cases)
   // ==========================================================================
@@ -139,17 +521,22 @@ describe("Validation Errors", () => {
       // Provide only 1 pair instead of 3 (for 3 options)
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx.vaultPda,
-          mint: vault.baseMint,
+          mint: vault.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts([
           { pubkey: vault.condBaseMints[0], isSigner: false, isWritable: true },
           {
             pubkey: getAssociatedTokenAddressSync(
               vault.condBaseMints[0],
-              wallet.publicKey
+              wallet.publicKey,
+              false,
+              TOKEN_2022_PROGRAM_ID
             ),
             isSigner: false,
             isWritable: true,
@@ -173,7 +560,7 @@ describe("Validation Errors", () => {
       const accounts = condMints.flatMap((m) => [
         { pubkey: m, isSigner: false, isWritable: true },
         {
-          pubkey: getAssociatedTokenAddressSync(m, wallet.publicKey),
+          pubkey: getAssociatedTokenAddressSync(m, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
           isSigner: false,
           isWritable: true,
         },
@@ -181,17 +568,20 @@ describe("Validation Errors", () => {
       // Add extra pair
       accounts.push({ pubkey: fakeMint, isSigner: false, isWritable: true });
       accounts.push({
-        pubkey: getAssociatedTokenAddressSync(fakeMint, wallet.publicKey),
+        pubkey: getAssociatedTokenAddressSync(fakeMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
         isSigner: false,
         isWritable: true,
       });
 
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx.vaultPda,
-          mint: vault.baseMint,
+          mint: vault.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts(accounts);
 
@@ -213,7 +603,7 @@ describe("Validation Errors", () => {
       const accounts = [
         { pubkey: fakeMint, isSigner: false, isWritable: true },
         {
-          pubkey: getAssociatedTokenAddressSync(fakeMint, wallet.publicKey),
+          pubkey: getAssociatedTokenAddressSync(fakeMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
           isSigner: false,
           isWritable: true,
         },
@@ -221,7 +611,9 @@ describe("Validation Errors", () => {
         {
           pubkey: getAssociatedTokenAddressSync(
             vault.condBaseMints[1],
-            wallet.publicKey
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
           ),
           isSigner: false,
           isWritable: true,
@@ -230,10 +622,13 @@ describe("Validation Errors", () => {
 
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx.vaultPda,
-          mint: vault.baseMint,
+          mint: vault.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts(accounts);
 
@@ -254,7 +649,9 @@ describe("Validation Errors", () => {
         {
           pubkey: getAssociatedTokenAddressSync(
             vault.condBaseMints[1],
-            wallet.publicKey
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
           ),
           isSigner: false,
           isWritable: true,
@@ -263,7 +660,9 @@ describe("Validation Errors", () => {
         {
           pubkey: getAssociatedTokenAddressSync(
             vault.condBaseMints[0],
-            wallet.publicKey
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
           ),
           isSigner: false,
           isWritable: true,
@@ -272,10 +671,13 @@ describe("Validation Errors", () => {
 
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx.vaultPda,
-          mint: vault.baseMint,
+          mint: vault.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts(accounts);
 
@@ -298,7 +700,7 @@ describe("Validation Errors", () => {
       const accounts = condMints.flatMap((m) => [
         { pubkey: m, isSigner: false, isWritable: true },
         {
-          pubkey: getAssociatedTokenAddressSync(m, wrongUser),
+          pubkey: getAssociatedTokenAddressSync(m, wrongUser, false, TOKEN_2022_PROGRAM_ID),
           isSigner: false,
           isWritable: true,
         },
@@ -306,10 +708,13 @@ describe("Validation Errors", () => {
 
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx.vaultPda,
-          mint: vault.baseMint,
+          mint: vault.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts(accounts);
 
@@ -369,7 +774,7 @@ describe("Validation Errors", () => {
       const accounts = condMints.flatMap((m) => [
         { pubkey: m, isSigner: false, isWritable: true },
         {
-          pubkey: getAssociatedTokenAddressSync(m, wallet.publicKey),
+          pubkey: getAssociatedTokenAddressSync(m, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
           isSigner: false,
           isWritable: true,
         },
@@ -377,10 +782,13 @@ describe("Validation Errors", () => {
 
       const builder = client.program.methods
         .deposit(vaultTypeArg, new BN(DEPOSIT_AMOUNT))
-        .accounts({
+        .accountsPartial({
           signer: wallet.publicKey,
           vault: ctx2.vaultPda, // Different vault!
-          mint: vault1.baseMint,
+          mint: vault1.baseMint.address,
+          userAta: getAssociatedTokenAddressSync(vault1.baseMint.address, wallet.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          conditionalTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .remainingAccounts(accounts);
 
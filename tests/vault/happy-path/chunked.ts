@@ -0,0 +1,194 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType, ChunkedAction } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultInActiveState,
+  sendAndLog,
+  createFundedUser,
+  createUserClient,
+  expectNumOptions,
+  expectCondBalances,
+  expectVaultBalance,
+} from "../helpers";
+
+describe("Chunked Deposits and Withdrawals", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  // Too many options for a single deposit's remaining accounts
+  const NUM_OPTIONS = 20;
+  const CHUNK_SIZE = 5;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let vaultPda: PublicKey;
+
+  const processAll = async (from = 0) => {
+    for (let start = from; start < NUM_OPTIONS; start += CHUNK_SIZE) {
+      const builder = await client.processChunk(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        start,
+        CHUNK_SIZE
+      );
+      await sendAndLog(builder, client, wallet);
+    }
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+      numOptions: NUM_OPTIONS,
+    });
+    vaultPda = ctx.vaultPda;
+  });
+
+  it("grows past the old 8-option limit", async () => {
+    await expectNumOptions(client, vaultPda, NUM_OPTIONS);
+    const vault = await client.fetchVault(vaultPda);
+    expect(vault.condBaseMints).to.have.length(NUM_OPTIONS);
+  });
+
+  it("deposits in chunks", async () => {
+    const { builder } = await client.startChunked(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      ChunkedAction.Deposit,
+      DEPOSIT_AMOUNT
+    );
+    await builder.rpc();
+    await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+
+    await processAll();
+
+    await expectCondBalances(
+      client,
+      vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(NUM_OPTIONS).fill(DEPOSIT_AMOUNT)
+    );
+    expect(await client.fetchChunkProgress(vaultPda, wallet.publicKey, VaultType.Base)).to.be.null;
+  });
+
+  it("withdraws in chunks, paying out on the last one", async () => {
+    const { builder } = await client.startChunked(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      ChunkedAction.Withdraw,
+      DEPOSIT_AMOUNT / 2
+    );
+    await builder.rpc();
+
+    await processAll();
+
+    await expectCondBalances(
+      client,
+      vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(NUM_OPTIONS).fill(DEPOSIT_AMOUNT / 2)
+    );
+    await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 2);
+  });
+
+  it("cancels a partly processed deposit and refunds it", async () => {
+    const { builder } = await client.startChunked(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      ChunkedAction.Deposit,
+      DEPOSIT_AMOUNT
+    );
+    await builder.rpc();
+
+    const process = await client.processChunk(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      0,
+      CHUNK_SIZE
+    );
+    await sendAndLog(process, client, wallet);
+    const progress = await client.fetchChunkProgress(vaultPda, wallet.publicKey, VaultType.Base);
+    expect(progress!.processed.toNumber()).to.equal((1 << CHUNK_SIZE) - 1);
+
+    const cancel = await client.cancelChunked(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      0,
+      CHUNK_SIZE
+    );
+    await sendAndLog(cancel, client, wallet);
+
+    expect(await client.fetchChunkProgress(vaultPda, wallet.publicKey, VaultType.Base)).to.be.null;
+    await expectCondBalances(
+      client,
+      vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(NUM_OPTIONS).fill(DEPOSIT_AMOUNT / 2)
+    );
+    await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 2);
+  });
+
+  it("lets anyone cancel a half-burned withdrawal once the vault resolves", async () => {
+    const { builder } = await client.startChunked(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      ChunkedAction.Withdraw,
+      DEPOSIT_AMOUNT / 2
+    );
+    await builder.rpc();
+
+    const process = await client.processChunk(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      0,
+      CHUNK_SIZE
+    );
+    await sendAndLog(process, client, wallet);
+
+    await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc();
+
+    // The withdrawal can't finish on a resolved vault; a stranger returns the burned tokens
+    const stranger = await createFundedUser(provider, wallet, baseMint, quoteMint);
+    const strangerClient = createUserClient(provider, stranger.keypair);
+    const cancel = await strangerClient.cancelChunked(
+      stranger.keypair.publicKey,
+      vaultPda,
+      VaultType.Base,
+      0,
+      CHUNK_SIZE,
+      wallet.publicKey
+    );
+    await sendAndLog(cancel, strangerClient, stranger.wallet);
+
+    expect(await client.fetchChunkProgress(vaultPda, wallet.publicKey, VaultType.Base)).to.be.null;
+    await expectCondBalances(
+      client,
+      vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(NUM_OPTIONS).fill(DEPOSIT_AMOUNT / 2)
+    );
+    await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 2);
+
+    const vault = await client.fetchVault(vaultPda);
+    expect(vault.pendingChunks).to.equal(0);
+  });
+});
@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultInActiveState,
+  sendAndLog,
+  expectCondBalances,
+  expectVaultBalance,
+} from "../helpers";
+
+describe("Deposit and Withdraw Both", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const BASE_AMOUNT = DEPOSIT_AMOUNT;
+  const QUOTE_AMOUNT = DEPOSIT_AMOUNT * 2;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+  });
+
+  describe("6 options", () => {
+    const numOptions = 6;
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+        numOptions,
+      });
+      vaultPda = ctx.vaultPda;
+    });
+
+    it("splits base and quote in one instruction", async () => {
+      const builder = await client.depositBoth(
+        wallet.publicKey,
+        vaultPda,
+        BASE_AMOUNT,
+        QUOTE_AMOUNT
+      );
+      await sendAndLog(builder, client, wallet, "deposit_both (6 options)");
+
+      await expectCondBalances(
+        client,
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Base,
+        Array(numOptions).fill(BASE_AMOUNT)
+      );
+      await expectCondBalances(
+        client,
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Quote,
+        Array(numOptions).fill(QUOTE_AMOUNT)
+      );
+      await expectVaultBalance(client, vaultPda, VaultType.Base, BASE_AMOUNT);
+      await expectVaultBalance(client, vaultPda, VaultType.Quote, QUOTE_AMOUNT);
+    });
+
+    it("merges base and quote back in one instruction", async () => {
+      const builder = await client.withdrawBoth(
+        wallet.publicKey,
+        vaultPda,
+        BASE_AMOUNT / 2,
+        QUOTE_AMOUNT / 2
+      );
+      await sendAndLog(builder, client, wallet, "withdraw_both (6 options)");
+
+      await expectCondBalances(
+        client,
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Base,
+        Array(numOptions).fill(BASE_AMOUNT / 2)
+      );
+      await expectCondBalances(
+        client,
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Quote,
+        Array(numOptions).fill(QUOTE_AMOUNT / 2)
+      );
+      await expectVaultBalance(client, vaultPda, VaultType.Base, BASE_AMOUNT / 2);
+      await expectVaultBalance(client, vaultPda, VaultType.Quote, QUOTE_AMOUNT / 2);
+    });
+  });
+
+  describe("one side zero", () => {
+    it("only touches the non-zero side", async () => {
+      const { vaultPda } = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+      const builder = await client.depositBoth(wallet.publicKey, vaultPda, 0, QUOTE_AMOUNT);
+      await sendAndLog(builder, client, wallet);
+
+      await expectCondBalances(client, vaultPda, wallet.publicKey, VaultType.Base, [0, 0]);
+      await expectCondBalances(client, vaultPda, wallet.publicKey, VaultType.Quote, [
+        QUOTE_AMOUNT,
+        QUOTE_AMOUNT,
+      ]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+    });
+  });
+});
@@ -0,0 +1,92 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, transfer } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  sendAndLog,
+  redeemAndMeasure,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Escrow Totals and Close", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const withdrawAmount = DEPOSIT_AMOUNT / 4;
+  const donation = 5;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+  });
+
+  it("tracks deposits and withdrawals per vault type", async () => {
+    const builder = await client.withdraw(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      withdrawAmount
+    );
+    await sendAndLog(builder, client, wallet);
+
+    const vault = await client.fetchVault(ctx.vaultPda);
+    expect(vault.baseTotals.deposited.toNumber()).to.equal(DEPOSIT_AMOUNT);
+    expect(vault.baseTotals.withdrawn.toNumber()).to.equal(withdrawAmount);
+    expect(vault.quoteTotals.deposited.toNumber()).to.equal(0);
+  });
+
+  it("tracks redemptions", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 1).rpc();
+
+    const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT - withdrawAmount);
+
+    const vault = await client.fetchVault(ctx.vaultPda);
+    expect(vault.baseTotals.redeemed.toNumber()).to.equal(DEPOSIT_AMOUNT - withdrawAmount);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+  });
+
+  it("closes the vault, its mints and escrows, sweeping leftover escrow", async () => {
+    // Tokens sent straight to the escrow aren't backed by any conditional supply
+    const vaultAta = await client.fetchVaultATA(ctx.vaultPda, VaultType.Base);
+    const ownerAta = getAssociatedTokenAddressSync(baseMint, wallet.publicKey);
+    await transfer(
+      provider.connection,
+      wallet.payer,
+      ownerAta,
+      vaultAta,
+      wallet.publicKey,
+      donation
+    );
+
+    const balance = async () =>
+      Number((await provider.connection.getTokenAccountBalance(ownerAta)).value.amount);
+    const before = await balance();
+
+    const builder = await client.closeVault(wallet.publicKey, ctx.vaultPda);
+    await builder.rpc();
+
+    // Leftover escrow is swept to the owner's ATA
+    expect((await balance()) - before).to.equal(donation);
+
+    expect(await client.program.account.vaultAccount.fetchNullable(ctx.vaultPda)).to.be.null;
+    expect(await provider.connection.getAccountInfo(vaultAta)).to.be.null;
+    for (const mint of [...ctx.condBaseMints, ...ctx.condQuoteMints]) {
+      expect(await provider.connection.getAccountInfo(mint)).to.be.null;
+    }
+  });
+});
@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultEvent, VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultInActiveState,
+  sendAndLog,
+  expectError,
+  VaultTestContext,
+} from "../helpers";
+
+// Escrow balance and per-option supply must be reconstructable from logs alone
+describe("Vault Events", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+
+  const userCondAtas = () =>
+    ctx.condBaseMints.map((mint) =>
+      getAssociatedTokenAddressSync(mint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID)
+    );
+
+  const eventsNamed = <N extends VaultEvent["name"]>(events: VaultEvent[], name: N) =>
+    events.filter((e): e is Extract<VaultEvent, { name: N }> => e.name === name);
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+  });
+
+  it("reports post-deposit escrow, supply and created accounts", async () => {
+    const builder = await client.deposit(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      DEPOSIT_AMOUNT
+    );
+    const sig = await sendAndLog(builder, client, wallet);
+    const events = await client.fetchEvents(sig);
+
+    const [deposit] = eventsNamed(events, "VaultDeposit");
+    expect(deposit.data.vault.equals(ctx.vaultPda)).to.be.true;
+    expect(deposit.data.amount.toNumber()).to.equal(DEPOSIT_AMOUNT);
+    expect(deposit.data.escrowBalance.toNumber()).to.equal(DEPOSIT_AMOUNT);
+    expect(deposit.data.condSupply.map((s) => s.toNumber())).to.deep.equal([
+      DEPOSIT_AMOUNT,
+      DEPOSIT_AMOUNT,
+    ]);
+    expect(deposit.data.slot.toNumber()).to.be.greaterThan(0);
+
+    const created = eventsNamed(events, "TokenAccountCreated").map((e) =>
+      e.data.account.toBase58()
+    );
+    expect(created).to.include.members(userCondAtas().map((ata) => ata.toBase58()));
+  });
+
+  it("reports post-withdrawal escrow and supply", async () => {
+    const builder = await client.withdraw(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      DEPOSIT_AMOUNT / 2
+    );
+    const events = await client.fetchEvents(await sendAndLog(builder, client, wallet));
+
+    const [withdrawal] = eventsNamed(events, "VaultWithdrawal");
+    expect(withdrawal.data.escrowBalance.toNumber()).to.equal(DEPOSIT_AMOUNT / 2);
+    expect(withdrawal.data.condSupply.map((s) => s.toNumber())).to.deep.equal([
+      DEPOSIT_AMOUNT / 2,
+      DEPOSIT_AMOUNT / 2,
+    ]);
+  });
+
+  it("reports the emptied escrow and closed accounts on redemption", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
+
+    const builder = await client.redeemWinnings(wallet.publicKey, ctx.vaultPda, VaultType.Base);
+    const events = await client.fetchEvents(await sendAndLog(builder, client, wallet));
+
+    const [redeemed] = eventsNamed(events, "WinningsRedeemed");
+    expect(redeemed.data.amount.toNumber()).to.equal(DEPOSIT_AMOUNT / 2);
+    expect(redeemed.data.escrowBalance.toNumber()).to.equal(0);
+    expect(redeemed.data.condSupply.map((s) => s.toNumber())).to.deep.equal([0, 0]);
+
+    const closed = eventsNamed(events, "TokenAccountClosed").map((e) =>
+      e.data.account.toBase58()
+    );
+    expect(closed).to.have.members(userCondAtas().map((ata) => ata.toBase58()));
+  });
+
+  it("rebuilds escrow and supply from a log sequence", async () => {
+    const fresh = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+    const steps = [
+      await client.deposit(wallet.publicKey, fresh.vaultPda, VaultType.Base, DEPOSIT_AMOUNT),
+      await client.deposit(wallet.publicKey, fresh.vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 2),
+      await client.withdraw(wallet.publicKey, fresh.vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 4),
+    ];
+    const log: VaultEvent[] = [];
+    for (const builder of steps) {
+      log.push(...(await client.fetchEvents(await sendAndLog(builder, client, wallet))));
+    }
+    await client.finalize(wallet.publicKey, wallet.publicKey, fresh.vaultPda, 0).rpc();
+    const redeem = await client.redeemWinnings(wallet.publicKey, fresh.vaultPda, VaultType.Base);
+    log.push(...(await client.fetchEvents(await sendAndLog(redeem, client, wallet))));
+
+    // Replay the amounts only; the post-state fields are what the replay is checked against
+    let escrow = 0;
+    let supply = 0;
+    let lastSlot = 0;
+    for (const event of log) {
+      switch (event.name) {
+        case "VaultDeposit":
+          escrow += event.data.amount.toNumber() + event.data.fee.toNumber();
+          supply += event.data.amount.toNumber();
+          break;
+        case "VaultWithdrawal":
+          escrow -= event.data.amount.toNumber();
+          supply -= event.data.amount.toNumber();
+          break;
+        case "WinningsRedeemed":
+          escrow -= event.data.amount.toNumber();
+          supply = 0;
+          break;
+        default:
+          continue;
+      }
+      expect(event.data.escrowBalance.toNumber()).to.equal(escrow);
+      expect(event.data.condSupply.map((s) => s.toNumber())).to.deep.equal([supply, supply]);
+      expect(event.data.slot.toNumber()).to.be.at.least(lastSlot);
+      lastSlot = event.data.slot.toNumber();
+    }
+
+    const vaultAta = getAssociatedTokenAddressSync(baseMint, fresh.vaultPda, true);
+    const onChain = await provider.connection.getTokenAccountBalance(vaultAta);
+    expect(Number(onChain.value.amount)).to.equal(escrow);
+    for (const mint of fresh.condBaseMints) {
+      const { value } = await provider.connection.getTokenSupply(mint);
+      expect(Number(value.amount)).to.equal(supply);
+    }
+  });
+
+  it("rejects fetching events of an unknown transaction", async () => {
+    const signature = anchor.utils.bytes.bs58.encode(Keypair.generate().secretKey);
+    await expectError(client.fetchEvents(signature), "not found");
+  });
+});
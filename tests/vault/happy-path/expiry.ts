@@ -0,0 +1,85 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType, VaultState } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  DEADLINE_DELAY,
+  ONE_TOKEN,
+  getTestContext,
+  getChainTime,
+  waitForChainTime,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultInSetupState,
+  sendAndLog,
+  redeemAndMeasure,
+  expectAnchorError,
+  expectVaultState,
+  expectVaultBalance,
+} from "../helpers";
+
+describe("Resolution Deadline", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let vaultPda: PublicKey;
+  let deadline: number;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+    vaultPda = ctx.vaultPda;
+
+    deadline = (await getChainTime(provider)) + DEADLINE_DELAY;
+    await client.setResolutionDeadline(wallet.publicKey, vaultPda, deadline).rpc();
+    await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
+
+    const builder = await client.deposit(
+      wallet.publicKey,
+      vaultPda,
+      VaultType.Base,
+      DEPOSIT_AMOUNT
+    );
+    await sendAndLog(builder, client, wallet);
+  });
+
+  it("stores the deadline on the vault", async () => {
+    const vault = await client.fetchVault(vaultPda);
+    expect(vault.resolutionDeadline?.toNumber()).to.equal(deadline);
+  });
+
+  it("rejects expire before the deadline", async () => {
+    await expectAnchorError(client.expire(vaultPda).rpc(), "ResolutionDeadlineNotReached");
+  });
+
+  it("rejects finalize once the deadline has passed", async () => {
+    await waitForChainTime(provider, deadline);
+
+    await expectAnchorError(
+      client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc(),
+      "ResolutionDeadlinePassed"
+    );
+  });
+
+  it("lets anyone expire the vault into Voided", async () => {
+    const stranger = await createFundedUser(provider, wallet, baseMint, quoteMint, ONE_TOKEN);
+    const strangerClient = createUserClient(provider, stranger.keypair);
+
+    await strangerClient.expire(vaultPda).rpc();
+    await expectVaultState(client, vaultPda, VaultState.Voided);
+  });
+
+  it("refunds the full deposit", async () => {
+    const paid = await redeemAndMeasure(client, wallet, vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT);
+    await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+  });
+});
@@ -61,7 +61,7 @@ describe("Vault Lifecycle", () => {
         // Add additional options to reach target
         for (let i = 2; i < numOptions; i++) {
           const { builder: addBuilder, condBaseMint, condQuoteMint } =
-            await client.addOption(wallet.publicKey, vaultPda);
+            await client.addOption(wallet.publicKey, wallet.publicKey, vaultPda);
           await addBuilder.rpc();
           condBaseMints.push(condBaseMint);
           condQuoteMints.push(condQuoteMint);
@@ -72,8 +72,8 @@ describe("Vault Lifecycle", () => {
         it("verifies vault initialized with correct options", async () => {
           const vault = await client.fetchVault(vaultPda);
           expect(vault.owner.toBase58()).to.equal(wallet.publicKey.toBase58());
-          expect(vault.baseMint.toBase58()).to.equal(baseMint.toBase58());
-          expect(vault.quoteMint.toBase58()).to.equal(quoteMint.toBase58());
+          expect(vault.baseMint.address.toBase58()).to.equal(baseMint.toBase58());
+          expect(vault.quoteMint.address.toBase58()).to.equal(quoteMint.toBase58());
           expect(vault.numOptions).to.equal(numOptions);
           const { state } = parseVaultState(vault.state);
           expect(state).to.equal(VaultState.Setup);
@@ -81,7 +81,7 @@ describe("Vault Lifecycle", () => {
         });
 
         it("activates vault", async () => {
-          await client.activate(wallet.publicKey, vaultPda).rpc();
+          await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
           await expectVaultState(client, vaultPda, VaultState.Active);
         });
       });
@@ -164,7 +164,7 @@ describe("Vault Lifecycle", () => {
         const winningIdx = 0;
 
         it("finalizes vault with winning option", async () => {
-          await client.finalize(wallet.publicKey, vaultPda, winningIdx).rpc();
+          await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, winningIdx).rpc();
 
           await expectVaultState(client, vaultPda, VaultState.Finalized);
           await expectWinningIndex(client, vaultPda, winningIdx);
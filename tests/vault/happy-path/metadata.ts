@@ -0,0 +1,96 @@
+import { PublicKey } from "@solana/web3.js";
+import { getTokenMetadata, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import {
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultInSetupState,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Conditional Mint Metadata", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+
+  const fetchMetadata = async (mint: PublicKey) => {
+    const metadata = await getTokenMetadata(
+      provider.connection,
+      mint,
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(metadata, `No token metadata on ${mint.toBase58()}`).to.not.be.null;
+    return metadata!;
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+  });
+
+  describe("with owner-supplied symbols and labels", () => {
+    const uri = "https://example.com/options/2.json";
+    let ctx: VaultTestContext;
+
+    before(async () => {
+      ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint, {
+        initOptions: {
+          baseSymbol: "ZC",
+          quoteSymbol: "USDC",
+          optionLabels: ["PASS", "FAIL"],
+        },
+      });
+    });
+
+    it("names conditional mints after symbol, nonce and label", async () => {
+      const base0 = await fetchMetadata(ctx.condBaseMints[0]);
+      expect(base0.name).to.equal(`cZC-${ctx.nonce}-PASS`);
+      expect(base0.symbol).to.equal("cZC");
+
+      const quote1 = await fetchMetadata(ctx.condQuoteMints[1]);
+      expect(quote1.name).to.equal(`cUSDC-${ctx.nonce}-FAIL`);
+      expect(quote1.symbol).to.equal("cUSDC");
+    });
+
+    it("stores the symbols on the vault", async () => {
+      const vault = await client.fetchVault(ctx.vaultPda);
+      expect(vault.baseSymbol).to.equal("ZC");
+      expect(vault.quoteSymbol).to.equal("USDC");
+    });
+
+    it("sets the owner's URI and label on added options", async () => {
+      const { builder, condBaseMint, condQuoteMint } = await client.addOption(
+        wallet.publicKey,
+        wallet.publicKey,
+        ctx.vaultPda,
+        uri,
+        "MAYBE"
+      );
+      await builder.rpc();
+
+      const base2 = await fetchMetadata(condBaseMint);
+      expect(base2.name).to.equal(`cZC-${ctx.nonce}-MAYBE`);
+      expect(base2.uri).to.equal(uri);
+
+      const quote2 = await fetchMetadata(condQuoteMint);
+      expect(quote2.uri).to.equal(uri);
+    });
+  });
+
+  describe("without symbols or labels", () => {
+    it("falls back to the mint address prefix and option index", async () => {
+      const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+      const ticker = baseMint.toBase58().slice(0, 4);
+
+      const base1 = await fetchMetadata(ctx.condBaseMints[1]);
+      expect(base1.name).to.equal(`c${ticker}-${ctx.nonce}-1`);
+      expect(base1.symbol).to.equal(`c${ticker}`);
+    });
+  });
+});
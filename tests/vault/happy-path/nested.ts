@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  createVaultInSetupState,
+  sendAndLog,
+  redeemAndMeasure,
+  expectAnchorError,
+  expectCondBalances,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Nested Vaults", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  // Parent option 0 conditional tokens escrowed in the child
+  const NESTED = DEPOSIT_AMOUNT / 2;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let parent: VaultTestContext;
+  let child: VaultTestContext;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    parent = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+
+    // Child's base underlying is the parent's option 0 base conditional mint
+    child = await createVaultInSetupState(client, wallet, parent.condBaseMints[0], quoteMint, {
+      initOptions: { baseTokenProgram: TOKEN_2022_PROGRAM_ID },
+    });
+  });
+
+  it("links the child to its parent during Setup", async () => {
+    await client
+      .setParent(wallet.publicKey, child.vaultPda, parent.vaultPda, VaultType.Base, VaultType.Base, 0)
+      .rpc();
+
+    const vault = await client.fetchVault(child.vaultPda);
+    expect(vault.baseParent?.vault.equals(parent.vaultPda)).to.be.true;
+    expect(vault.baseParent?.option).to.equal(0);
+    expect(vault.quoteParent).to.be.null;
+  });
+
+  it("escrows parent conditional tokens as the child's underlying", async () => {
+    await client.activate(wallet.publicKey, wallet.publicKey, child.vaultPda).rpc();
+
+    const builder = await client.deposit(wallet.publicKey, child.vaultPda, VaultType.Base, NESTED);
+    await sendAndLog(builder, client, wallet);
+
+    await expectCondBalances(client, child.vaultPda, wallet.publicKey, VaultType.Base, [
+      NESTED,
+      NESTED,
+    ]);
+    await expectVaultBalance(client, child.vaultPda, VaultType.Base, NESTED);
+  });
+
+  it("rejects nested redemption until the parent is resolved", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, child.vaultPda, 0).rpc();
+
+    const builder = await client.redeemNested(wallet.publicKey, child.vaultPda, VaultType.Base);
+    await expectAnchorError(builder.rpc(), "InvalidState");
+  });
+
+  it("unwinds child winnings into the parent's underlying", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, parent.vaultPda, 0).rpc();
+
+    const { userBalance: before } = await client.fetchUserBalances(
+      parent.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    const builder = await client.redeemNested(wallet.publicKey, child.vaultPda, VaultType.Base);
+    await sendAndLog(builder, client, wallet);
+    const { userBalance: after } = await client.fetchUserBalances(
+      parent.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+
+    expect(after.sub(before).toNumber()).to.equal(NESTED);
+    await expectVaultBalance(client, child.vaultPda, VaultType.Base, 0);
+  });
+
+  it("leaves the parent escrow covering its direct holders", async () => {
+    const paid = await redeemAndMeasure(client, wallet, parent.vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT - NESTED);
+    await expectVaultBalance(client, parent.vaultPda, VaultType.Base, 0);
+  });
+});
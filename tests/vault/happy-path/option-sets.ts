@@ -0,0 +1,133 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAccount, getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  sendAndLog,
+  redeemAndMeasure,
+  expectCondBalances,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Option Sets", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  // Options {0, 1} of three
+  const MASK = 0b011;
+  const MERGED = DEPOSIT_AMOUNT / 2;
+  const SPLIT = DEPOSIT_AMOUNT / 4;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+  let setMint: PublicKey;
+
+  const setBalance = async () => {
+    const ata = getAssociatedTokenAddressSync(setMint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const acc = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+    return Number(acc.amount);
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultWithDeposit(
+      client,
+      wallet,
+      baseMint,
+      quoteMint,
+      DEPOSIT_AMOUNT,
+      VaultType.Base,
+      { numOptions: 3 }
+    );
+  });
+
+  it("merges options into a set's OR-position", async () => {
+    const merge = await client.mergeOptions(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      MASK,
+      MERGED
+    );
+    setMint = merge.setMint;
+    await sendAndLog(merge.builder, client, wallet);
+
+    expect(setMint.equals(client.deriveSetMint(ctx.vaultPda, VaultType.Base, MASK)[0])).to.be.true;
+    expect(await setBalance()).to.equal(MERGED);
+    expect((await client.fetchVault(ctx.vaultPda)).extraMints).to.equal(1);
+    await expectCondBalances(client, ctx.vaultPda, wallet.publicKey, VaultType.Base, [
+      DEPOSIT_AMOUNT - MERGED,
+      DEPOSIT_AMOUNT - MERGED,
+      DEPOSIT_AMOUNT,
+    ]);
+  });
+
+  it("splits part of the set back into its options", async () => {
+    const builder = await client.splitOptionSet(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      MASK,
+      SPLIT
+    );
+    await sendAndLog(builder, client, wallet);
+
+    expect(await setBalance()).to.equal(MERGED - SPLIT);
+    await expectCondBalances(client, ctx.vaultPda, wallet.publicKey, VaultType.Base, [
+      DEPOSIT_AMOUNT - MERGED + SPLIT,
+      DEPOSIT_AMOUNT - MERGED + SPLIT,
+      DEPOSIT_AMOUNT,
+    ]);
+  });
+
+  it("redeems the set in full when one of its options wins", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 1).rpc();
+
+    const { userBalance: before } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    const builder = await client.redeemOptionSet(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      MASK
+    );
+    await sendAndLog(builder, client, wallet);
+    const { userBalance: after } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+
+    expect(after.sub(before).toNumber()).to.equal(MERGED - SPLIT);
+  });
+
+  it("keeps the escrow whole for the remaining winners", async () => {
+    const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT - MERGED + SPLIT);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+  });
+
+  it("closes the set mint along with the vault", async () => {
+    const extraMints = await client.fetchExtraMints(ctx.vaultPda);
+    expect(extraMints.map((m) => m.toBase58())).to.deep.equal([setMint.toBase58()]);
+
+    const builder = await client.closeVault(wallet.publicKey, ctx.vaultPda);
+    await sendAndLog(builder, client, wallet);
+
+    expect(await provider.connection.getAccountInfo(setMint)).to.be.null;
+  });
+});
@@ -0,0 +1,105 @@
+import { PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+
+import { AMMClient, PRICE_SCALE, VaultState, VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  DEADLINE_DELAY,
+  getTestContext,
+  getChainTime,
+  waitForChainTime,
+  sleep,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultInSetupState,
+  createTwapPool,
+  sendAndLog,
+  redeemAndMeasure,
+  expectAnchorError,
+  expectVaultState,
+  expectWinningIndex,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Oracle Resolution", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  // Pool price sits at PRICE_SCALE, between the two thresholds -> option 1 wins
+  const THRESHOLDS = [PRICE_SCALE / 2n, PRICE_SCALE * 2n];
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let pool: PublicKey;
+  let ctx: VaultTestContext;
+  let resolveAt: number;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    pool = await createTwapPool(provider, wallet, baseMint, quoteMint);
+    ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint, { numOptions: 3 });
+  });
+
+  it("configures the vault to resolve from the pool's TWAP", async () => {
+    resolveAt = (await getChainTime(provider)) + DEADLINE_DELAY;
+    await client
+      .configureOracle(wallet.publicKey, wallet.publicKey, ctx.vaultPda, pool, resolveAt, THRESHOLDS)
+      .rpc();
+
+    const vault = await client.fetchVault(ctx.vaultPda);
+    expect(vault.oracleResolved).to.be.true;
+
+    const config = await client.fetchOracleConfig(ctx.vaultPda);
+    expect(config!.pool.equals(pool)).to.be.true;
+    expect(config!.resolveAt.toNumber()).to.equal(resolveAt);
+    expect(config!.thresholds.map((t: BN) => t.toString())).to.deep.equal(
+      THRESHOLDS.map((t) => t.toString())
+    );
+
+    await client.activate(wallet.publicKey, wallet.publicKey, ctx.vaultPda).rpc();
+    const deposit = await client.deposit(wallet.publicKey, ctx.vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+    await sendAndLog(deposit, client, wallet);
+  });
+
+  it("does not let the owner finalize it", async () => {
+    await expectAnchorError(
+      client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc(),
+      "OracleResolved"
+    );
+  });
+
+  it("does not let the owner void it", async () => {
+    await expectAnchorError(client.voidVault(wallet.publicKey, ctx.vaultPda).rpc(), "OracleResolved");
+  });
+
+  it("rejects resolve before the pool ceases trading", async () => {
+    await expectAnchorError((await client.resolve(ctx.vaultPda)).rpc(), "OracleNotReady");
+  });
+
+  it("resolves to the option whose threshold range holds the TWAP", async () => {
+    // TWAP only records once MIN_RECORDING_INTERVAL (60s) has passed since pool creation
+    await sleep(62000);
+    await waitForChainTime(provider, resolveAt);
+    const amm = new AMMClient(provider);
+    await amm.ceaseTrading(wallet.publicKey, pool).rpc();
+
+    // A swap after resolve_at would push the price out of option 1's range, but the
+    // ceased pool rejects it and the snapshot the vault resolves from stays put
+    const swap = await amm.swap(wallet.publicKey, pool, true, DEPOSIT_AMOUNT * 10, 1);
+    await expectAnchorError(swap.rpc(), "InvalidState");
+
+    await (await client.resolve(ctx.vaultPda)).rpc();
+
+    await expectVaultState(client, ctx.vaultPda, VaultState.Finalized);
+    await expectWinningIndex(client, ctx.vaultPda, 1);
+  });
+
+  it("pays out the winning option", async () => {
+    const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT);
+  });
+});
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType, RedeemOptions } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  sendAndLog,
+  expectCondBalances,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Partial Redemption", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const FIRST = DEPOSIT_AMOUNT / 4;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+
+  const condAtaExists = async (option: number) => {
+    const ata = getAssociatedTokenAddressSync(
+      ctx.condBaseMints[option],
+      wallet.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    return (await provider.connection.getAccountInfo(ata)) !== null;
+  };
+
+  const redeemMeasured = async (options: RedeemOptions) => {
+    const { userBalance: before } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    const builder = await client.redeem(wallet.publicKey, ctx.vaultPda, VaultType.Base, options);
+    await sendAndLog(builder, client, wallet);
+    const { userBalance: after } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    return after.sub(before).toNumber();
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
+  });
+
+  it("redeems part of the winning tokens and burns the losers, keeping accounts open", async () => {
+    const paid = await redeemMeasured({ amount: FIRST });
+
+    expect(paid).to.equal(FIRST);
+    await expectCondBalances(client, ctx.vaultPda, wallet.publicKey, VaultType.Base, [
+      DEPOSIT_AMOUNT - FIRST,
+      0,
+    ]);
+    expect(await condAtaExists(0)).to.be.true;
+    expect(await condAtaExists(1)).to.be.true;
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, DEPOSIT_AMOUNT - FIRST);
+  });
+
+  it("redeems the rest and closes the emptied accounts on request", async () => {
+    const paid = await redeemMeasured({ closeAccounts: true });
+
+    expect(paid).to.equal(DEPOSIT_AMOUNT - FIRST);
+    expect(await condAtaExists(0)).to.be.false;
+    expect(await condAtaExists(1)).to.be.false;
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+  });
+});
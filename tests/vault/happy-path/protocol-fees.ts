@@ -0,0 +1,117 @@
+import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { FeeRates, VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createUserClient,
+  ensureProtocolConfig,
+  createVaultWithDeposit,
+  createVaultInSetupState,
+  sendAndLog,
+  redeemAndMeasure,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+// The protocol config is a program-wide singleton on the shared validator:
+// rates are reset to zero afterwards so other suites see fee-free vaults
+describe("Protocol Fees", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const RATES: FeeRates = { deposit: 100, redeem: 200 }; // 1% and 2%
+  const NO_FEES: FeeRates = { deposit: 0, redeem: 0 };
+
+  const DEPOSIT_FEE = DEPOSIT_AMOUNT / 100;
+  const MINTED = DEPOSIT_AMOUNT - DEPOSIT_FEE;
+  const REDEEM_FEE = (MINTED * 2) / 100;
+
+  const feeRecipient = Keypair.generate().publicKey;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    await ensureProtocolConfig(client, wallet);
+    await client.updateProtocolConfig(wallet.publicKey, feeRecipient, RATES).rpc();
+  });
+
+  after(async () => {
+    await client.updateProtocolConfig(wallet.publicKey, wallet.publicKey, NO_FEES).rpc();
+  });
+
+  it("copies the configured rates into vaults at initialize", async () => {
+    ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+
+    const vault = await client.fetchVault(ctx.vaultPda);
+    expect(vault.feeRates).to.deep.equal(RATES);
+    expect(vault.baseTotals.fees.toNumber()).to.equal(DEPOSIT_FEE);
+
+    const { condBalances } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    expect(condBalances.map((b) => b.toNumber())).to.deep.equal([MINTED, MINTED]);
+  });
+
+  it("never applies rate changes to live vaults", async () => {
+    await client.updateProtocolConfig(wallet.publicKey, feeRecipient, NO_FEES).rpc();
+
+    const fresh = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+    expect((await client.fetchVault(fresh.vaultPda)).feeRates).to.deep.equal(NO_FEES);
+    expect((await client.fetchVault(ctx.vaultPda)).feeRates).to.deep.equal(RATES);
+  });
+
+  it("charges the redeem fee on payouts", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
+
+    const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+    expect(paid).to.equal(MINTED - REDEEM_FEE);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, DEPOSIT_FEE + REDEEM_FEE);
+  });
+
+  it("sweeps accrued fees to the fee recipient", async () => {
+    const builder = await client.collectFees(wallet.publicKey, ctx.vaultPda, VaultType.Base);
+    await sendAndLog(builder, client, wallet);
+
+    const recipientAta = getAssociatedTokenAddressSync(baseMint, feeRecipient, true);
+    const acc = await getAccount(provider.connection, recipientAta);
+    expect(Number(acc.amount)).to.equal(DEPOSIT_FEE + REDEEM_FEE);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+    expect((await client.fetchVault(ctx.vaultPda)).baseTotals.fees.toNumber()).to.equal(0);
+  });
+
+  it("hands the admin role over in two steps", async () => {
+    const newAdmin = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(newAdmin.publicKey, LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig);
+    const newAdminClient = createUserClient(provider, newAdmin);
+
+    await client
+      .updateProtocolConfig(wallet.publicKey, wallet.publicKey, NO_FEES, newAdmin.publicKey)
+      .rpc();
+    await newAdminClient.acceptProtocolAdmin(newAdmin.publicKey).rpc();
+
+    const config = await client.fetchProtocolConfig();
+    expect(config!.admin.equals(newAdmin.publicKey)).to.be.true;
+    expect(config!.pendingAdmin).to.be.null;
+
+    // Hand it back so `after` and later runs keep the provider wallet as admin
+    await newAdminClient
+      .updateProtocolConfig(newAdmin.publicKey, wallet.publicKey, NO_FEES, wallet.publicKey)
+      .rpc();
+    await client.acceptProtocolAdmin(wallet.publicKey).rpc();
+    expect((await client.fetchProtocolConfig())!.admin.equals(wallet.publicKey)).to.be.true;
+  });
+});
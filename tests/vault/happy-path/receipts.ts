@@ -0,0 +1,126 @@
+import { PublicKey } from "@solana/web3.js";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  sendAndLog,
+  redeemAndMeasure,
+  expectCondBalances,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Complete-Set Receipts", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const WRAPPED = DEPOSIT_AMOUNT / 2;
+  const UNWRAPPED = DEPOSIT_AMOUNT / 4;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+  let receiptMint: PublicKey;
+
+  const receiptBalance = async () => {
+    const ata = getAssociatedTokenAddressSync(
+      receiptMint,
+      wallet.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const acc = await getAccount(provider.connection, ata, undefined, TOKEN_2022_PROGRAM_ID);
+    return Number(acc.amount);
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultWithDeposit(
+      client,
+      wallet,
+      baseMint,
+      quoteMint,
+      DEPOSIT_AMOUNT,
+      VaultType.Base,
+      { numOptions: 3 }
+    );
+  });
+
+  it("wraps a complete set into one receipt token", async () => {
+    const wrap = await client.wrap(wallet.publicKey, ctx.vaultPda, VaultType.Base, WRAPPED);
+    receiptMint = wrap.receiptMint;
+    await sendAndLog(wrap.builder, client, wallet);
+
+    expect(receiptMint.equals(client.deriveReceiptMint(ctx.vaultPda, VaultType.Base)[0])).to.be.true;
+    expect(await receiptBalance()).to.equal(WRAPPED);
+    await expectCondBalances(
+      client,
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(3).fill(DEPOSIT_AMOUNT - WRAPPED)
+    );
+
+    const mint = await getMint(provider.connection, receiptMint, undefined, TOKEN_2022_PROGRAM_ID);
+    expect(Number(mint.supply)).to.equal(WRAPPED);
+  });
+
+  it("unwraps receipts back into every option", async () => {
+    const builder = await client.unwrap(wallet.publicKey, ctx.vaultPda, VaultType.Base, UNWRAPPED);
+    await sendAndLog(builder, client, wallet);
+
+    expect(await receiptBalance()).to.equal(WRAPPED - UNWRAPPED);
+    await expectCondBalances(
+      client,
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base,
+      Array(3).fill(DEPOSIT_AMOUNT - WRAPPED + UNWRAPPED)
+    );
+  });
+
+  it("redeems receipts for the underlying after finalization", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 2).rpc();
+
+    const { userBalance: before } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+    const builder = await client.redeemReceipt(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      WRAPPED - UNWRAPPED
+    );
+    await sendAndLog(builder, client, wallet);
+    const { userBalance: after } = await client.fetchUserBalances(
+      ctx.vaultPda,
+      wallet.publicKey,
+      VaultType.Base
+    );
+
+    expect(after.sub(before).toNumber()).to.equal(WRAPPED - UNWRAPPED);
+    expect(await receiptBalance()).to.equal(0);
+  });
+
+  it("keeps the escrow whole for the unwrapped winners", async () => {
+    const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+    expect(paid).to.equal(DEPOSIT_AMOUNT - WRAPPED + UNWRAPPED);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+  });
+});
@@ -0,0 +1,150 @@
+import { PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  createTestMint2022,
+  fundOwnerWallet,
+  createVaultInSetupState,
+  createVaultInActiveState,
+  sendAndLog,
+  redeemAndMeasure,
+  expectAnchorError,
+  expectCondBalances,
+  expectVaultBalance,
+} from "../helpers";
+
+describe("Token-2022 Underlying", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let quoteMint: PublicKey;
+
+  before(async () => {
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+  });
+
+  describe("plain Token-2022 base mint", () => {
+    let baseMint: PublicKey;
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      baseMint = await createTestMint2022(provider, wallet);
+      await fundOwnerWallet(provider, wallet, baseMint, undefined, TOKEN_2022_PROGRAM_ID);
+
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+        initOptions: { baseTokenProgram: TOKEN_2022_PROGRAM_ID },
+      });
+      vaultPda = ctx.vaultPda;
+    });
+
+    it("escrows deposits 1:1", async () => {
+      const builder = await client.deposit(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await sendAndLog(builder, client, wallet);
+
+      await expectCondBalances(client, vaultPda, wallet.publicKey, VaultType.Base, [
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+      ]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+    });
+
+    it("withdraws and redeems back to the Token-2022 account", async () => {
+      const builder = await client.withdraw(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT / 2
+      );
+      await sendAndLog(builder, client, wallet);
+
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc();
+
+      const paid = await redeemAndMeasure(client, wallet, vaultPda);
+      expect(paid).to.equal(DEPOSIT_AMOUNT / 2);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+    });
+  });
+
+  describe("transfer-fee base mint", () => {
+    const feeBps = 100; // 1%
+    const fee = (amount: number) => Math.ceil((amount * feeBps) / 10_000);
+
+    let baseMint: PublicKey;
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      baseMint = await createTestMint2022(provider, wallet, feeBps);
+      await fundOwnerWallet(provider, wallet, baseMint, undefined, TOKEN_2022_PROGRAM_ID);
+
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+        initOptions: { baseTokenProgram: TOKEN_2022_PROGRAM_ID },
+      });
+      vaultPda = ctx.vaultPda;
+    });
+
+    it("mints conditional tokens for the amount actually received", async () => {
+      const builder = await client.deposit(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await sendAndLog(builder, client, wallet);
+
+      const received = DEPOSIT_AMOUNT - fee(DEPOSIT_AMOUNT);
+      await expectCondBalances(client, vaultPda, wallet.publicKey, VaultType.Base, [
+        received,
+        received,
+      ]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, received);
+    });
+
+    it("withdraws the whole escrow, the transfer fee charged on the way out", async () => {
+      const received = DEPOSIT_AMOUNT - fee(DEPOSIT_AMOUNT);
+      const { userBalance: before } = await client.fetchUserBalances(
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Base
+      );
+
+      const builder = await client.withdraw(
+        wallet.publicKey,
+        vaultPda,
+        VaultType.Base,
+        received
+      );
+      await sendAndLog(builder, client, wallet);
+
+      const { userBalance: after } = await client.fetchUserBalances(
+        vaultPda,
+        wallet.publicKey,
+        VaultType.Base
+      );
+      expect(after.sub(before).toNumber()).to.equal(received - fee(received));
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+    });
+  });
+
+  describe("token program mismatch", () => {
+    it("rejects a Token-2022 mint passed with the SPL Token program", async () => {
+      const baseMint = await createTestMint2022(provider, wallet);
+
+      await expectAnchorError(
+        createVaultInSetupState(client, wallet, baseMint, quoteMint, {
+          initOptions: { baseTokenProgram: TOKEN_PROGRAM_ID },
+        }),
+        "ConstraintMintTokenProgram"
+      );
+    });
+  });
+});
@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAccount, mintTo, TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultType, UserTokenAccounts } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  createTokenAccount,
+  fundOwnerWallet,
+  createVaultInActiveState,
+  sendAndLog,
+  expectCondBalances,
+  expectVaultBalance,
+  VaultTestContext,
+} from "../helpers";
+
+describe("Non-ATA Token Accounts", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+  let tokenAccounts: UserTokenAccounts;
+
+  const balanceOf = async (account: PublicKey, tokenProgram: PublicKey) =>
+    Number((await getAccount(provider.connection, account, undefined, tokenProgram)).amount);
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+
+    // Base and conditional legs both held outside the owner's ATAs
+    const userAta = await createTokenAccount(provider, wallet, baseMint, wallet.publicKey);
+    await mintTo(provider.connection, wallet.payer, baseMint, userAta, wallet.publicKey, DEPOSIT_AMOUNT);
+    const userCondAtas = await Promise.all(
+      ctx.condBaseMints.map((condMint) =>
+        createTokenAccount(provider, wallet, condMint, wallet.publicKey, TOKEN_2022_PROGRAM_ID)
+      )
+    );
+    tokenAccounts = { userAta, userCondAtas };
+  });
+
+  it("deposits from and mints to the given accounts", async () => {
+    const builder = await client.deposit(wallet.publicKey, ctx.vaultPda, VaultType.Base, DEPOSIT_AMOUNT, {
+      tokenAccounts,
+    });
+    await sendAndLog(builder, client, wallet);
+
+    expect(await balanceOf(tokenAccounts.userAta!, TOKEN_PROGRAM_ID)).to.equal(0);
+    for (const condAccount of tokenAccounts.userCondAtas!) {
+      expect(await balanceOf(condAccount, TOKEN_2022_PROGRAM_ID)).to.equal(DEPOSIT_AMOUNT);
+    }
+    // No ATAs were created along the way
+    await expectCondBalances(client, ctx.vaultPda, wallet.publicKey, VaultType.Base, [0, 0]);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+  });
+
+  it("withdraws back to the given accounts", async () => {
+    const builder = await client.withdraw(
+      wallet.publicKey,
+      ctx.vaultPda,
+      VaultType.Base,
+      DEPOSIT_AMOUNT / 2,
+      { tokenAccounts }
+    );
+    await sendAndLog(builder, client, wallet);
+
+    expect(await balanceOf(tokenAccounts.userAta!, TOKEN_PROGRAM_ID)).to.equal(DEPOSIT_AMOUNT / 2);
+    for (const condAccount of tokenAccounts.userCondAtas!) {
+      expect(await balanceOf(condAccount, TOKEN_2022_PROGRAM_ID)).to.equal(DEPOSIT_AMOUNT / 2);
+    }
+  });
+
+  it("redeems into the given underlying account", async () => {
+    await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, 0).rpc();
+
+    const builder = await client.redeemWinnings(wallet.publicKey, ctx.vaultPda, VaultType.Base, {
+      tokenAccounts,
+    });
+    await sendAndLog(builder, client, wallet);
+
+    expect(await balanceOf(tokenAccounts.userAta!, TOKEN_PROGRAM_ID)).to.equal(DEPOSIT_AMOUNT);
+    await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+  });
+});
@@ -48,21 +48,21 @@ describe("Vault Types", () => {
       vaultPda = pda;
 
       // Add option to reach 3 options
-      const { builder: addBuilder } = await client.addOption(wallet.publicKey, vaultPda);
+      const { builder: addBuilder } = await client.addOption(wallet.publicKey, wallet.publicKey, vaultPda);
       await addBuilder.rpc();
     });
 
     it("verifies vault initialized with both mints", async () => {
       const vault = await client.fetchVault(vaultPda);
-      expect(vault.baseMint.toBase58()).to.equal(baseMint.toBase58());
-      expect(vault.quoteMint.toBase58()).to.equal(quoteMint.toBase58());
+      expect(vault.baseMint.address.toBase58()).to.equal(baseMint.toBase58());
+      expect(vault.quoteMint.address.toBase58()).to.equal(quoteMint.toBase58());
       const { state } = parseVaultState(vault.state);
       expect(state).to.equal(VaultState.Setup);
       expect(vault.numOptions).to.equal(3);
     });
 
     it("activates vault", async () => {
-      await client.activate(wallet.publicKey, vaultPda).rpc();
+      await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
       await expectVaultState(client, vaultPda, VaultState.Active);
     });
 
@@ -152,7 +152,7 @@ describe("Vault Types", () => {
     });
 
     it("finalizes vault", async () => {
-      await client.finalize(wallet.publicKey, vaultPda, 1).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 1).rpc();
       await expectVaultState(client, vaultPda, VaultState.Finalized);
 
       const vault = await client.fetchVault(vaultPda);
@@ -0,0 +1,62 @@
+import { PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VAULT_VERSION } from "../../../sdk/src";
+import { expire } from "../../../sdk/src/vault/instructions";
+import {
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createVaultWithDeposit,
+  expectAnchorError,
+  VaultTestContext,
+} from "../helpers";
+
+// v1 vault loaded into the validator from tests/fixtures/vault-v1.json (see Anchor.toml):
+// Active, 2 options, owner [21; 32], nonce 1, base [22; 32] / quote [23; 32]
+const V1_VAULT = new PublicKey("TRSeiJSAS9CELXbKafbnhCykNxD69Fs6kUhp5jwRKdQ");
+
+describe("Vault Versioning", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let ctx: VaultTestContext;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+  });
+
+  it("creates vaults at the current version", async () => {
+    expect(await client.fetchVaultVersion(ctx.vaultPda)).to.equal(VAULT_VERSION);
+
+    const vault = await client.fetchVault(ctx.vaultPda);
+    expect(vault.version).to.equal(VAULT_VERSION);
+  });
+
+  it("builds migrate from the raw account, passing the vault's escrows", async () => {
+    const builder = await client.migrate(wallet.publicKey, ctx.vaultPda);
+    const accounts = await builder.pubkeys();
+
+    expect(accounts.vault!.equals(ctx.vaultPda)).to.be.true;
+    expect(
+      accounts.baseEscrow!.equals(getAssociatedTokenAddressSync(baseMint, ctx.vaultPda, true))
+    ).to.be.true;
+    expect(
+      accounts.quoteEscrow!.equals(getAssociatedTokenAddressSync(quoteMint, ctx.vaultPda, true))
+    ).to.be.true;
+  });
+
+  it("rejects an unmigrated vault with InvalidVersion", async () => {
+    expect(await client.fetchVaultVersion(V1_VAULT)).to.equal(1);
+
+    // Built directly, since the client can't decode the v1 layout
+    await expectAnchorError(expire(client.program, V1_VAULT).rpc(), "InvalidVersion");
+  });
+});
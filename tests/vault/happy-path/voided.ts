@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType, VaultState } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  ONE_TOKEN,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultWithDeposit,
+  transferCondTokens,
+  redeemAndMeasure,
+  expectVaultState,
+  expectVaultBalance,
+  FundedUser,
+} from "../helpers";
+
+describe("Voided Vaults", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let holder: FundedUser;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    holder = await createFundedUser(provider, wallet, baseMint, quoteMint, ONE_TOKEN);
+  });
+
+  describe("single-option holders", () => {
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      const ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint);
+      vaultPda = ctx.vaultPda;
+
+      // Owner keeps option 0, holder takes option 1
+      await transferCondTokens(
+        provider,
+        wallet.payer,
+        ctx.condBaseMints[1],
+        holder.keypair.publicKey,
+        DEPOSIT_AMOUNT
+      );
+    });
+
+    it("voids the vault", async () => {
+      await client.voidVault(wallet.publicKey, vaultPda).rpc();
+      await expectVaultState(client, vaultPda, VaultState.Voided);
+    });
+
+    it("refunds each conditional token at 1/N", async () => {
+      const ownerPaid = await redeemAndMeasure(client, wallet, vaultPda);
+      expect(ownerPaid).to.equal(DEPOSIT_AMOUNT / 2);
+
+      const holderClient = createUserClient(provider, holder.keypair);
+      const holderPaid = await redeemAndMeasure(holderClient, holder.wallet, vaultPda);
+      expect(holderPaid).to.equal(DEPOSIT_AMOUNT / 2);
+
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+    });
+  });
+
+  describe("full set holder", () => {
+    it("redeems a full set for one underlying", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Quote,
+        { numOptions: 4 }
+      );
+
+      await client.voidVault(wallet.publicKey, ctx.vaultPda).rpc();
+
+      const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda, VaultType.Quote);
+      expect(paid).to.equal(DEPOSIT_AMOUNT);
+      await expectVaultBalance(client, ctx.vaultPda, VaultType.Quote, 0);
+    });
+  });
+});
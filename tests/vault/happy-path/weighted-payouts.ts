@@ -0,0 +1,99 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType, VaultState, PAYOUT_WEIGHT_TOTAL } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  ONE_TOKEN,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultWithDeposit,
+  transferCondTokens,
+  redeemAndMeasure,
+  expectVaultState,
+  expectVaultBalance,
+  FundedUser,
+} from "../helpers";
+
+describe("Weighted Payouts", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let holder: FundedUser;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    holder = await createFundedUser(provider, wallet, baseMint, quoteMint, ONE_TOKEN);
+  });
+
+  describe("70/30 split across two holders", () => {
+    // One extra unit so each side's payout rounds down
+    const deposit = DEPOSIT_AMOUNT + 1;
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      const ctx = await createVaultWithDeposit(client, wallet, baseMint, quoteMint, deposit);
+      vaultPda = ctx.vaultPda;
+
+      // Owner keeps option 0, holder takes option 1
+      await transferCondTokens(
+        provider,
+        wallet.payer,
+        ctx.condBaseMints[1],
+        holder.keypair.publicKey,
+        deposit
+      );
+    });
+
+    it("finalizes with a payout weight per option", async () => {
+      await client.finalizeWeighted(wallet.publicKey, vaultPda, [7000, 3000]).rpc();
+
+      await expectVaultState(client, vaultPda, VaultState.FinalizedWeighted);
+      const vault = await client.fetchVault(vaultPda);
+      expect(vault.payoutWeights.slice(0, vault.numOptions)).to.deep.equal([7000, 3000]);
+    });
+
+    it("pays each holder pro-rata to their option's weight", async () => {
+      const ownerPaid = await redeemAndMeasure(client, wallet, vaultPda);
+      expect(ownerPaid).to.equal(Math.floor((deposit * 7000) / PAYOUT_WEIGHT_TOTAL));
+
+      const holderClient = createUserClient(provider, holder.keypair);
+      const holderPaid = await redeemAndMeasure(holderClient, holder.wallet, vaultPda);
+      expect(holderPaid).to.equal(Math.floor((deposit * 3000) / PAYOUT_WEIGHT_TOTAL));
+    });
+
+    it("leaves rounding dust in escrow", async () => {
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 1);
+    });
+  });
+
+  describe("full weight on one option", () => {
+    it("pays like a winner-take-all finalize", async () => {
+      const ctx = await createVaultWithDeposit(
+        client,
+        wallet,
+        baseMint,
+        quoteMint,
+        DEPOSIT_AMOUNT,
+        VaultType.Base,
+        { numOptions: 3 }
+      );
+
+      await client
+        .finalizeWeighted(wallet.publicKey, ctx.vaultPda, [0, PAYOUT_WEIGHT_TOTAL, 0])
+        .rpc();
+
+      const paid = await redeemAndMeasure(client, wallet, ctx.vaultPda);
+      expect(paid).to.equal(DEPOSIT_AMOUNT);
+      await expectVaultBalance(client, ctx.vaultPda, VaultType.Base, 0);
+    });
+  });
+});
@@ -19,3 +19,11 @@ export const COMPUTE_UNITS_HIGH = 450_000; // For 6-10 options
 export function getComputeUnitsForOptions(numOptions: number): number {
   return numOptions <= 5 ? COMPUTE_UNITS_LOW : COMPUTE_UNITS_HIGH;
 }
+
+// Resolution deadline offset for expiry tests (seconds after current chain time)
+export const DEADLINE_DELAY = 5;
+
+// Sleep helper for waiting on resolution deadlines
+export function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
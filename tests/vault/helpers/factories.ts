@@ -1,7 +1,14 @@
 import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
 
-import { VaultClient, VaultType } from "../../../sdk/src";
+import {
+  AMMClient,
+  PRICE_SCALE,
+  VaultClient,
+  VaultType,
+  InitializeVaultOptions,
+} from "../../../sdk/src";
 import { getComputeUnitsForOptions, DEPOSIT_AMOUNT } from "./constants";
 
 export interface VaultTestContext {
@@ -17,6 +24,7 @@ export interface VaultTestContext {
 export interface CreateVaultOptions {
   numOptions?: number; // default: 2 (MIN_OPTIONS)
   nonce?: number; // default: auto-generated
+  initOptions?: InitializeVaultOptions; // symbols, labels, token programs (default: none)
 }
 
 // Auto-incrementing counter for unique vaults
@@ -51,7 +59,14 @@ export async function createVaultInSetupState(
     condBaseMint1,
     condQuoteMint0,
     condQuoteMint1,
-  } = client.initialize(wallet.publicKey, baseMint, quoteMint, nonce);
+  } = client.initialize(
+    wallet.publicKey,
+    baseMint,
+    quoteMint,
+    nonce,
+    undefined,
+    options.initOptions
+  );
   await builder.rpc();
 
   const condBaseMints: PublicKey[] = [condBaseMint0, condBaseMint1];
@@ -60,7 +75,7 @@ export async function createVaultInSetupState(
   // Add additional options to reach target
   for (let i = 2; i < numOptions; i++) {
     const { builder: addBuilder, condBaseMint, condQuoteMint } =
-      await client.addOption(wallet.publicKey, vaultPda);
+      await client.addOption(wallet.publicKey, wallet.publicKey, vaultPda);
     await addBuilder.rpc();
     condBaseMints.push(condBaseMint);
     condQuoteMints.push(condQuoteMint);
@@ -89,7 +104,7 @@ export async function createVaultInActiveState(
 ): Promise<VaultTestContext> {
   const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint, options);
 
-  await client.activate(wallet.publicKey, ctx.vaultPda).rpc();
+  await client.activate(wallet.publicKey, wallet.publicKey, ctx.vaultPda).rpc();
 
   return ctx;
 }
@@ -107,7 +122,7 @@ export async function createVaultInFinalizedState(
 ): Promise<VaultTestContext> {
   const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, options);
 
-  await client.finalize(wallet.publicKey, ctx.vaultPda, winningIdx).rpc();
+  await client.finalize(wallet.publicKey, wallet.publicKey, ctx.vaultPda, winningIdx).rpc();
 
   return ctx;
 }
@@ -137,6 +152,37 @@ export async function createVaultWithDeposit(
   return ctx;
 }
 
+/**
+ * Create an amm pool with equal liquidity on both sides (spot price = PRICE_SCALE),
+ * for oracle-resolved vaults to read their TWAP from
+ */
+export async function createTwapPool(
+  provider: anchor.AnchorProvider,
+  wallet: anchor.Wallet,
+  mintA: PublicKey,
+  mintB: PublicKey,
+  liquidity: number = DEPOSIT_AMOUNT * 10
+): Promise<PublicKey> {
+  const amm = new AMMClient(provider);
+
+  const { builder, poolPda } = amm.createPool(
+    wallet.publicKey,
+    wallet.publicKey, // admin
+    mintA,
+    mintB,
+    0, // fee
+    new BN(PRICE_SCALE.toString()), // starting observation
+    new BN((PRICE_SCALE / 10n).toString()), // max observation delta
+    0 // warmup duration
+  );
+  await builder.rpc();
+
+  const addLiquidity = await amm.addLiquidity(wallet.publicKey, poolPda, liquidity, liquidity);
+  await addLiquidity.rpc();
+
+  return poolPda;
+}
+
 /**
  * Helper to send transaction and log byte/CU usage
  * Note: SDK already includes compute budget, so we just send and log
@@ -168,3 +214,29 @@ export async function sendAndLog(
 
   return sig;
 }
+
+/**
+ * Redeem all of a user's conditional tokens and return the underlying received
+ */
+export async function redeemAndMeasure(
+  client: VaultClient,
+  wallet: anchor.Wallet,
+  vaultPda: PublicKey,
+  vaultType: VaultType = VaultType.Base
+): Promise<number> {
+  const { userBalance: before } = await client.fetchUserBalances(
+    vaultPda,
+    wallet.publicKey,
+    vaultType
+  );
+
+  const builder = await client.redeemWinnings(wallet.publicKey, vaultPda, vaultType);
+  await sendAndLog(builder, client, wallet);
+
+  const { userBalance: after } = await client.fetchUserBalances(
+    vaultPda,
+    wallet.publicKey,
+    vaultType
+  );
+  return after.sub(before).toNumber();
+}
@@ -1,13 +1,29 @@
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
 import {
   createMint,
+  createAccount,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  ExtensionType,
+  getMintLen,
   mintTo,
+  getMint,
   getOrCreateAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  transferChecked,
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
 
 import { VaultClient } from "../../../sdk/src";
-import { FUNDING_AMOUNT } from "./constants";
+import { FUNDING_AMOUNT, sleep } from "./constants";
 
 export interface TestContext {
   provider: anchor.AnchorProvider;
@@ -49,6 +65,54 @@ export async function createTestMint(
   );
 }
 
+/**
+ * Create a Token-2022 test mint with 6 decimals, optionally with a transfer fee
+ */
+export async function createTestMint2022(
+  provider: anchor.AnchorProvider,
+  wallet: anchor.Wallet,
+  transferFeeBps: number = 0
+): Promise<PublicKey> {
+  const mintKeypair = Keypair.generate();
+  const extensions = transferFeeBps > 0 ? [ExtensionType.TransferFeeConfig] : [];
+  const mintLen = getMintLen(extensions);
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: wallet.publicKey,
+      newAccountPubkey: mintKeypair.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    })
+  );
+  if (transferFeeBps > 0) {
+    tx.add(
+      createInitializeTransferFeeConfigInstruction(
+        mintKeypair.publicKey,
+        wallet.publicKey,
+        wallet.publicKey,
+        transferFeeBps,
+        BigInt(Number.MAX_SAFE_INTEGER),
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+  }
+  tx.add(
+    createInitializeMintInstruction(
+      mintKeypair.publicKey,
+      6,
+      wallet.publicKey,
+      null,
+      TOKEN_2022_PROGRAM_ID
+    )
+  );
+  await provider.sendAndConfirm(tx, [mintKeypair]);
+
+  return mintKeypair.publicKey;
+}
+
 /**
  * Create a funded user with SOL and tokens for both base and quote mints
  */
@@ -57,7 +121,9 @@ export async function createFundedUser(
   wallet: anchor.Wallet,
   baseMint: PublicKey,
   quoteMint: PublicKey,
-  amount: number = FUNDING_AMOUNT
+  amount: number = FUNDING_AMOUNT,
+  baseTokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+  quoteTokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<FundedUser> {
   const keypair = Keypair.generate();
   const userWallet = new anchor.Wallet(keypair);
@@ -74,7 +140,11 @@ export async function createFundedUser(
     provider.connection,
     wallet.payer,
     baseMint,
-    keypair.publicKey
+    keypair.publicKey,
+    false,
+    undefined,
+    undefined,
+    baseTokenProgram
   );
   await mintTo(
     provider.connection,
@@ -82,7 +152,10 @@ export async function createFundedUser(
     baseMint,
     baseAta.address,
     wallet.publicKey,
-    amount
+    amount,
+    [],
+    undefined,
+    baseTokenProgram
   );
 
   // Create ATA and fund with quote tokens
@@ -90,7 +163,11 @@ export async function createFundedUser(
     provider.connection,
     wallet.payer,
     quoteMint,
-    keypair.publicKey
+    keypair.publicKey,
+    false,
+    undefined,
+    undefined,
+    quoteTokenProgram
   );
   await mintTo(
     provider.connection,
@@ -98,7 +175,10 @@ export async function createFundedUser(
     quoteMint,
     quoteAta.address,
     wallet.publicKey,
-    amount
+    amount,
+    [],
+    undefined,
+    quoteTokenProgram
   );
 
   return {
@@ -116,13 +196,18 @@ export async function fundOwnerWallet(
   provider: anchor.AnchorProvider,
   wallet: anchor.Wallet,
   mint: PublicKey,
-  amount: number = FUNDING_AMOUNT
+  amount: number = FUNDING_AMOUNT,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<PublicKey> {
   const ata = await getOrCreateAssociatedTokenAccount(
     provider.connection,
     wallet.payer,
     mint,
-    wallet.publicKey
+    wallet.publicKey,
+    false,
+    undefined,
+    undefined,
+    tokenProgram
   );
 
   await mintTo(
@@ -131,7 +216,10 @@ export async function fundOwnerWallet(
     mint,
     ata.address,
     wallet.publicKey,
-    amount
+    amount,
+    [],
+    undefined,
+    tokenProgram
   );
 
   return ata.address;
@@ -152,3 +240,111 @@ export function createUserClient(
   );
   return new VaultClient(userProvider);
 }
+
+/**
+ * Transfer conditional tokens (Token-2022) to another holder, creating their ATA if needed
+ */
+export async function transferCondTokens(
+  provider: anchor.AnchorProvider,
+  from: Keypair,
+  condMint: PublicKey,
+  to: PublicKey,
+  amount: number
+): Promise<void> {
+  const { decimals } = await getMint(
+    provider.connection,
+    condMint,
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+  const toAta = await getOrCreateAssociatedTokenAccount(
+    provider.connection,
+    from,
+    condMint,
+    to,
+    false,
+    undefined,
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+  await transferChecked(
+    provider.connection,
+    from,
+    getAssociatedTokenAddressSync(condMint, from.publicKey, false, TOKEN_2022_PROGRAM_ID),
+    condMint,
+    toAta.address,
+    from,
+    amount,
+    decimals,
+    [],
+    undefined,
+    TOKEN_2022_PROGRAM_ID
+  );
+}
+
+/**
+ * Create a non-ATA token account of `owner` for `mint` (like a smart wallet's extra account)
+ */
+export async function createTokenAccount(
+  provider: anchor.AnchorProvider,
+  wallet: anchor.Wallet,
+  mint: PublicKey,
+  owner: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<PublicKey> {
+  return createAccount(
+    provider.connection,
+    wallet.payer,
+    mint,
+    owner,
+    Keypair.generate(),
+    undefined,
+    tokenProgram
+  );
+}
+
+/**
+ * Current cluster unix timestamp (vault deadlines are checked against this, not local time)
+ */
+export async function getChainTime(provider: anchor.AnchorProvider): Promise<number> {
+  const slot = await provider.connection.getSlot();
+  const time = await provider.connection.getBlockTime(slot);
+  if (time === null) {
+    throw new Error(`No block time for slot ${slot}`);
+  }
+  return time;
+}
+
+/**
+ * Wait until the cluster clock reaches `timestamp`
+ */
+export async function waitForChainTime(
+  provider: anchor.AnchorProvider,
+  timestamp: number
+): Promise<void> {
+  while ((await getChainTime(provider)) < timestamp) {
+    await sleep(1000);
+  }
+}
+
+/**
+ * Initialize the program-wide protocol config with zero fees if no suite has yet.
+ * The provider wallet deploys the program, so it's the upgrade authority and becomes admin.
+ */
+export async function ensureProtocolConfig(
+  client: VaultClient,
+  wallet: anchor.Wallet
+): Promise<void> {
+  if (await client.fetchProtocolConfig()) {
+    return;
+  }
+  await client
+    .initializeProtocolConfig(
+      wallet.publicKey,
+      wallet.publicKey,
+      wallet.publicKey,
+      wallet.publicKey,
+      { deposit: 0, redeem: 0 }
+    )
+    .rpc();
+}
@@ -3,14 +3,44 @@
  *
  * Comprehensive test coverage including:
  * - Parameterized lifecycle tests (2 and 10 options)
- * - VaultType.Base and VaultType.Quote
+ * - VaultType.Base and VaultType.Quote, together via deposit_both/withdraw_both
+ * - Weighted (scalar) payouts, voided vaults and resolution deadlines
+ * - Oracle-resolved vaults (amm pool TWAP)
+ * - Conditional mint metadata and Token-2022 underlying mints
+ * - Non-ATA user token accounts
+ * - Partial redemption that keeps conditional token accounts open
+ * - Chunked deposits/withdrawals for vaults with many options
+ * - Escrow totals and close_vault
+ * - Vault layout versions and migrate
+ * - Event payloads (post-state escrow/supply, token account lifecycle)
+ * - Protocol fees (config, per-vault rates, collection, admin handover)
+ * - Option sets (merge, split, OR-position redemption) and nested vaults
+ * - Complete-set receipts (wrap, unwrap, redeem_receipt)
  * - All error conditions (state, authorization, validation)
- * - Multi-user scenarios (deposits, interleaved ops, redemption)
+ * - Multi-user scenarios (deposits, interleaved ops, redemption, sweeps, operators,
+ *   deposit caps and allow-lists)
  */
 
 // Happy Path Tests
 import "./happy-path/lifecycle";
 import "./happy-path/vault-types";
+import "./happy-path/weighted-payouts";
+import "./happy-path/voided";
+import "./happy-path/expiry";
+import "./happy-path/metadata";
+import "./happy-path/token-2022";
+import "./happy-path/escrow";
+import "./happy-path/option-sets";
+import "./happy-path/nested";
+import "./happy-path/deposit-both";
+import "./happy-path/token-accounts";
+import "./happy-path/chunked";
+import "./happy-path/oracle";
+import "./happy-path/partial-redeem";
+import "./happy-path/versioning";
+import "./happy-path/receipts";
+import "./happy-path/events";
+import "./happy-path/protocol-fees";
 
 // Error Tests
 import "./errors/state-errors";
@@ -21,3 +51,6 @@ import "./errors/validation-errors";
 import "./multi-user/deposits";
 import "./multi-user/interleaved";
 import "./multi-user/redemption";
+import "./multi-user/sweep";
+import "./multi-user/operators";
+import "./multi-user/deposit-limits";
@@ -0,0 +1,137 @@
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultClient, VaultType, DepositLimits } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultInSetupState,
+  sendAndLog,
+  expectCondBalances,
+  expectVaultBalance,
+  FundedUser,
+} from "../helpers";
+
+describe("Deposit Limits", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let alice: FundedUser;
+  let aliceClient: VaultClient;
+  let bob: FundedUser;
+  let bobClient: VaultClient;
+
+  // Vault still in Setup, with the given limits
+  const createLimitedVault = async (limits: DepositLimits) => {
+    const ctx = await createVaultInSetupState(client, wallet, baseMint, quoteMint);
+    await client.setDepositLimits(wallet.publicKey, ctx.vaultPda, limits).rpc();
+    return ctx.vaultPda;
+  };
+
+  const depositAs = async (
+    userClient: VaultClient,
+    user: FundedUser,
+    vaultPda: PublicKey,
+    amount: number
+  ) => {
+    const builder = await userClient.deposit(user.keypair.publicKey, vaultPda, VaultType.Base, amount);
+    await sendAndLog(builder, userClient, user.wallet);
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    alice = await createFundedUser(provider, wallet, baseMint, quoteMint, DEPOSIT_AMOUNT * 4);
+    aliceClient = createUserClient(provider, alice.keypair);
+    bob = await createFundedUser(provider, wallet, baseMint, quoteMint, DEPOSIT_AMOUNT * 4);
+    bobClient = createUserClient(provider, bob.keypair);
+  });
+
+  describe("total cap", () => {
+    it("accepts deposits up to the cap, and again once withdrawals free room", async () => {
+      const vaultPda = await createLimitedVault({ baseCaps: { total: DEPOSIT_AMOUNT * 2 } });
+      await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
+
+      const vault = await client.fetchVault(vaultPda);
+      expect(vault.baseCaps.total!.toNumber()).to.equal(DEPOSIT_AMOUNT * 2);
+      expect(vault.quoteCaps.total).to.be.null;
+
+      await depositAs(aliceClient, alice, vaultPda, DEPOSIT_AMOUNT);
+      await depositAs(bobClient, bob, vaultPda, DEPOSIT_AMOUNT);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT * 2);
+
+      const withdraw = await aliceClient.withdraw(
+        alice.keypair.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await sendAndLog(withdraw, aliceClient, alice.wallet);
+      await depositAs(bobClient, bob, vaultPda, DEPOSIT_AMOUNT);
+
+      await expectCondBalances(client, vaultPda, bob.keypair.publicKey, VaultType.Base, [
+        DEPOSIT_AMOUNT * 2,
+        DEPOSIT_AMOUNT * 2,
+      ]);
+    });
+  });
+
+  describe("per-user cap", () => {
+    it("tracks each depositor's cumulative deposits separately", async () => {
+      const vaultPda = await createLimitedVault({ baseCaps: { perUser: DEPOSIT_AMOUNT } });
+      await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
+
+      await depositAs(aliceClient, alice, vaultPda, DEPOSIT_AMOUNT);
+      await depositAs(bobClient, bob, vaultPda, DEPOSIT_AMOUNT / 2);
+
+      const aliceRecord = await client.fetchDepositRecord(vaultPda, alice.keypair.publicKey);
+      const bobRecord = await client.fetchDepositRecord(vaultPda, bob.keypair.publicKey);
+      expect(aliceRecord!.baseDeposited.toNumber()).to.equal(DEPOSIT_AMOUNT);
+      expect(bobRecord!.baseDeposited.toNumber()).to.equal(DEPOSIT_AMOUNT / 2);
+      expect(bobRecord!.quoteDeposited.toNumber()).to.equal(0);
+    });
+
+    it("creates the deposit record even if its address was funded ahead of time", async () => {
+      const vaultPda = await createLimitedVault({ baseCaps: { perUser: DEPOSIT_AMOUNT } });
+      await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
+
+      // Lamports sent to the record PDA would make a bare create_account fail
+      const [record] = client.deriveDepositRecordPDA(vaultPda, alice.keypair.publicKey);
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.transfer({ fromPubkey: wallet.publicKey, toPubkey: record, lamports: 1_000 })
+        )
+      );
+
+      await depositAs(aliceClient, alice, vaultPda, DEPOSIT_AMOUNT);
+
+      const aliceRecord = await client.fetchDepositRecord(vaultPda, alice.keypair.publicKey);
+      expect(aliceRecord!.baseDeposited.toNumber()).to.equal(DEPOSIT_AMOUNT);
+    });
+  });
+
+  describe("allow-list", () => {
+    it("lets approved wallets deposit, including ones added after activation", async () => {
+      const vaultPda = await createLimitedVault({ allowList: true });
+      await client.allowDepositor(wallet.publicKey, wallet.publicKey, vaultPda, alice.keypair.publicKey).rpc();
+      await client.activate(wallet.publicKey, wallet.publicKey, vaultPda).rpc();
+
+      await depositAs(aliceClient, alice, vaultPda, DEPOSIT_AMOUNT);
+
+      await client.allowDepositor(wallet.publicKey, wallet.publicKey, vaultPda, bob.keypair.publicKey).rpc();
+      await depositAs(bobClient, bob, vaultPda, DEPOSIT_AMOUNT);
+
+      const record = await client.fetchDepositRecord(vaultPda, bob.keypair.publicKey);
+      expect(record!.allowed).to.be.true;
+      await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT * 2);
+    });
+  });
+});
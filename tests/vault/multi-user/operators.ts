@@ -0,0 +1,170 @@
+import { PublicKey } from "@solana/web3.js";
+import { approve, getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+
+import { VaultClient, VaultType } from "../../../sdk/src";
+import {
+  DEPOSIT_AMOUNT,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultInActiveState,
+  sendAndLog,
+  expectAnchorError,
+  expectCondBalances,
+  expectVaultBalance,
+  FundedUser,
+} from "../helpers";
+
+describe("Operators and Delegates", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let owner: FundedUser;
+  let ownerClient: VaultClient;
+
+  // Owner grants `delegate` an allowance over their base ATA and, optionally, conditional ATAs
+  const approveAllowances = async (
+    vaultPda: PublicKey,
+    delegate: PublicKey,
+    amount: number,
+    condMints: PublicKey[] = []
+  ) => {
+    await approve(
+      provider.connection,
+      owner.keypair,
+      owner.baseAta,
+      delegate,
+      owner.keypair,
+      amount
+    );
+    for (const condMint of condMints) {
+      await approve(
+        provider.connection,
+        owner.keypair,
+        getAssociatedTokenAddressSync(condMint, owner.keypair.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        delegate,
+        owner.keypair,
+        amount,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    }
+  };
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    owner = await createFundedUser(provider, wallet, baseMint, quoteMint, DEPOSIT_AMOUNT * 4);
+    ownerClient = createUserClient(provider, owner.keypair);
+  });
+
+  describe("registered operator", () => {
+    let vaultPda: PublicKey;
+    let condBaseMints: PublicKey[];
+    let operatorApproval: PublicKey;
+
+    before(async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      vaultPda = ctx.vaultPda;
+      condBaseMints = ctx.condBaseMints;
+    });
+
+    it("registers the provider wallet as the owner's operator", async () => {
+      const approval = ownerClient.approveOperator(
+        owner.keypair.publicKey,
+        owner.keypair.publicKey,
+        vaultPda,
+        wallet.publicKey
+      );
+      operatorApproval = approval.operatorApproval;
+      await approval.builder.rpc();
+
+      const account = await client.program.account.operatorApproval.fetch(operatorApproval);
+      expect(account.owner.equals(owner.keypair.publicKey)).to.be.true;
+      expect(account.operator.equals(wallet.publicKey)).to.be.true;
+    });
+
+    it("deposits the owner's funds, minting to the owner", async () => {
+      await approveAllowances(vaultPda, operatorApproval, DEPOSIT_AMOUNT);
+
+      const builder = await client.depositFor(
+        wallet.publicKey,
+        owner.keypair.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await sendAndLog(builder, client, wallet);
+
+      await expectCondBalances(client, vaultPda, owner.keypair.publicKey, VaultType.Base, [
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+      ]);
+      await expectCondBalances(client, vaultPda, wallet.publicKey, VaultType.Base, [0, 0]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT);
+    });
+
+    it("withdraws back to the owner", async () => {
+      await approveAllowances(vaultPda, operatorApproval, DEPOSIT_AMOUNT / 2, condBaseMints);
+
+      const builder = await client.withdrawFor(
+        wallet.publicKey,
+        owner.keypair.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT / 2
+      );
+      await sendAndLog(builder, client, wallet);
+
+      await expectCondBalances(client, vaultPda, owner.keypair.publicKey, VaultType.Base, [
+        DEPOSIT_AMOUNT / 2,
+        DEPOSIT_AMOUNT / 2,
+      ]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, DEPOSIT_AMOUNT / 2);
+    });
+
+    it("stops acting once the owner revokes it", async () => {
+      await ownerClient.revokeOperator(owner.keypair.publicKey, vaultPda, wallet.publicKey).rpc();
+      expect(await provider.connection.getAccountInfo(operatorApproval)).to.be.null;
+
+      const builder = await client.depositFor(
+        wallet.publicKey,
+        owner.keypair.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT
+      );
+      await expectAnchorError(sendAndLog(builder, client, wallet), "AccountNotInitialized");
+    });
+  });
+
+  describe("token delegate", () => {
+    it("deposits for the owner with only an SPL allowance", async () => {
+      const { vaultPda } = await createVaultInActiveState(client, wallet, baseMint, quoteMint);
+      await approveAllowances(vaultPda, wallet.publicKey, DEPOSIT_AMOUNT);
+
+      const builder = await client.depositFor(
+        wallet.publicKey,
+        owner.keypair.publicKey,
+        vaultPda,
+        VaultType.Base,
+        DEPOSIT_AMOUNT,
+        { asDelegate: true }
+      );
+      await sendAndLog(builder, client, wallet);
+
+      await expectCondBalances(client, vaultPda, owner.keypair.publicKey, VaultType.Base, [
+        DEPOSIT_AMOUNT,
+        DEPOSIT_AMOUNT,
+      ]);
+    });
+  });
+});
@@ -83,7 +83,7 @@ describe("Multi-User Redemption", () => {
       await sendAndLog(charlieBuilder, charlieClient, charlie.wallet);
 
       // Finalize
-      await client.finalize(wallet.publicKey, vaultPda, winningIdx).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, winningIdx).rpc();
     });
 
     it("Alice redeems and receives 5M (her winning token balance)", async () => {
@@ -198,7 +198,7 @@ describe("Multi-User Redemption", () => {
       );
       await sendAndLog(bobBuilder, bobClient, bob.wallet);
 
-      await client.finalize(wallet.publicKey, vaultPda, winningIdx).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, winningIdx).rpc();
     });
 
     it("Bob redeems FIRST (different order than deposit)", async () => {
@@ -293,7 +293,7 @@ describe("Multi-User Redemption", () => {
       );
       await sendAndLog(bobWithdrawBuilder, bobClient, bob.wallet);
 
-      await client.finalize(wallet.publicKey, vaultPda, winningIdx).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, winningIdx).rpc();
     });
 
     it("Bob (0 winning tokens) can still call redeem - receives 0", async () => {
@@ -358,7 +358,7 @@ describe("Multi-User Redemption", () => {
       );
       await sendAndLog(aliceBuilder, aliceClient, alice.wallet);
 
-      await client.finalize(wallet.publicKey, vaultPda, 0).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc();
     });
 
     it("Charlie (never deposited) can call redeem - succeeds with 0", async () => {
@@ -457,7 +457,7 @@ describe("Multi-User Redemption", () => {
       await expectVaultBalance(client, vaultPda, VaultType.Base, totalDeposits);
 
       // Finalize
-      await client.finalize(wallet.publicKey, vaultPda, 0).rpc();
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc();
 
       // All redeem
       const ar = await aliceClient.redeemWinnings(
@@ -0,0 +1,111 @@
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+
+import { VaultType } from "../../../sdk/src";
+import {
+  ONE_TOKEN,
+  getTestContext,
+  createTestMint,
+  fundOwnerWallet,
+  createFundedUser,
+  createUserClient,
+  createVaultInActiveState,
+  createVaultInFinalizedState,
+  sendAndLog,
+  expectAnchorError,
+  expectCondBalances,
+  expectVaultBalance,
+  FundedUser,
+} from "../helpers";
+
+describe("Multi-User Sweep (redeem_for)", () => {
+  const { provider, wallet, client } = getTestContext();
+
+  const aliceDeposit = 4 * ONE_TOKEN;
+  const bobDeposit = 2 * ONE_TOKEN;
+
+  let baseMint: PublicKey;
+  let quoteMint: PublicKey;
+  let alice: FundedUser;
+  let bob: FundedUser;
+
+  before(async () => {
+    baseMint = await createTestMint(provider, wallet);
+    quoteMint = await createTestMint(provider, wallet);
+    await fundOwnerWallet(provider, wallet, baseMint);
+    await fundOwnerWallet(provider, wallet, quoteMint);
+
+    alice = await createFundedUser(provider, wallet, baseMint, quoteMint, 10 * ONE_TOKEN);
+    bob = await createFundedUser(provider, wallet, baseMint, quoteMint, 10 * ONE_TOKEN);
+  });
+
+  describe("vault with permanent delegate", () => {
+    let vaultPda: PublicKey;
+
+    before(async () => {
+      const ctx = await createVaultInActiveState(client, wallet, baseMint, quoteMint, {
+        initOptions: { permanentDelegate: true },
+      });
+      vaultPda = ctx.vaultPda;
+
+      for (const [user, amount] of [
+        [alice, aliceDeposit],
+        [bob, bobDeposit],
+      ] as [FundedUser, number][]) {
+        const userClient = createUserClient(provider, user.keypair);
+        const builder = await userClient.deposit(
+          user.keypair.publicKey,
+          vaultPda,
+          VaultType.Base,
+          amount
+        );
+        await sendAndLog(builder, userClient, user.wallet);
+      }
+
+      await client.finalize(wallet.publicKey, wallet.publicKey, vaultPda, 0).rpc();
+    });
+
+    it("redeems several holders in one transaction", async () => {
+      const balances = async () =>
+        Promise.all(
+          [alice, bob].map(async (user) => {
+            const { userBalance } = await client.fetchUserBalances(
+              vaultPda,
+              user.keypair.publicKey,
+              VaultType.Base
+            );
+            return userBalance.toNumber();
+          })
+        );
+      const before = await balances();
+
+      // Anyone can sweep; the owner's wallet calls it here
+      const builder = await client.redeemFor(wallet.publicKey, vaultPda, VaultType.Base, [
+        alice.keypair.publicKey,
+        bob.keypair.publicKey,
+      ]);
+      await sendAndLog(builder, client, wallet);
+
+      const after = await balances();
+      expect(after[0] - before[0]).to.equal(aliceDeposit);
+      expect(after[1] - before[1]).to.equal(bobDeposit);
+    });
+
+    it("burns every conditional token of the holders", async () => {
+      await expectCondBalances(client, vaultPda, alice.keypair.publicKey, VaultType.Base, [0, 0]);
+      await expectCondBalances(client, vaultPda, bob.keypair.publicKey, VaultType.Base, [0, 0]);
+      await expectVaultBalance(client, vaultPda, VaultType.Base, 0);
+    });
+  });
+
+  describe("vault without permanent delegate", () => {
+    it("rejects redeem_for", async () => {
+      const ctx = await createVaultInFinalizedState(client, wallet, baseMint, quoteMint, 0);
+
+      const builder = await client.redeemFor(wallet.publicKey, ctx.vaultPda, VaultType.Base, [
+        alice.keypair.publicKey,
+      ]);
+      await expectAnchorError(builder.rpc(), "NoPermanentDelegate");
+    });
+  });
+});